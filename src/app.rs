@@ -3,44 +3,80 @@
 //! The corresponding ZIP item is `/docProps/app.xml`.
 
 use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::io::Write;
-use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+use strong_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
 
+use crate::document::{BodyContent, Document, Paragraph, Table, TableCellContent};
 use crate::schema::{SCHEMAS_EXTENDED, SCHEMA_DOC_PROPS_V_TYPES};
+use crate::{__setter, __xml_test_suites};
 
+/// Application-specific properties of the package
+///
+/// ```rust
+/// use docx::app::*;
+///
+/// let app = App::default()
+///     .company("ACME")
+///     .heading_pairs(HeadingPairs::default().push(HeadingPair::new("Title", 1)))
+///     .titles_of_parts(TitlesOfParts::default().push("Contract"));
+/// ```
 #[derive(Debug, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "Properties")]
 pub struct App<'a> {
+    /// Specifies the name of the template used to create the document.
     #[xml(flatten_text = "Template")]
     pub template: Option<Cow<'a, str>>,
+    /// Specifies the total editing time in minutes.
     #[xml(flatten_text = "TotalTime")]
-    pub total_time: Option<Cow<'a, str>>,
+    pub total_time: Option<usize>,
+    /// Specifies the number of pages.
     #[xml(flatten_text = "Pages")]
-    pub pages: Option<Cow<'a, str>>,
+    pub pages: Option<usize>,
+    /// Specifies the number of words.
     #[xml(flatten_text = "Words")]
-    pub words: Option<Cow<'a, str>>,
+    pub words: Option<usize>,
+    /// Specifies the number of characters, excluding white spaces.
     #[xml(flatten_text = "Characters")]
-    pub characters: Option<Cow<'a, str>>,
+    pub characters: Option<usize>,
+    /// Specifies the name of the application which created the document.
     #[xml(flatten_text = "Application")]
     pub application: Option<Cow<'a, str>>,
+    /// Specifies the security level of the document.
     #[xml(flatten_text = "DocSecurity")]
-    pub doc_security: Option<Cow<'a, str>>,
+    pub doc_security: Option<usize>,
+    /// Specifies the number of lines.
     #[xml(flatten_text = "Lines")]
-    pub lines: Option<Cow<'a, str>>,
+    pub lines: Option<usize>,
+    /// Specifies the number of paragraphs.
     #[xml(flatten_text = "Paragraphs")]
-    pub paragraphs: Option<Cow<'a, str>>,
+    pub paragraphs: Option<usize>,
+    /// Specifies whether the thumbnail is cropped or scaled.
     #[xml(flatten_text = "ScaleCrop")]
-    pub scale_crop: Option<Cow<'a, str>>,
+    pub scale_crop: Option<bool>,
+    /// Specifies the grouping of document parts and the number of parts in each group.
+    #[xml(child = "HeadingPairs")]
+    pub heading_pairs: Option<HeadingPairs<'a>>,
+    /// Specifies the titles of document parts.
+    #[xml(child = "TitlesOfParts")]
+    pub titles_of_parts: Option<TitlesOfParts<'a>>,
+    /// Specifies the name of the company.
     #[xml(flatten_text = "Company")]
     pub company: Option<Cow<'a, str>>,
+    /// Specifies whether hyperlinks are up to date.
     #[xml(flatten_text = "LinksUpToDate")]
-    pub links_up_to_date: Option<Cow<'a, str>>,
+    pub links_up_to_date: Option<bool>,
+    /// Specifies the number of characters, including white spaces.
     #[xml(flatten_text = "CharactersWithSpaces")]
-    pub characters_with_spaces: Option<Cow<'a, str>>,
+    pub characters_with_spaces: Option<usize>,
+    /// Specifies whether the document is shared between multiple producers.
     #[xml(flatten_text = "SharedDoc")]
-    pub shared_doc: Option<Cow<'a, str>>,
+    pub shared_doc: Option<bool>,
+    /// Specifies whether hyperlinks have been changed by the producer.
     #[xml(flatten_text = "HyperlinksChanged")]
-    pub hyperlinks_changed: Option<Cow<'a, str>>,
+    pub hyperlinks_changed: Option<bool>,
+    /// Specifies the version of the application which created the document.
     #[xml(flatten_text = "AppVersion")]
     pub app_version: Option<Cow<'a, str>>,
 }
@@ -48,26 +84,108 @@ pub struct App<'a> {
 impl Default for App<'static> {
     fn default() -> App<'static> {
         App {
-            template: Some("Normal.dotm".into()),
-            total_time: Some("1".into()),
-            pages: Some("1".into()),
-            words: Some("0".into()),
-            characters: Some("0".into()),
+            template: None,
+            total_time: None,
+            pages: None,
+            words: None,
+            characters: None,
             application: Some("docx-rs".into()),
-            doc_security: Some("0".into()),
-            lines: Some("0".into()),
-            paragraphs: Some("1".into()),
-            scale_crop: Some("false".into()),
-            company: Some("MS".into()),
-            links_up_to_date: Some("false".into()),
-            characters_with_spaces: Some("25".into()),
-            shared_doc: Some("false".into()),
-            hyperlinks_changed: Some("false".into()),
-            app_version: Some("12.0000".into()),
+            doc_security: None,
+            lines: None,
+            paragraphs: None,
+            scale_crop: None,
+            heading_pairs: None,
+            titles_of_parts: None,
+            company: None,
+            links_up_to_date: None,
+            characters_with_spaces: None,
+            shared_doc: None,
+            hyperlinks_changed: None,
+            app_version: None,
         }
     }
 }
 
+impl<'a> App<'a> {
+    __setter!(template: Option<Cow<'a, str>>);
+    __setter!(total_time: Option<usize>);
+    __setter!(pages: Option<usize>);
+    __setter!(application: Option<Cow<'a, str>>);
+    __setter!(doc_security: Option<usize>);
+    __setter!(scale_crop: Option<bool>);
+    __setter!(heading_pairs: Option<HeadingPairs<'a>>);
+    __setter!(titles_of_parts: Option<TitlesOfParts<'a>>);
+    __setter!(company: Option<Cow<'a, str>>);
+    __setter!(links_up_to_date: Option<bool>);
+    __setter!(shared_doc: Option<bool>);
+    __setter!(hyperlinks_changed: Option<bool>);
+    __setter!(app_version: Option<Cow<'a, str>>);
+
+    /// Recomputes the statistics from the content of the document
+    ///
+    /// Words are sequences of characters separated by white spaces, and
+    /// paragraphs without any text are not counted. Since the layout is
+    /// unknown, each paragraph is counted as a single line.
+    pub fn update_statistics(&mut self, document: &Document) {
+        let mut stats = Statistics::default();
+
+        stats.add_body(&document.body.content);
+
+        self.words = Some(stats.words);
+        self.characters = Some(stats.characters);
+        self.characters_with_spaces = Some(stats.characters_with_spaces);
+        self.paragraphs = Some(stats.paragraphs);
+        self.lines = Some(stats.paragraphs);
+    }
+}
+
+#[derive(Default)]
+struct Statistics {
+    words: usize,
+    characters: usize,
+    characters_with_spaces: usize,
+    paragraphs: usize,
+}
+
+impl Statistics {
+    fn add_body(&mut self, content: &[BodyContent]) {
+        for content in content {
+            match content {
+                BodyContent::Paragraph(para) => self.add(para),
+                BodyContent::Table(table) => self.add_table(table),
+                BodyContent::Sdt(sdt) => self.add_body(&sdt.content.content),
+                _ => (),
+            }
+        }
+    }
+
+    fn add_table(&mut self, table: &Table) {
+        for row in &table.rows {
+            for cell in &row.cells {
+                for content in &cell.content {
+                    match content {
+                        TableCellContent::Paragraph(para) => self.add(para),
+                        TableCellContent::Table(table) => self.add_table(table),
+                    }
+                }
+            }
+        }
+    }
+
+    fn add(&mut self, para: &Paragraph) {
+        let text: String = para.iter_text().map(|text| &**text).collect();
+
+        if text.is_empty() {
+            return;
+        }
+
+        self.words += text.split_whitespace().count();
+        self.characters += text.chars().filter(|c| !c.is_whitespace()).count();
+        self.characters_with_spaces += text.chars().count();
+        self.paragraphs += 1;
+    }
+}
+
 impl<'a> XmlWrite for App<'a> {
     fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
         let App {
@@ -81,6 +199,8 @@ impl<'a> XmlWrite for App<'a> {
             lines,
             paragraphs,
             scale_crop,
+            heading_pairs,
+            titles_of_parts,
             company,
             links_up_to_date,
             characters_with_spaces,
@@ -106,6 +226,8 @@ impl<'a> XmlWrite for App<'a> {
             && lines.is_none()
             && paragraphs.is_none()
             && scale_crop.is_none()
+            && heading_pairs.is_none()
+            && titles_of_parts.is_none()
             && company.is_none()
             && links_up_to_date.is_none()
             && characters_with_spaces.is_none()
@@ -120,46 +242,52 @@ impl<'a> XmlWrite for App<'a> {
                 writer.write_flatten_text("Template", val)?;
             }
             if let Some(val) = total_time {
-                writer.write_flatten_text("TotalTime", val)?;
+                writer.write_flatten_text("TotalTime", &val.to_string())?;
             }
             if let Some(val) = pages {
-                writer.write_flatten_text("Pages", val)?;
+                writer.write_flatten_text("Pages", &val.to_string())?;
             }
             if let Some(val) = words {
-                writer.write_flatten_text("Words", val)?;
+                writer.write_flatten_text("Words", &val.to_string())?;
             }
             if let Some(val) = characters {
-                writer.write_flatten_text("Characters", val)?;
+                writer.write_flatten_text("Characters", &val.to_string())?;
             }
             if let Some(val) = application {
                 writer.write_flatten_text("Application", val)?;
             }
             if let Some(val) = doc_security {
-                writer.write_flatten_text("DocSecurity", val)?;
+                writer.write_flatten_text("DocSecurity", &val.to_string())?;
             }
             if let Some(val) = lines {
-                writer.write_flatten_text("Lines", val)?;
+                writer.write_flatten_text("Lines", &val.to_string())?;
             }
             if let Some(val) = paragraphs {
-                writer.write_flatten_text("Paragraphs", val)?;
+                writer.write_flatten_text("Paragraphs", &val.to_string())?;
             }
             if let Some(val) = scale_crop {
-                writer.write_flatten_text("ScaleCrop", val)?;
+                writer.write_flatten_text("ScaleCrop", &val.to_string())?;
+            }
+            if let Some(val) = heading_pairs {
+                val.to_writer(writer)?;
+            }
+            if let Some(val) = titles_of_parts {
+                val.to_writer(writer)?;
             }
             if let Some(val) = company {
                 writer.write_flatten_text("Company", val)?;
             }
             if let Some(val) = links_up_to_date {
-                writer.write_flatten_text("LinksUpToDate", val)?;
+                writer.write_flatten_text("LinksUpToDate", &val.to_string())?;
             }
             if let Some(val) = characters_with_spaces {
-                writer.write_flatten_text("CharactersWithSpaces", val)?;
+                writer.write_flatten_text("CharactersWithSpaces", &val.to_string())?;
             }
             if let Some(val) = shared_doc {
-                writer.write_flatten_text("SharedDoc", val)?;
+                writer.write_flatten_text("SharedDoc", &val.to_string())?;
             }
             if let Some(val) = hyperlinks_changed {
-                writer.write_flatten_text("HyperlinksChanged", val)?;
+                writer.write_flatten_text("HyperlinksChanged", &val.to_string())?;
             }
             if let Some(val) = app_version {
                 writer.write_flatten_text("AppVersion", val)?;
@@ -172,3 +300,245 @@ impl<'a> XmlWrite for App<'a> {
         Ok(())
     }
 }
+
+/// Heading Pairs
+///
+/// Specifies the groups of document parts, like `Title` or `Headings`, and
+/// the number of parts in each group. The titles of the parts are listed in
+/// the same order in [`TitlesOfParts`].
+///
+/// [`TitlesOfParts`]: struct.TitlesOfParts.html
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct HeadingPairs<'a> {
+    pub pairs: Vec<HeadingPair<'a>>,
+}
+
+impl<'a> HeadingPairs<'a> {
+    pub fn push(mut self, pair: HeadingPair<'a>) -> Self {
+        self.pairs.push(pair);
+        self
+    }
+}
+
+/// A group of document parts
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct HeadingPair<'a> {
+    /// Specifies the name of the group.
+    pub name: Cow<'a, str>,
+    /// Specifies the number of parts in the group.
+    pub count: usize,
+}
+
+impl<'a> HeadingPair<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(name: T, count: usize) -> Self {
+        HeadingPair {
+            name: name.into(),
+            count,
+        }
+    }
+}
+
+impl<'i: 'a, 'a> XmlRead<'i> for HeadingPairs<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let RawHeadingPairs { vector } = RawHeadingPairs::from_reader(reader)?;
+
+        let mut pairs = Vec::new();
+        let mut name = None;
+        for variant in vector.variants {
+            if let Some(val) = variant.lpstr.or(variant.lpwstr) {
+                name = Some(val);
+            } else if let (Some(count), Some(name)) = (variant.i4, name.take()) {
+                // negative counts are invalid, the group is skipped
+                if let Ok(count) = usize::try_from(count) {
+                    pairs.push(HeadingPair { name, count });
+                }
+            }
+        }
+
+        Ok(HeadingPairs { pairs })
+    }
+}
+
+impl<'a> XmlWrite for HeadingPairs<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        writer.write_element_start("HeadingPairs")?;
+        writer.write_element_end_open()?;
+
+        writer.write_element_start("vt:vector")?;
+        writer.write_attribute("size", &(self.pairs.len() * 2).to_string())?;
+        writer.write_attribute("baseType", "variant")?;
+        writer.write_element_end_open()?;
+
+        for pair in &self.pairs {
+            writer.write_element_start("vt:variant")?;
+            writer.write_element_end_open()?;
+            writer.write_flatten_text("vt:lpstr", &pair.name)?;
+            writer.write_element_end_close("vt:variant")?;
+
+            writer.write_element_start("vt:variant")?;
+            writer.write_element_end_open()?;
+            writer.write_flatten_text("vt:i4", &pair.count.to_string())?;
+            writer.write_element_end_close("vt:variant")?;
+        }
+
+        writer.write_element_end_close("vt:vector")?;
+        writer.write_element_end_close("HeadingPairs")?;
+
+        Ok(())
+    }
+}
+
+/// Titles of Parts
+///
+/// Specifies the title of each document part.
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct TitlesOfParts<'a> {
+    pub titles: Vec<Cow<'a, str>>,
+}
+
+impl<'a> TitlesOfParts<'a> {
+    pub fn push<T: Into<Cow<'a, str>>>(mut self, title: T) -> Self {
+        self.titles.push(title.into());
+        self
+    }
+}
+
+impl<'i: 'a, 'a> XmlRead<'i> for TitlesOfParts<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let RawTitlesOfParts { vector } = RawTitlesOfParts::from_reader(reader)?;
+
+        let mut titles = vector.lpstr;
+        titles.extend(vector.lpwstr);
+
+        Ok(TitlesOfParts { titles })
+    }
+}
+
+impl<'a> XmlWrite for TitlesOfParts<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        writer.write_element_start("TitlesOfParts")?;
+        writer.write_element_end_open()?;
+
+        writer.write_element_start("vt:vector")?;
+        writer.write_attribute("size", &self.titles.len().to_string())?;
+        writer.write_attribute("baseType", "lpstr")?;
+        writer.write_element_end_open()?;
+
+        for title in &self.titles {
+            writer.write_flatten_text("vt:lpstr", title)?;
+        }
+
+        writer.write_element_end_close("vt:vector")?;
+        writer.write_element_end_close("TitlesOfParts")?;
+
+        Ok(())
+    }
+}
+
+#[derive(XmlRead)]
+#[xml(tag = "HeadingPairs")]
+struct RawHeadingPairs<'a> {
+    #[xml(default, child = "vt:vector")]
+    vector: RawVector<'a>,
+}
+
+#[derive(XmlRead)]
+#[xml(tag = "TitlesOfParts")]
+struct RawTitlesOfParts<'a> {
+    #[xml(default, child = "vt:vector")]
+    vector: RawVector<'a>,
+}
+
+#[derive(Default, XmlRead)]
+#[xml(tag = "vt:vector")]
+struct RawVector<'a> {
+    #[xml(child = "vt:variant")]
+    variants: Vec<RawVariant<'a>>,
+    #[xml(flatten_text = "vt:lpstr")]
+    lpstr: Vec<Cow<'a, str>>,
+    #[xml(flatten_text = "vt:lpwstr")]
+    lpwstr: Vec<Cow<'a, str>>,
+}
+
+#[derive(XmlRead)]
+#[xml(tag = "vt:variant")]
+struct RawVariant<'a> {
+    #[xml(flatten_text = "vt:lpstr")]
+    lpstr: Option<Cow<'a, str>>,
+    #[xml(flatten_text = "vt:lpwstr")]
+    lpwstr: Option<Cow<'a, str>>,
+    #[xml(flatten_text = "vt:i4")]
+    i4: Option<i32>,
+}
+
+#[test]
+fn update_statistics() {
+    use crate::document::TableRow;
+
+    let mut document = Document::default();
+    document
+        .push(Paragraph::default().push_text("Lorem ipsum dolor"))
+        .push(Paragraph::default())
+        .push(
+            Table::default().push_row(
+                TableRow::default()
+                    .push_cell(Paragraph::default().push_text("sit amet"))
+                    .push_cell(
+                        Table::default().push_row(
+                            TableRow::default()
+                                .push_cell(Paragraph::default().push_text("consectetur")),
+                        ),
+                    ),
+            ),
+        );
+
+    let mut app = App::default();
+    app.update_statistics(&document);
+
+    assert_eq!(app.words, Some(6));
+    assert_eq!(app.characters, Some(33));
+    assert_eq!(app.characters_with_spaces, Some(36));
+    assert_eq!(app.paragraphs, Some(3));
+    assert_eq!(app.lines, Some(3));
+}
+
+#[test]
+fn negative_heading_pair_count() -> XmlResult<()> {
+    let pairs = HeadingPairs::from_str(
+        r#"<HeadingPairs><vt:vector size="4" baseType="variant"><vt:variant><vt:lpstr>Title</vt:lpstr></vt:variant><vt:variant><vt:i4>-1</vt:i4></vt:variant><vt:variant><vt:lpstr>Headings</vt:lpstr></vt:variant><vt:variant><vt:i4>2</vt:i4></vt:variant></vt:vector></HeadingPairs>"#,
+    )?;
+
+    assert_eq!(pairs.pairs, [HeadingPair::new("Headings", 2)]);
+
+    Ok(())
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes""#;
+
+__xml_test_suites!(
+    App,
+    App::default(),
+    format!(
+        r#"<Properties {}><Application>docx-rs</Application></Properties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+    App::default().pages(2usize).scale_crop(false),
+    format!(
+        r#"<Properties {}><Pages>2</Pages><Application>docx-rs</Application><ScaleCrop>false</ScaleCrop></Properties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+    App::default()
+        .heading_pairs(HeadingPairs::default().push(HeadingPair::new("Title", 1)))
+        .titles_of_parts(TitlesOfParts::default().push("Contract")),
+    format!(
+        r#"<Properties {}><Application>docx-rs</Application><HeadingPairs><vt:vector size="2" baseType="variant"><vt:variant><vt:lpstr>Title</vt:lpstr></vt:variant><vt:variant><vt:i4>1</vt:i4></vt:variant></vt:vector></HeadingPairs><TitlesOfParts><vt:vector size="1" baseType="lpstr"><vt:lpstr>Contract</vt:lpstr></vt:vector></TitlesOfParts></Properties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
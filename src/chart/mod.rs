@@ -0,0 +1,700 @@
+//! Chart parts
+//!
+//! The corresponding ZIP items are `/word/charts/chart{n}.xml`, along with
+//! the embedded workbooks `/word/embeddings/Microsoft_Excel_Worksheet{n}.xlsx`.
+//!
+//! Charts are generated from series of values over categories with
+//! `Docx::add_chart`, which returns a drawing referring to the chart part.
+
+mod part;
+mod workbook;
+
+pub use self::part::*;
+
+pub(crate) use self::workbook::workbook;
+
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlResult, XmlWrite, XmlWriter};
+
+use crate::{
+    __setter, __string_enum,
+    error::{DocxError, DocxResult},
+    schema::{SCHEMA_CHART, SCHEMA_DRAWING_MAIN, SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS},
+};
+
+/// A chart of series of values over categories
+///
+/// Values of scatter charts are plotted over `x_values` instead of the
+/// categories. The data is also written into the embedded workbook, with
+/// the categories in the first column and a column for each series.
+///
+/// ```rust
+/// use docx::chart::*;
+///
+/// let chart = Chart::new(ChartKind::Column)
+///     .title("Revenue")
+///     .categories(vec!["Q1", "Q2", "Q3"])
+///     .push(Series::new("2023", vec![1.0, 2.5, 3.0]).color("4472C4"))
+///     .push(Series::new("2024", vec![2.0, 3.5, 4.0]).color("ED7D31"))
+///     .category_axis_title("Quarter")
+///     .value_axis_title("Millions")
+///     .legend(LegendPosition::Bottom);
+///
+/// let scatter = Chart::new(ChartKind::Scatter)
+///     .x_values(vec![1.0, 2.0, 4.0])
+///     .push(Series::new("Samples", vec![2.0, 4.1, 7.9]));
+/// ```
+#[derive(Debug)]
+pub struct Chart<'a> {
+    /// Specifies the kind of the chart.
+    pub kind: ChartKind,
+    /// Specifies the title of the chart.
+    pub title: Option<Cow<'a, str>>,
+    /// Specifies the categories, except for scatter charts.
+    pub categories: Vec<Cow<'a, str>>,
+    /// Specifies the x values of scatter charts.
+    pub x_values: Vec<f64>,
+    /// Specifies the series.
+    pub series: Vec<Series<'a>>,
+    /// Specifies the title of the category axis, or the x axis of scatter
+    /// charts.
+    pub category_axis_title: Option<Cow<'a, str>>,
+    /// Specifies the title of the value axis.
+    pub value_axis_title: Option<Cow<'a, str>>,
+    /// Specifies the position of the legend, or no legend if `None`.
+    pub legend: Option<LegendPosition>,
+    /// Specifies the id of the relationship to the embedded workbook.
+    ///
+    /// It's set by `Docx::add_chart`.
+    pub external_data: Option<Cow<'a, str>>,
+}
+
+impl<'a> Chart<'a> {
+    __setter!(title: Option<Cow<'a, str>>);
+    __setter!(category_axis_title: Option<Cow<'a, str>>);
+    __setter!(value_axis_title: Option<Cow<'a, str>>);
+    __setter!(legend: Option<LegendPosition>);
+
+    /// Creates an empty chart with the legend on the right
+    pub fn new(kind: ChartKind) -> Self {
+        Chart {
+            kind,
+            title: None,
+            categories: Vec::new(),
+            x_values: Vec::new(),
+            series: Vec::new(),
+            category_axis_title: None,
+            value_axis_title: None,
+            legend: Some(LegendPosition::Right),
+            external_data: None,
+        }
+    }
+
+    /// Specifies the categories
+    pub fn categories<I, T>(mut self, categories: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.categories = categories.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Specifies the x values of a scatter chart
+    pub fn x_values<T: Into<Vec<f64>>>(mut self, x_values: T) -> Self {
+        self.x_values = x_values.into();
+        self
+    }
+
+    /// Appends a series
+    pub fn push(mut self, series: Series<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Removes the legend
+    pub fn no_legend(mut self) -> Self {
+        self.legend = None;
+        self
+    }
+
+    /// Checks that the chart has data and that its values are finite
+    pub(crate) fn check(&self) -> DocxResult<()> {
+        if self.rows() == 0 || self.series.is_empty() {
+            return Err(DocxError::Chart("chart has no data".into()));
+        }
+
+        let values = self
+            .series
+            .iter()
+            .flat_map(|series| &series.values)
+            .chain(&self.x_values);
+        for value in values {
+            if !value.is_finite() {
+                return Err(DocxError::Chart(format!(
+                    "chart has a non-finite value {}",
+                    value
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of rows of data, i.e. the number of categories
+    /// or x values
+    fn rows(&self) -> usize {
+        if self.kind == ChartKind::Scatter {
+            self.x_values.len()
+        } else {
+            self.categories.len()
+        }
+    }
+}
+
+/// A series of values
+#[derive(Debug)]
+pub struct Series<'a> {
+    /// Specifies the name of the series.
+    pub name: Cow<'a, str>,
+    /// Specifies the values, one for each category or x value.
+    pub values: Vec<f64>,
+    /// Specifies the color in hex, e.g. `4472C4`.
+    ///
+    /// Slices of pie charts are colored automatically, so it's ignored.
+    pub color: Option<Cow<'a, str>>,
+}
+
+impl<'a> Series<'a> {
+    __setter!(color: Option<Cow<'a, str>>);
+
+    pub fn new<N, V>(name: N, values: V) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Vec<f64>>,
+    {
+        Series {
+            name: name.into(),
+            values: values.into(),
+            color: None,
+        }
+    }
+}
+
+/// The kind of a chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartKind {
+    /// Horizontal bars
+    Bar,
+    /// Vertical bars
+    Column,
+    Line,
+    Pie,
+    Scatter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LegendPosition {
+    Bottom,
+    Left,
+    Right,
+    Top,
+    TopRight,
+}
+
+__string_enum! {
+    LegendPosition {
+        Bottom = "b",
+        Left = "l",
+        Right = "r",
+        Top = "t",
+        TopRight = "tr",
+    }
+}
+
+// ids of the axes, which only have to be unique within the chart
+const CATEGORY_AXIS_ID: &str = "100000001";
+const VALUE_AXIS_ID: &str = "100000002";
+
+impl<'a> XmlWrite for Chart<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        log::debug!("[Chart] Started writing.");
+
+        writer.write_element_start("c:chartSpace")?;
+        writer.write_attribute("xmlns:c", SCHEMA_CHART)?;
+        writer.write_attribute("xmlns:a", SCHEMA_DRAWING_MAIN)?;
+        writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+        writer.write_element_end_open()?;
+
+        write_value(writer, "c:roundedCorners", "0")?;
+
+        writer.write_element_start("c:chart")?;
+        writer.write_element_end_open()?;
+
+        match &self.title {
+            Some(title) => write_title(writer, title, false)?,
+            // otherwise Word titles charts of a single series after it
+            None => write_value(writer, "c:autoTitleDeleted", "1")?,
+        }
+
+        writer.write_element_start("c:plotArea")?;
+        writer.write_element_end_open()?;
+        writer.write_element_start("c:layout")?;
+        writer.write_element_end_empty()?;
+
+        self.write_plot(writer)?;
+
+        match self.kind {
+            ChartKind::Pie => (),
+            ChartKind::Scatter => {
+                self.write_axis(writer, "c:valAx", CATEGORY_AXIS_ID, VALUE_AXIS_ID)?;
+                self.write_axis(writer, "c:valAx", VALUE_AXIS_ID, CATEGORY_AXIS_ID)?;
+            }
+            _ => {
+                self.write_axis(writer, "c:catAx", CATEGORY_AXIS_ID, VALUE_AXIS_ID)?;
+                self.write_axis(writer, "c:valAx", VALUE_AXIS_ID, CATEGORY_AXIS_ID)?;
+            }
+        }
+
+        writer.write_element_end_close("c:plotArea")?;
+
+        if let Some(position) = self.legend {
+            writer.write_element_start("c:legend")?;
+            writer.write_element_end_open()?;
+            write_value(writer, "c:legendPos", &position.to_string())?;
+            write_value(writer, "c:overlay", "0")?;
+            writer.write_element_end_close("c:legend")?;
+        }
+
+        write_value(writer, "c:plotVisOnly", "1")?;
+        write_value(writer, "c:dispBlanksAs", "gap")?;
+
+        writer.write_element_end_close("c:chart")?;
+
+        if let Some(id) = &self.external_data {
+            writer.write_element_start("c:externalData")?;
+            writer.write_attribute("r:id", id)?;
+            writer.write_element_end_open()?;
+            write_value(writer, "c:autoUpdate", "0")?;
+            writer.write_element_end_close("c:externalData")?;
+        }
+
+        writer.write_element_end_close("c:chartSpace")?;
+
+        log::debug!("[Chart] Finished writing.");
+
+        Ok(())
+    }
+}
+
+impl<'a> Chart<'a> {
+    fn write_plot<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let tag = match self.kind {
+            ChartKind::Bar | ChartKind::Column => "c:barChart",
+            ChartKind::Line => "c:lineChart",
+            ChartKind::Pie => "c:pieChart",
+            ChartKind::Scatter => "c:scatterChart",
+        };
+
+        writer.write_element_start(tag)?;
+        writer.write_element_end_open()?;
+
+        match self.kind {
+            ChartKind::Bar | ChartKind::Column => {
+                let direction = if self.kind == ChartKind::Bar {
+                    "bar"
+                } else {
+                    "col"
+                };
+                write_value(writer, "c:barDir", direction)?;
+                write_value(writer, "c:grouping", "clustered")?;
+                write_value(writer, "c:varyColors", "0")?;
+            }
+            ChartKind::Line => {
+                write_value(writer, "c:grouping", "standard")?;
+                write_value(writer, "c:varyColors", "0")?;
+            }
+            ChartKind::Pie => write_value(writer, "c:varyColors", "1")?,
+            ChartKind::Scatter => {
+                write_value(writer, "c:scatterStyle", "lineMarker")?;
+                write_value(writer, "c:varyColors", "0")?;
+            }
+        }
+
+        for (i, series) in self.series.iter().enumerate() {
+            self.write_series(writer, i, series)?;
+        }
+
+        match self.kind {
+            ChartKind::Bar | ChartKind::Column => write_value(writer, "c:gapWidth", "150")?,
+            ChartKind::Line => write_value(writer, "c:marker", "1")?,
+            ChartKind::Pie => write_value(writer, "c:firstSliceAng", "0")?,
+            ChartKind::Scatter => (),
+        }
+
+        if self.kind != ChartKind::Pie {
+            write_value(writer, "c:axId", CATEGORY_AXIS_ID)?;
+            write_value(writer, "c:axId", VALUE_AXIS_ID)?;
+        }
+
+        writer.write_element_end_close(tag)?;
+
+        Ok(())
+    }
+
+    fn write_series<W: Write>(
+        &self,
+        writer: &mut XmlWriter<W>,
+        index: usize,
+        series: &Series,
+    ) -> XmlResult<()> {
+        let rows = self.rows();
+        let column = column_name(index + 1);
+
+        writer.write_element_start("c:ser")?;
+        writer.write_element_end_open()?;
+
+        write_value(writer, "c:idx", &index.to_string())?;
+        write_value(writer, "c:order", &index.to_string())?;
+
+        writer.write_element_start("c:tx")?;
+        writer.write_element_end_open()?;
+        write_string_reference(
+            writer,
+            &format!("Sheet1!${}$1", column),
+            std::iter::once(&*series.name),
+        )?;
+        writer.write_element_end_close("c:tx")?;
+
+        match (&series.color, self.kind) {
+            (_, ChartKind::Pie) | (None, _) => (),
+            (Some(color), ChartKind::Line) | (Some(color), ChartKind::Scatter) => {
+                writer.write_element_start("c:spPr")?;
+                writer.write_element_end_open()?;
+                writer.write_element_start("a:ln")?;
+                writer.write_attribute("w", "28575")?;
+                writer.write_element_end_open()?;
+                if self.kind == ChartKind::Scatter {
+                    // scatter charts only show the markers
+                    writer.write_element_start("a:noFill")?;
+                    writer.write_element_end_empty()?;
+                } else {
+                    write_solid_fill(writer, color)?;
+                }
+                writer.write_element_end_close("a:ln")?;
+                writer.write_element_end_close("c:spPr")?;
+            }
+            (Some(color), _) => {
+                writer.write_element_start("c:spPr")?;
+                writer.write_element_end_open()?;
+                write_solid_fill(writer, color)?;
+                writer.write_element_end_close("c:spPr")?;
+            }
+        }
+
+        match self.kind {
+            ChartKind::Line => {
+                writer.write_element_start("c:marker")?;
+                writer.write_element_end_open()?;
+                write_value(writer, "c:symbol", "none")?;
+                writer.write_element_end_close("c:marker")?;
+            }
+            ChartKind::Scatter => {
+                writer.write_element_start("c:marker")?;
+                writer.write_element_end_open()?;
+                write_value(writer, "c:symbol", "circle")?;
+                write_value(writer, "c:size", "5")?;
+                if let Some(color) = &series.color {
+                    writer.write_element_start("c:spPr")?;
+                    writer.write_element_end_open()?;
+                    write_solid_fill(writer, color)?;
+                    writer.write_element_end_close("c:spPr")?;
+                }
+                writer.write_element_end_close("c:marker")?;
+            }
+            _ => (),
+        }
+
+        if self.kind == ChartKind::Bar || self.kind == ChartKind::Column {
+            write_value(writer, "c:invertIfNegative", "0")?;
+        }
+
+        let (category_tag, value_tag) = if self.kind == ChartKind::Scatter {
+            ("c:xVal", "c:yVal")
+        } else {
+            ("c:cat", "c:val")
+        };
+
+        writer.write_element_start(category_tag)?;
+        writer.write_element_end_open()?;
+        // the range spans a row at least, so that it isn't reversed
+        let last_row = rows.max(1) + 1;
+        let range = format!("Sheet1!$A$2:$A${}", last_row);
+        if self.kind == ChartKind::Scatter {
+            write_number_reference(writer, &range, rows, &self.x_values)?;
+        } else {
+            let categories = self.categories.iter().map(|category| &**category);
+            write_string_reference(writer, &range, categories)?;
+        }
+        writer.write_element_end_close(category_tag)?;
+
+        writer.write_element_start(value_tag)?;
+        writer.write_element_end_open()?;
+        write_number_reference(
+            writer,
+            &format!("Sheet1!${}$2:${}${}", column, column, last_row),
+            rows,
+            &series.values,
+        )?;
+        writer.write_element_end_close(value_tag)?;
+
+        if self.kind == ChartKind::Line || self.kind == ChartKind::Scatter {
+            write_value(writer, "c:smooth", "0")?;
+        }
+
+        writer.write_element_end_close("c:ser")?;
+
+        Ok(())
+    }
+
+    fn write_axis<W: Write>(
+        &self,
+        writer: &mut XmlWriter<W>,
+        tag: &str,
+        id: &str,
+        cross_id: &str,
+    ) -> XmlResult<()> {
+        let is_category = id == CATEGORY_AXIS_ID;
+        // the category axis of bar charts is vertical
+        let vertical = is_category == (self.kind == ChartKind::Bar);
+
+        writer.write_element_start(tag)?;
+        writer.write_element_end_open()?;
+
+        write_value(writer, "c:axId", id)?;
+        writer.write_element_start("c:scaling")?;
+        writer.write_element_end_open()?;
+        write_value(writer, "c:orientation", "minMax")?;
+        writer.write_element_end_close("c:scaling")?;
+        write_value(writer, "c:delete", "0")?;
+        write_value(writer, "c:axPos", if vertical { "l" } else { "b" })?;
+
+        if !is_category {
+            writer.write_element_start("c:majorGridlines")?;
+            writer.write_element_end_empty()?;
+        }
+
+        let title = if is_category {
+            &self.category_axis_title
+        } else {
+            &self.value_axis_title
+        };
+        if let Some(title) = title {
+            write_title(writer, title, vertical)?;
+        }
+
+        write_value(writer, "c:majorTickMark", "out")?;
+        write_value(writer, "c:minorTickMark", "none")?;
+        write_value(writer, "c:tickLblPos", "nextTo")?;
+        write_value(writer, "c:crossAx", cross_id)?;
+        write_value(writer, "c:crosses", "autoZero")?;
+
+        if tag == "c:catAx" {
+            write_value(writer, "c:auto", "1")?;
+            write_value(writer, "c:lblAlgn", "ctr")?;
+            write_value(writer, "c:lblOffset", "100")?;
+        } else if self.kind == ChartKind::Scatter && is_category {
+            write_value(writer, "c:crossBetween", "midCat")?;
+        } else {
+            write_value(writer, "c:crossBetween", "between")?;
+        }
+
+        writer.write_element_end_close(tag)?;
+
+        Ok(())
+    }
+}
+
+/// Writes an element with only a `val` attribute
+fn write_value<W: Write>(writer: &mut XmlWriter<W>, tag: &str, value: &str) -> XmlResult<()> {
+    writer.write_element_start(tag)?;
+    writer.write_attribute("val", value)?;
+    writer.write_element_end_empty()?;
+    Ok(())
+}
+
+fn write_solid_fill<W: Write>(writer: &mut XmlWriter<W>, color: &str) -> XmlResult<()> {
+    writer.write_element_start("a:solidFill")?;
+    writer.write_element_end_open()?;
+    write_value(writer, "a:srgbClr", color)?;
+    writer.write_element_end_close("a:solidFill")?;
+    Ok(())
+}
+
+/// Writes the title of a chart or an axis, rotated if it's vertical
+fn write_title<W: Write>(writer: &mut XmlWriter<W>, title: &str, vertical: bool) -> XmlResult<()> {
+    writer.write_element_start("c:title")?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("c:tx")?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("c:rich")?;
+    writer.write_element_end_open()?;
+
+    writer.write_element_start("a:bodyPr")?;
+    if vertical {
+        writer.write_attribute("rot", "-5400000")?;
+        writer.write_attribute("vert", "horz")?;
+    }
+    writer.write_element_end_empty()?;
+    writer.write_element_start("a:p")?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("a:r")?;
+    writer.write_element_end_open()?;
+    writer.write_flatten_text("a:t", title)?;
+    writer.write_element_end_close("a:r")?;
+    writer.write_element_end_close("a:p")?;
+
+    writer.write_element_end_close("c:rich")?;
+    writer.write_element_end_close("c:tx")?;
+    write_value(writer, "c:overlay", "0")?;
+    writer.write_element_end_close("c:title")?;
+    Ok(())
+}
+
+/// Writes a reference to cells of strings, along with the cached strings
+fn write_string_reference<'s, W, I>(
+    writer: &mut XmlWriter<W>,
+    range: &str,
+    values: I,
+) -> XmlResult<()>
+where
+    W: Write,
+    I: ExactSizeIterator<Item = &'s str>,
+{
+    writer.write_element_start("c:strRef")?;
+    writer.write_element_end_open()?;
+    writer.write_flatten_text("c:f", range)?;
+    writer.write_element_start("c:strCache")?;
+    writer.write_element_end_open()?;
+    write_value(writer, "c:ptCount", &values.len().to_string())?;
+    for (i, value) in values.enumerate() {
+        writer.write_element_start("c:pt")?;
+        writer.write_attribute("idx", &i.to_string())?;
+        writer.write_element_end_open()?;
+        writer.write_flatten_text("c:v", value)?;
+        writer.write_element_end_close("c:pt")?;
+    }
+    writer.write_element_end_close("c:strCache")?;
+    writer.write_element_end_close("c:strRef")?;
+    Ok(())
+}
+
+/// Writes a reference to cells of numbers, along with the cached numbers
+///
+/// Missing and non-finite values are left as blanks.
+fn write_number_reference<W: Write>(
+    writer: &mut XmlWriter<W>,
+    range: &str,
+    count: usize,
+    values: &[f64],
+) -> XmlResult<()> {
+    writer.write_element_start("c:numRef")?;
+    writer.write_element_end_open()?;
+    writer.write_flatten_text("c:f", range)?;
+    writer.write_element_start("c:numCache")?;
+    writer.write_element_end_open()?;
+    writer.write_flatten_text("c:formatCode", "General")?;
+    write_value(writer, "c:ptCount", &count.to_string())?;
+    for (i, value) in values.iter().take(count).enumerate() {
+        if !value.is_finite() {
+            continue;
+        }
+        writer.write_element_start("c:pt")?;
+        writer.write_attribute("idx", &i.to_string())?;
+        writer.write_element_end_open()?;
+        writer.write_flatten_text("c:v", &value.to_string())?;
+        writer.write_element_end_close("c:pt")?;
+    }
+    writer.write_element_end_close("c:numCache")?;
+    writer.write_element_end_close("c:numRef")?;
+    Ok(())
+}
+
+/// Returns the name of the column at the zero-based index, e.g. `AA` for 26
+pub(crate) fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+#[test]
+fn column_names() {
+    assert_eq!(column_name(0), "A");
+    assert_eq!(column_name(25), "Z");
+    assert_eq!(column_name(26), "AA");
+    assert_eq!(column_name(701), "ZZ");
+    assert_eq!(column_name(702), "AAA");
+}
+
+#[test]
+fn chart_space() -> XmlResult<()> {
+    let chart = Chart::new(ChartKind::Column)
+        .categories(vec!["Q1", "Q2"])
+        .push(Series::new("Sales", vec![1.0, 2.5]).color("4472C4"))
+        .no_legend();
+
+    assert_eq!(
+        chart.to_string()?,
+        format!(
+            r#"<c:chartSpace xmlns:c="{}" xmlns:a="{}" xmlns:r="{}"><c:roundedCorners val="0"/><c:chart><c:autoTitleDeleted val="1"/><c:plotArea><c:layout/><c:barChart><c:barDir val="col"/><c:grouping val="clustered"/><c:varyColors val="0"/><c:ser><c:idx val="0"/><c:order val="0"/><c:tx><c:strRef><c:f>Sheet1!$B$1</c:f><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>Sales</c:v></c:pt></c:strCache></c:strRef></c:tx><c:spPr><a:solidFill><a:srgbClr val="4472C4"/></a:solidFill></c:spPr><c:invertIfNegative val="0"/><c:cat><c:strRef><c:f>Sheet1!$A$2:$A$3</c:f><c:strCache><c:ptCount val="2"/><c:pt idx="0"><c:v>Q1</c:v></c:pt><c:pt idx="1"><c:v>Q2</c:v></c:pt></c:strCache></c:strRef></c:cat><c:val><c:numRef><c:f>Sheet1!$B$2:$B$3</c:f><c:numCache><c:formatCode>General</c:formatCode><c:ptCount val="2"/><c:pt idx="0"><c:v>1</c:v></c:pt><c:pt idx="1"><c:v>2.5</c:v></c:pt></c:numCache></c:numRef></c:val></c:ser><c:gapWidth val="150"/><c:axId val="100000001"/><c:axId val="100000002"/></c:barChart><c:catAx><c:axId val="100000001"/><c:scaling><c:orientation val="minMax"/></c:scaling><c:delete val="0"/><c:axPos val="b"/><c:majorTickMark val="out"/><c:minorTickMark val="none"/><c:tickLblPos val="nextTo"/><c:crossAx val="100000002"/><c:crosses val="autoZero"/><c:auto val="1"/><c:lblAlgn val="ctr"/><c:lblOffset val="100"/></c:catAx><c:valAx><c:axId val="100000002"/><c:scaling><c:orientation val="minMax"/></c:scaling><c:delete val="0"/><c:axPos val="l"/><c:majorGridlines/><c:majorTickMark val="out"/><c:minorTickMark val="none"/><c:tickLblPos val="nextTo"/><c:crossAx val="100000001"/><c:crosses val="autoZero"/><c:crossBetween val="between"/></c:valAx></c:plotArea><c:plotVisOnly val="1"/><c:dispBlanksAs val="gap"/></c:chart></c:chartSpace>"#,
+            SCHEMA_CHART, SCHEMA_DRAWING_MAIN, SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS
+        )
+    );
+
+    let pie = Chart::new(ChartKind::Pie)
+        .title("Share")
+        .categories(vec!["A", "B"])
+        .push(Series::new("Share", vec![0.4, 0.6]).color("FF0000"))
+        .to_string()?;
+    assert!(pie.contains(r#"<c:title><c:tx><c:rich><a:bodyPr/><a:p><a:r><a:t>Share</a:t></a:r></a:p></c:rich></c:tx><c:overlay val="0"/></c:title>"#));
+    assert!(pie.contains(r#"<c:pieChart><c:varyColors val="1"/>"#));
+    // slices are colored automatically and pie charts have no axes
+    assert!(!pie.contains("c:spPr"));
+    assert!(!pie.contains("c:axId"));
+    assert!(pie.contains(r#"<c:legend><c:legendPos val="r"/><c:overlay val="0"/></c:legend>"#));
+
+    let scatter = Chart::new(ChartKind::Scatter)
+        .x_values(vec![1.0, 2.0])
+        .push(Series::new("Samples", vec![3.0]))
+        .value_axis_title("y")
+        .to_string()?;
+    assert!(scatter.contains(r#"<c:xVal><c:numRef><c:f>Sheet1!$A$2:$A$3</c:f>"#));
+    assert!(scatter.contains(r#"<c:yVal><c:numRef><c:f>Sheet1!$B$2:$B$3</c:f><c:numCache><c:formatCode>General</c:formatCode><c:ptCount val="2"/><c:pt idx="0"><c:v>3</c:v></c:pt></c:numCache>"#));
+    assert!(scatter.contains(r#"<a:bodyPr rot="-5400000" vert="horz"/><a:p><a:r><a:t>y</a:t>"#));
+    assert!(scatter.contains(r#"<c:crossBetween val="midCat"/>"#));
+
+    // non-finite values are left as blanks
+    let line = Chart::new(ChartKind::Line)
+        .categories(vec!["A", "B"])
+        .push(Series::new("Samples", vec![1.0, f64::NAN]))
+        .to_string()?;
+    assert!(line.contains(r#"<c:ptCount val="2"/><c:pt idx="0"><c:v>1</c:v></c:pt></c:numCache>"#));
+    assert!(!line.contains("NaN"));
+
+    // the range of an empty chart isn't reversed
+    let empty = Chart::new(ChartKind::Line)
+        .push(Series::new("Samples", Vec::new()))
+        .to_string()?;
+    assert!(empty.contains("<c:f>Sheet1!$A$2:$A$2</c:f>"));
+    assert!(Chart::new(ChartKind::Line).check().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use std::borrow::Cow;
+
+use crate::rels::Relationships;
+
+/// A chart part
+///
+/// The content is kept as is, since charts are only generated but not
+/// modified by this crate.
+#[derive(Debug, Default)]
+pub struct ChartPart<'a> {
+    /// Specifies the name of the part, e.g. `word/charts/chart1.xml`.
+    pub name: Cow<'a, str>,
+    /// Specifies the content of the part.
+    pub data: Cow<'a, str>,
+    /// Specifies the part-level relationships, e.g. to the embedded
+    /// workbook.
+    pub rels: Option<Relationships<'a>>,
+}
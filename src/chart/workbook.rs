@@ -0,0 +1,166 @@
+use std::io::{Cursor, Write};
+use strong_xml::{XmlResult, XmlWrite, XmlWriter};
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    chart::{column_name, Chart, ChartKind},
+    content_type::{ContentTypes, OverrideContentType},
+    error::DocxResult,
+    rels::Relationships,
+    schema::{
+        SCHEMA_OFFICE_DOCUMENT, SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS, SCHEMA_REL_WORKSHEET,
+        SCHEMA_SPREADSHEET_MAIN,
+    },
+};
+
+const CONTENT_TYPE_WORKBOOK: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml";
+const CONTENT_TYPE_WORKSHEET: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml";
+
+/// Creates a SpreadsheetML package containing the data of the chart
+///
+/// The sheet `Sheet1` has the categories, or the x values, in the first
+/// column and a column for each series, with the names of the series in
+/// the first row. It's what the formulas of the chart refer to.
+pub(crate) fn workbook(chart: &Chart) -> DocxResult<Vec<u8>> {
+    let mut writer = XmlWriter::new(ZipWriter::new(Cursor::new(Vec::new())));
+
+    let content_types = ContentTypes {
+        defaults: ContentTypes::default().defaults,
+        overrides: vec![
+            OverrideContentType {
+                part: "/xl/workbook.xml".into(),
+                ty: CONTENT_TYPE_WORKBOOK.into(),
+            },
+            OverrideContentType {
+                part: "/xl/worksheets/sheet1.xml".into(),
+                ty: CONTENT_TYPE_WORKSHEET.into(),
+            },
+        ],
+    };
+    start_part(&mut writer, "[Content_Types].xml")?;
+    content_types.to_writer(&mut writer)?;
+
+    let mut rels = Relationships::default();
+    rels.add_rel(SCHEMA_OFFICE_DOCUMENT, "xl/workbook.xml");
+    start_part(&mut writer, "_rels/.rels")?;
+    rels.to_writer(&mut writer)?;
+
+    let mut workbook_rels = Relationships::default();
+    let id = workbook_rels
+        .add_rel(SCHEMA_REL_WORKSHEET, "worksheets/sheet1.xml")
+        .to_owned();
+    start_part(&mut writer, "xl/_rels/workbook.xml.rels")?;
+    workbook_rels.to_writer(&mut writer)?;
+
+    start_part(&mut writer, "xl/workbook.xml")?;
+    write_workbook(&mut writer, &id)?;
+
+    start_part(&mut writer, "xl/worksheets/sheet1.xml")?;
+    write_worksheet(&mut writer, chart)?;
+
+    Ok(writer.inner.finish()?.into_inner())
+}
+
+fn start_part<W: Write + std::io::Seek>(
+    writer: &mut XmlWriter<ZipWriter<W>>,
+    name: &str,
+) -> DocxResult<()> {
+    let opt = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    Ok(writer.inner.start_file(name, opt)?)
+}
+
+fn write_workbook<W: Write>(writer: &mut XmlWriter<W>, id: &str) -> XmlResult<()> {
+    writer.write_element_start("workbook")?;
+    writer.write_attribute("xmlns", SCHEMA_SPREADSHEET_MAIN)?;
+    writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("sheets")?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("sheet")?;
+    writer.write_attribute("name", "Sheet1")?;
+    writer.write_attribute("sheetId", "1")?;
+    writer.write_attribute("r:id", id)?;
+    writer.write_element_end_empty()?;
+    writer.write_element_end_close("sheets")?;
+    writer.write_element_end_close("workbook")?;
+    Ok(())
+}
+
+fn write_worksheet<W: Write>(writer: &mut XmlWriter<W>, chart: &Chart) -> XmlResult<()> {
+    writer.write_element_start("worksheet")?;
+    writer.write_attribute("xmlns", SCHEMA_SPREADSHEET_MAIN)?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("sheetData")?;
+    writer.write_element_end_open()?;
+
+    start_row(writer, 1)?;
+    for (i, series) in chart.series.iter().enumerate() {
+        write_string_cell(writer, &format!("{}1", column_name(i + 1)), &series.name)?;
+    }
+    writer.write_element_end_close("row")?;
+
+    for row in 0..chart.rows() {
+        start_row(writer, row + 2)?;
+        let reference = format!("A{}", row + 2);
+        if chart.kind == ChartKind::Scatter {
+            write_number_cell(writer, &reference, chart.x_values[row])?;
+        } else {
+            write_string_cell(writer, &reference, &chart.categories[row])?;
+        }
+        for (i, series) in chart.series.iter().enumerate() {
+            if let Some(value) = series.values.get(row) {
+                let reference = format!("{}{}", column_name(i + 1), row + 2);
+                write_number_cell(writer, &reference, *value)?;
+            }
+        }
+        writer.write_element_end_close("row")?;
+    }
+
+    writer.write_element_end_close("sheetData")?;
+    writer.write_element_end_close("worksheet")?;
+    Ok(())
+}
+
+fn start_row<W: Write>(writer: &mut XmlWriter<W>, row: usize) -> XmlResult<()> {
+    writer.write_element_start("row")?;
+    writer.write_attribute("r", &row.to_string())?;
+    writer.write_element_end_open()?;
+    Ok(())
+}
+
+fn write_string_cell<W: Write>(
+    writer: &mut XmlWriter<W>,
+    reference: &str,
+    value: &str,
+) -> XmlResult<()> {
+    writer.write_element_start("c")?;
+    writer.write_attribute("r", reference)?;
+    writer.write_attribute("t", "inlineStr")?;
+    writer.write_element_end_open()?;
+    writer.write_element_start("is")?;
+    writer.write_element_end_open()?;
+    writer.write_flatten_text("t", value)?;
+    writer.write_element_end_close("is")?;
+    writer.write_element_end_close("c")?;
+    Ok(())
+}
+
+/// Writes a cell of the number, non-finite numbers are left as blanks
+fn write_number_cell<W: Write>(
+    writer: &mut XmlWriter<W>,
+    reference: &str,
+    value: f64,
+) -> XmlResult<()> {
+    if !value.is_finite() {
+        return Ok(());
+    }
+
+    writer.write_element_start("c")?;
+    writer.write_attribute("r", reference)?;
+    writer.write_element_end_open()?;
+    writer.write_flatten_text("v", &value.to_string())?;
+    writer.write_element_end_close("c")?;
+    Ok(())
+}
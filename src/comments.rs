@@ -0,0 +1,253 @@
+//! Comments part
+//!
+//! The corresponding ZIP item is `/word/comments.xml`.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::document::{BodyContent, Paragraph, Text};
+use crate::schema::{
+    SCHEMA_DRAWING_MAIN, SCHEMA_MAIN, SCHEMA_MARKUP_COMPATIBILITY,
+    SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS, SCHEMA_PICTURE, SCHEMA_VML,
+    SCHEMA_WORDPROCESSING_DRAWING, SCHEMA_WORDPROCESSING_SHAPE, SCHEMA_WORD_2010, SCHEMA_WORD_2012,
+};
+use crate::{__setter, __xml_test_suites, DateTime};
+
+/// Comments of the document
+///
+/// ```rust
+/// use docx::comments::*;
+///
+/// let mut comments = Comments::default();
+/// let id = comments.push(Comment::new("Reviewer").initials("R").push_text("Typo?"));
+///
+/// assert_eq!(id, 0);
+/// assert!(comments.get(id).is_some());
+/// ```
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:comments")]
+pub struct Comments<'a> {
+    #[xml(child = "w:comment")]
+    pub comments: Vec<Comment<'a>>,
+}
+
+impl<'a> Comments<'a> {
+    /// Appends a comment, returns the id allocated to it
+    pub fn push(&mut self, mut comment: Comment<'a>) -> usize {
+        let id = self
+            .comments
+            .iter()
+            .map(|comment| comment.id + 1)
+            .max()
+            .unwrap_or(0);
+
+        comment.id = id;
+        self.comments.push(comment);
+
+        id
+    }
+
+    /// Returns the comment with the given id
+    pub fn get(&self, id: usize) -> Option<&Comment<'a>> {
+        self.comments.iter().find(|comment| comment.id == id)
+    }
+}
+
+impl<'a> XmlWrite for Comments<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Comments { comments } = self;
+
+        log::debug!("[Comments] Started writing.");
+
+        writer.write_element_start("w:comments")?;
+
+        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+        writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+        writer.write_attribute("xmlns:w14", SCHEMA_WORD_2010)?;
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:wp", SCHEMA_WORDPROCESSING_DRAWING)?;
+        writer.write_attribute("xmlns:a", SCHEMA_DRAWING_MAIN)?;
+        writer.write_attribute("xmlns:pic", SCHEMA_PICTURE)?;
+        writer.write_attribute("xmlns:wps", SCHEMA_WORDPROCESSING_SHAPE)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("xmlns:v", SCHEMA_VML)?;
+        writer.write_attribute("mc:Ignorable", "w14 w15")?;
+
+        if comments.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in comments {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w:comments")?;
+        }
+
+        log::debug!("[Comments] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Comment
+///
+/// The id is allocated when the comment is added to the comments part.
+///
+/// ```rust
+/// use docx::comments::*;
+/// use docx::DateTime;
+///
+/// let comment = Comment::new("Reviewer")
+///     .initials("R")
+///     .date(DateTime::now())
+///     .push_text("Please cite the source.");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:comment")]
+pub struct Comment<'a> {
+    /// Specifies the id which anchors and references refer to.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+    /// Specifies the author of the comment.
+    #[xml(attr = "w:author")]
+    pub author: Cow<'a, str>,
+    /// Specifies the initials of the author.
+    #[xml(attr = "w:initials")]
+    pub initials: Option<Cow<'a, str>>,
+    /// Specifies when the comment was made.
+    #[xml(attr = "w:date")]
+    pub date: Option<DateTime>,
+    /// Specifies the content of the comment.
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
+    pub content: Vec<BodyContent<'a>>,
+}
+
+impl<'a> Comment<'a> {
+    __setter!(initials: Option<Cow<'a, str>>);
+    __setter!(date: Option<DateTime>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(author: T) -> Self {
+        Comment {
+            author: author.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline(always)]
+    pub fn push<T: Into<BodyContent<'a>>>(mut self, content: T) -> Self {
+        self.content.push(content.into());
+        self
+    }
+
+    /// Appends a paragraph of text
+    #[inline(always)]
+    pub fn push_text<T: Into<Text<'a>>>(self, content: T) -> Self {
+        self.push(Paragraph::default().push_text(content))
+    }
+
+    /// Returns the paragraph id of the last paragraph
+    ///
+    /// Comment extensions, e.g. replies and resolved state, refer to
+    /// comments with it.
+    pub fn para_id(&self) -> Option<&str> {
+        self.content.iter().rev().find_map(|content| match content {
+            BodyContent::Paragraph(para) => para.para_id.as_deref(),
+            _ => None,
+        })
+    }
+
+    /// Returns the paragraph id of the last paragraph, generates one which
+    /// isn't in `used` if it doesn't have
+    pub(crate) fn ensure_para_id(&mut self, used: &mut HashSet<u32>) -> String {
+        if !matches!(self.content.last(), Some(BodyContent::Paragraph(_))) {
+            self.content.push(Paragraph::default().into());
+        }
+
+        match self.content.last_mut() {
+            Some(BodyContent::Paragraph(para)) => para
+                .para_id
+                .get_or_insert_with(|| new_hex_id(used).into())
+                .to_string(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A comment with its replies
+#[derive(Debug)]
+pub struct CommentThread<'b, 'a> {
+    /// Specifies the comment which starts the thread.
+    pub comment: &'b Comment<'a>,
+    /// Specifies the replies in the order they're stored.
+    pub replies: Vec<&'b Comment<'a>>,
+    /// Specifies whether the thread is resolved.
+    pub done: bool,
+}
+
+/// Generates an id for paragraphs and comments which isn't in `used`, and
+/// adds it to `used`
+///
+/// Word requires these ids to be less than `0x80000000`.
+pub(crate) fn new_hex_id(used: &mut HashSet<u32>) -> String {
+    let id = (1..0x8000_0000)
+        .find(|id| !used.contains(id))
+        .unwrap_or_default();
+    used.insert(id);
+
+    format!("{:08X}", id)
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v="urn:schemas-microsoft-com:vml" mc:Ignorable="w14 w15""#;
+
+__xml_test_suites!(
+    Comments,
+    Comments::default(),
+    format!(r#"<w:comments {}/>"#, NAMESPACES).as_str(),
+    {
+        let mut comments = Comments::default();
+        comments.push(Comment::new("foo"));
+        comments.push(Comment::new("bar").initials("b").push_text("baz"));
+        comments
+    },
+    format!(
+        r#"<w:comments {}><w:comment w:id="0" w:author="foo"/><w:comment w:id="1" w:author="bar" w:initials="b"><w:p><w:pPr/><w:r><w:rPr/><w:t>baz</w:t></w:r></w:p></w:comment></w:comments>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
+
+#[test]
+fn comment_date() -> strong_xml::XmlResult<()> {
+    let comment = Comment::new("foo").date("2020-04-01T08:30:00Z".parse::<DateTime>().unwrap());
+    let xml = r#"<w:comment w:id="0" w:author="foo" w:date="2020-04-01T08:30:00Z"/>"#;
+
+    assert_eq!(comment.to_string()?, xml);
+    assert_eq!(Comment::from_str(xml)?, comment);
+
+    Ok(())
+}
+
+#[test]
+fn ensure_para_id() {
+    let mut comment = Comment::new("foo").push_text("bar");
+    assert_eq!(comment.para_id(), None);
+
+    let mut used = [1, 3].iter().copied().collect();
+    let id = comment.ensure_para_id(&mut used);
+    assert_eq!(id, "00000002");
+    assert!(used.contains(&2));
+    assert_eq!(comment.para_id(), Some(&*id));
+    assert_eq!(comment.ensure_para_id(&mut used), id);
+    assert_eq!(comment.content.len(), 1);
+}
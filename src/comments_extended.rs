@@ -0,0 +1,125 @@
+//! Comments Extended part
+//!
+//! The corresponding ZIP item is `/word/commentsExtended.xml`.
+
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::schema::{SCHEMA_MARKUP_COMPATIBILITY, SCHEMA_WORD_2012};
+use crate::{__setter, __xml_test_suites};
+
+/// Extended properties of comments
+///
+/// Comments are identified by the paragraph id of their last paragraph.
+///
+/// ```rust
+/// use docx::comments_extended::*;
+///
+/// let mut ext = CommentsExtended::default();
+/// ext.comments.push(CommentExtended::new("1A2B3C4D").done(true));
+/// ext.comments.push(CommentExtended::new("2B3C4D5E").parent("1A2B3C4D"));
+///
+/// assert_eq!(ext.get("2B3C4D5E").unwrap().parent.as_deref(), Some("1A2B3C4D"));
+/// ```
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:commentsEx")]
+pub struct CommentsExtended<'a> {
+    #[xml(child = "w15:commentEx")]
+    pub comments: Vec<CommentExtended<'a>>,
+}
+
+impl<'a> CommentsExtended<'a> {
+    /// Returns the properties of the comment with the given paragraph id
+    pub fn get(&self, para_id: &str) -> Option<&CommentExtended<'a>> {
+        self.comments
+            .iter()
+            .find(|ele| ele.para_id.eq_ignore_ascii_case(para_id))
+    }
+
+    /// Returns the properties of the comment with the given paragraph id,
+    /// inserts a new one if needed
+    pub fn get_or_insert(&mut self, para_id: &str) -> &mut CommentExtended<'a> {
+        match self
+            .comments
+            .iter()
+            .position(|ele| ele.para_id.eq_ignore_ascii_case(para_id))
+        {
+            Some(i) => &mut self.comments[i],
+            None => {
+                self.comments.push(CommentExtended::new(para_id.to_owned()));
+                self.comments.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+impl<'a> XmlWrite for CommentsExtended<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let CommentsExtended { comments } = self;
+
+        log::debug!("[CommentsExtended] Started writing.");
+
+        writer.write_element_start("w15:commentsEx")?;
+
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("mc:Ignorable", "w15")?;
+
+        if comments.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in comments {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w15:commentsEx")?;
+        }
+
+        log::debug!("[CommentsExtended] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Extended properties of a comment
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:commentEx")]
+pub struct CommentExtended<'a> {
+    /// Specifies the paragraph id of the last paragraph of the comment.
+    #[xml(attr = "w15:paraId")]
+    pub para_id: Cow<'a, str>,
+    /// Specifies the paragraph id of the comment which it replies to.
+    #[xml(attr = "w15:paraIdParent")]
+    pub parent: Option<Cow<'a, str>>,
+    /// Specifies whether the comment is resolved.
+    #[xml(attr = "w15:done")]
+    pub done: Option<bool>,
+}
+
+impl<'a> CommentExtended<'a> {
+    __setter!(parent: Option<Cow<'a, str>>);
+    __setter!(done: Option<bool>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(para_id: T) -> Self {
+        CommentExtended {
+            para_id: para_id.into(),
+            ..Default::default()
+        }
+    }
+}
+
+__xml_test_suites!(
+    CommentsExtended,
+    CommentsExtended::default(),
+    r#"<w15:commentsEx xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" mc:Ignorable="w15"/>"#,
+    CommentsExtended {
+        comments: vec![
+            CommentExtended::new("1A2B3C4D").done(false),
+            CommentExtended::new("2B3C4D5E").parent("1A2B3C4D"),
+        ]
+    },
+    r#"<w15:commentsEx xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" mc:Ignorable="w15"><w15:commentEx w15:paraId="1A2B3C4D" w15:done="false"/><w15:commentEx w15:paraId="2B3C4D5E" w15:paraIdParent="1A2B3C4D"/></w15:commentsEx>"#,
+);
@@ -0,0 +1,85 @@
+//! Comments Ids part
+//!
+//! The corresponding ZIP item is `/word/commentsIds.xml`.
+
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::__xml_test_suites;
+use crate::schema::SCHEMA_WORD_2016_CID;
+
+/// Durable ids of comments
+///
+/// Unlike comment ids, durable ids stay the same when the document is
+/// edited. Comments are identified by the paragraph id of their last
+/// paragraph.
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w16cid:commentsIds")]
+pub struct CommentsIds<'a> {
+    #[xml(child = "w16cid:commentId")]
+    pub ids: Vec<CommentId<'a>>,
+}
+
+impl<'a> CommentsIds<'a> {
+    /// Returns the durable id of the comment with the given paragraph id
+    pub fn get(&self, para_id: &str) -> Option<&str> {
+        self.ids
+            .iter()
+            .find(|ele| ele.para_id.eq_ignore_ascii_case(para_id))
+            .map(|ele| &*ele.durable_id)
+    }
+}
+
+impl<'a> XmlWrite for CommentsIds<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let CommentsIds { ids } = self;
+
+        log::debug!("[CommentsIds] Started writing.");
+
+        writer.write_element_start("w16cid:commentsIds")?;
+
+        writer.write_attribute("xmlns:w16cid", SCHEMA_WORD_2016_CID)?;
+
+        if ids.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in ids {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w16cid:commentsIds")?;
+        }
+
+        log::debug!("[CommentsIds] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Durable id of a comment
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w16cid:commentId")]
+pub struct CommentId<'a> {
+    /// Specifies the paragraph id of the last paragraph of the comment.
+    #[xml(attr = "w16cid:paraId")]
+    pub para_id: Cow<'a, str>,
+    /// Specifies the durable id of the comment.
+    #[xml(attr = "w16cid:durableId")]
+    pub durable_id: Cow<'a, str>,
+}
+
+__xml_test_suites!(
+    CommentsIds,
+    CommentsIds::default(),
+    r#"<w16cid:commentsIds xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid"/>"#,
+    CommentsIds {
+        ids: vec![CommentId {
+            para_id: "1A2B3C4D".into(),
+            durable_id: "5E6F7A8B".into(),
+        }]
+    },
+    r#"<w16cid:commentsIds xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid"><w16cid:commentId w16cid:paraId="1A2B3C4D" w16cid:durableId="5E6F7A8B"/></w16cid:commentsIds>"#,
+);
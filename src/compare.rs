@@ -0,0 +1,972 @@
+//! Document comparison
+//!
+//! The content of two bodies is aligned first: paragraphs by their text and
+//! tables by the text of their rows. Aligned or similar paragraphs are then
+//! compared word by word, and differences are recorded as tracked changes.
+
+use strong_xml::XmlWrite;
+
+use crate::document::{
+    BodyContent, Paragraph, ParagraphContent, Run, RunContent, Table, TableCellContent, TableRow,
+    Text, TextSpace, TrackChanges,
+};
+use crate::formatting::{CharacterProperty, ParagraphProperty, ParagraphPropertyChange};
+
+/// Compares the original content with the revised one, returns the revised
+/// content with the differences recorded as tracked changes
+///
+/// Content which can't be tracked, e.g. structured document tags, is taken
+/// from the revised content as it is.
+pub(crate) fn compare_content<'a>(
+    original: Vec<BodyContent<'a>>,
+    revised: Vec<BodyContent<'a>>,
+    changes: &mut TrackChanges<'a>,
+) -> Vec<BodyContent<'a>> {
+    let original_keys: Vec<_> = original.iter().map(content_key).collect();
+    let revised_keys: Vec<_> = revised.iter().map(content_key).collect();
+
+    // changed paragraphs and tables are aligned again by similarity
+    let ops = refine(
+        diff(&original_keys, &revised_keys),
+        &original_keys,
+        &revised_keys,
+        |original, revised| pairable(original, revised) && similar(&original[1..], &revised[1..]),
+    );
+
+    let mut original = original.into_iter();
+    let mut revised = revised.into_iter();
+    let mut result = Vec::with_capacity(revised.len());
+
+    for op in ops {
+        match op {
+            Op::Equal => {
+                let original = original.next().unwrap();
+                let revised = revised.next().unwrap();
+                result.push(compare_pair(original, revised, changes));
+            }
+            Op::Changed(deleted, inserted) => {
+                let deleted: Vec<_> = original.by_ref().take(deleted).collect();
+                let inserted: Vec<_> = revised.by_ref().take(inserted).collect();
+
+                // a replaced paragraph is compared word by word
+                if deleted.len() == 1 && inserted.len() == 1 {
+                    let original_key = content_key(&deleted[0]);
+                    let revised_key = content_key(&inserted[0]);
+                    if pairable(&original_key, &revised_key) {
+                        let original = deleted.into_iter().next().unwrap();
+                        let revised = inserted.into_iter().next().unwrap();
+                        result.push(compare_pair(original, revised, changes));
+                        continue;
+                    }
+                }
+
+                for content in deleted {
+                    result.extend(delete(content, changes));
+                }
+                for content in inserted {
+                    result.push(insert(content, changes));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn content_key(content: &BodyContent) -> String {
+    match content {
+        BodyContent::Paragraph(para) => format!("p{}", paragraph_text(para)),
+        BodyContent::Table(table) => table_key(table),
+        content => format!("o{}", content.to_string().unwrap_or_default()),
+    }
+}
+
+fn table_key(table: &Table) -> String {
+    let rows: Vec<_> = table.rows.iter().map(row_key).collect();
+    format!("t{}", rows.join("\n"))
+}
+
+fn row_key(row: &TableRow) -> String {
+    let cells: Vec<_> = row
+        .cells
+        .iter()
+        .map(|cell| {
+            let paras: Vec<_> = cell
+                .content
+                .iter()
+                .map(|content| match content {
+                    TableCellContent::Paragraph(para) => paragraph_text(para),
+                    TableCellContent::Table(table) => table_key(table),
+                })
+                .collect();
+            paras.join("\u{b6}")
+        })
+        .collect();
+    cells.join("\t")
+}
+
+fn paragraph_text(para: &Paragraph) -> String {
+    para.iter_text().map(|text| &**text).collect()
+}
+
+/// Whether both keys are of paragraphs or of tables
+fn pairable(original: &str, revised: &str) -> bool {
+    original[..1] == revised[..1] && !original.starts_with('o')
+}
+
+/// Whether at least half of the words are kept
+fn similar(original: &str, revised: &str) -> bool {
+    let original = split_words(original);
+    let revised = split_words(revised);
+
+    let kept = diff(&original, &revised)
+        .into_iter()
+        .filter(|op| *op == Op::Equal)
+        .count();
+
+    kept * 4 >= original.len() + revised.len()
+}
+
+fn compare_pair<'a>(
+    original: BodyContent<'a>,
+    revised: BodyContent<'a>,
+    changes: &mut TrackChanges<'a>,
+) -> BodyContent<'a> {
+    match (original, revised) {
+        (BodyContent::Paragraph(original), BodyContent::Paragraph(revised)) => {
+            compare_paragraph(original, revised, changes).into()
+        }
+        (BodyContent::Table(original), BodyContent::Table(revised)) => {
+            compare_table(original, revised, changes).into()
+        }
+        (_, revised) => revised,
+    }
+}
+
+fn insert<'a>(mut content: BodyContent<'a>, changes: &mut TrackChanges<'a>) -> BodyContent<'a> {
+    match &mut content {
+        BodyContent::Paragraph(para) => changes.insert_paragraph(para),
+        BodyContent::Table(table) => {
+            for row in &mut table.rows {
+                changes.insert_row(row);
+            }
+        }
+        _ => (),
+    }
+    content
+}
+
+fn delete<'a>(
+    mut content: BodyContent<'a>,
+    changes: &mut TrackChanges<'a>,
+) -> Option<BodyContent<'a>> {
+    match &mut content {
+        BodyContent::Paragraph(para) => {
+            para.content = strip_references(std::mem::take(&mut para.content));
+            changes.delete_paragraph(para);
+        }
+        BodyContent::Table(table) => {
+            for row in &mut table.rows {
+                strip_row_references(row);
+                changes.delete_row(row);
+            }
+        }
+        _ => return None,
+    }
+    Some(content)
+}
+
+/// Removes references to the parts of the original document from its
+/// deleted content, since only the parts of the revised one are kept
+///
+/// Hyperlinks to relationships are turned into plain runs, while drawings,
+/// pictures, note and comment references, comment ranges, bookmarks and
+/// permission ranges are removed.
+fn strip_references(content: Vec<ParagraphContent>) -> Vec<ParagraphContent> {
+    fn strip_run(run: &mut Run) {
+        run.content.retain(|content| {
+            !matches!(
+                content,
+                RunContent::FootnoteReference(_)
+                    | RunContent::EndnoteReference(_)
+                    | RunContent::CommentReference(_)
+                    | RunContent::Drawing(_)
+                    | RunContent::AlternateContent(_)
+                    | RunContent::Pict(_)
+            )
+        });
+    }
+
+    content
+        .into_iter()
+        .filter_map(|content| match content {
+            ParagraphContent::Run(mut run) => {
+                strip_run(&mut run);
+                Some(run.into())
+            }
+            ParagraphContent::Link(mut link) => {
+                strip_run(&mut link.content);
+                if link.id.is_some() {
+                    Some(link.content.into())
+                } else {
+                    Some(link.into())
+                }
+            }
+            ParagraphContent::SimpleField(mut field) => {
+                field.content.iter_mut().for_each(strip_run);
+                Some(field.into())
+            }
+            ParagraphContent::Sdt(mut sdt) => {
+                let inner = std::mem::take(&mut sdt.content.content);
+                sdt.content.content = strip_references(inner);
+                Some(sdt.into())
+            }
+            ParagraphContent::BookmarkStart(_)
+            | ParagraphContent::BookmarkEnd(_)
+            | ParagraphContent::PermissionStart(_)
+            | ParagraphContent::PermissionEnd(_)
+            | ParagraphContent::CommentRangeStart(_)
+            | ParagraphContent::CommentRangeEnd(_) => None,
+            content => Some(content),
+        })
+        .collect()
+}
+
+fn strip_row_references(row: &mut TableRow) {
+    for cell in &mut row.cells {
+        for content in &mut cell.content {
+            match content {
+                TableCellContent::Paragraph(para) => {
+                    para.content = strip_references(std::mem::take(&mut para.content));
+                }
+                TableCellContent::Table(table) => {
+                    table.rows.iter_mut().for_each(strip_row_references);
+                }
+            }
+        }
+    }
+}
+
+fn compare_table<'a>(
+    original: Table<'a>,
+    mut revised: Table<'a>,
+    changes: &mut TrackChanges<'a>,
+) -> Table<'a> {
+    // rows are keyed by their text and number of cells
+    let key = |row: &TableRow| (row_key(row), row.cells.len());
+    let original_keys: Vec<_> = original.rows.iter().map(key).collect();
+    let revised_keys: Vec<_> = revised.rows.iter().map(key).collect();
+
+    // changed rows with the same number of cells are aligned again by
+    // similarity
+    let ops = refine(
+        diff(&original_keys, &revised_keys),
+        &original_keys,
+        &revised_keys,
+        |original, revised| original.1 == revised.1 && similar(&original.0, &revised.0),
+    );
+
+    let mut original = original.rows.into_iter();
+    let mut revised_rows = std::mem::take(&mut revised.rows).into_iter();
+
+    for op in ops {
+        match op {
+            Op::Equal => {
+                let original = original.next().unwrap();
+                let revised_row = revised_rows.next().unwrap();
+                revised
+                    .rows
+                    .push(compare_row(original, revised_row, changes));
+            }
+            Op::Changed(deleted, inserted) => {
+                let deleted: Vec<_> = original.by_ref().take(deleted).collect();
+                let inserted: Vec<_> = revised_rows.by_ref().take(inserted).collect();
+
+                // a replaced row is compared cell by cell
+                if deleted.len() == 1
+                    && inserted.len() == 1
+                    && deleted[0].cells.len() == inserted[0].cells.len()
+                {
+                    let original = deleted.into_iter().next().unwrap();
+                    let revised_row = inserted.into_iter().next().unwrap();
+                    revised
+                        .rows
+                        .push(compare_row(original, revised_row, changes));
+                    continue;
+                }
+
+                for mut row in deleted {
+                    strip_row_references(&mut row);
+                    changes.delete_row(&mut row);
+                    revised.rows.push(row);
+                }
+                for mut row in inserted {
+                    changes.insert_row(&mut row);
+                    revised.rows.push(row);
+                }
+            }
+        }
+    }
+
+    revised
+}
+
+fn compare_row<'a>(
+    original: TableRow<'a>,
+    mut revised: TableRow<'a>,
+    changes: &mut TrackChanges<'a>,
+) -> TableRow<'a> {
+    fn into_body(content: Vec<TableCellContent>) -> Vec<BodyContent> {
+        content
+            .into_iter()
+            .map(|content| match content {
+                TableCellContent::Paragraph(para) => para.into(),
+                TableCellContent::Table(table) => table.into(),
+            })
+            .collect()
+    }
+
+    for (original, revised) in original.cells.into_iter().zip(&mut revised.cells) {
+        let content = std::mem::take(&mut revised.content);
+        let content = compare_content(into_body(original.content), into_body(content), changes);
+
+        // cells contain paragraphs and tables only
+        revised.content = content
+            .into_iter()
+            .filter_map(|content| match content {
+                BodyContent::Paragraph(para) => Some(para.into()),
+                BodyContent::Table(table) => Some(table.into()),
+                _ => None,
+            })
+            .collect();
+    }
+
+    revised
+}
+
+/// A word, a whitespace or a punctuation in text runs, with the index of
+/// the run properties, or other content, which is compared as a whole
+enum Token<'a> {
+    Text(String, usize),
+    Other(ParagraphContent<'a>),
+}
+
+/// Splits the content into tokens, returns them with the run properties
+fn tokenize(content: Vec<ParagraphContent>) -> (Vec<Token>, Vec<CharacterProperty>) {
+    let mut tokens = Vec::new();
+    let mut properties = Vec::new();
+
+    for content in content {
+        match content {
+            ParagraphContent::Run(run)
+                if run
+                    .content
+                    .iter()
+                    .all(|content| matches!(content, RunContent::Text(_))) =>
+            {
+                let text: String = run.iter_text().map(|text| &**text).collect();
+                let words = split_words(&text);
+                tokens.extend(
+                    words
+                        .into_iter()
+                        .map(|word| Token::Text(word.to_string(), properties.len())),
+                );
+                properties.push(run.property);
+            }
+            content => tokens.push(Token::Other(content)),
+        }
+    }
+
+    (tokens, properties)
+}
+
+fn token_key(token: &Token) -> String {
+    match token {
+        Token::Text(text, _) => format!("t{}", text),
+        Token::Other(content) => format!("o{}", content.to_string().unwrap_or_default()),
+    }
+}
+
+fn split_words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut last = None;
+
+    for (index, c) in text.char_indices() {
+        let class = if c.is_alphanumeric() {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        };
+
+        // punctuations are split one by one
+        if index > start && (last != Some(class) || class == 2) {
+            words.push(&text[start..index]);
+            start = index;
+        }
+
+        last = Some(class);
+    }
+
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+
+    words
+}
+
+/// Kind of text pieces, with the indices of revised and original run properties
+#[derive(Clone, Copy, PartialEq)]
+enum Change {
+    Kept(usize),
+    Formatted(usize, usize),
+    Inserted(usize),
+    Deleted(usize),
+}
+
+fn compare_paragraph<'a>(
+    mut original: Paragraph<'a>,
+    mut revised: Paragraph<'a>,
+    changes: &mut TrackChanges<'a>,
+) -> Paragraph<'a> {
+    if paragraph_format(&mut original.property) != paragraph_format(&mut revised.property) {
+        let mut property = original.property;
+        property.mark = None;
+        property.section_property = None;
+
+        let mut change =
+            ParagraphPropertyChange::new(changes.allocate_id(), changes.author.clone(), property);
+        change.date = changes.date;
+        revised.property.change = Some(change);
+    }
+
+    let (original_tokens, original_properties) = tokenize(original.content);
+    let (revised_tokens, revised_properties) = tokenize(std::mem::take(&mut revised.content));
+
+    let original_formats: Vec<_> = original_properties.iter().map(run_format).collect();
+    let revised_formats: Vec<_> = revised_properties.iter().map(run_format).collect();
+
+    let original_keys: Vec<_> = original_tokens.iter().map(token_key).collect();
+    let revised_keys: Vec<_> = revised_tokens.iter().map(token_key).collect();
+
+    // text is aligned with its formatting first, so that the same text
+    // with different formatting is found only in the changed tokens
+    let formatted_key = |token: &Token, key: &String, formats: &[String]| match token {
+        Token::Text(_, property) => format!("{}\u{0}{}", key, formats[*property]),
+        Token::Other(_) => key.clone(),
+    };
+    let original_formatted: Vec<_> = original_tokens
+        .iter()
+        .zip(&original_keys)
+        .map(|(token, key)| formatted_key(token, key, &original_formats))
+        .collect();
+    let revised_formatted: Vec<_> = revised_tokens
+        .iter()
+        .zip(&revised_keys)
+        .map(|(token, key)| formatted_key(token, key, &revised_formats))
+        .collect();
+
+    let ops = refine(
+        diff(&original_formatted, &revised_formatted),
+        &original_keys,
+        &revised_keys,
+        |original, revised| original == revised,
+    );
+
+    let mut original_tokens = original_tokens.into_iter();
+    let mut revised_tokens = revised_tokens.into_iter();
+    let mut pieces = Pieces {
+        content: Vec::new(),
+        pending: None,
+        original: original_properties,
+        revised: revised_properties,
+    };
+
+    for op in ops {
+        match op {
+            Op::Equal => match (original_tokens.next(), revised_tokens.next()) {
+                (Some(Token::Text(_, original)), Some(Token::Text(text, revised))) => {
+                    let change = if original_formats[original] == revised_formats[revised] {
+                        Change::Kept(revised)
+                    } else {
+                        Change::Formatted(revised, original)
+                    };
+                    pieces.push_text(text, change, changes);
+                }
+                (_, Some(Token::Other(content))) => pieces.push(content, changes),
+                _ => unreachable!(),
+            },
+            Op::Changed(deleted, inserted) => {
+                for token in original_tokens.by_ref().take(deleted) {
+                    match token {
+                        Token::Text(text, original) => {
+                            pieces.push_text(text, Change::Deleted(original), changes)
+                        }
+                        // content other than runs can't be tracked as deleted
+                        Token::Other(content @ ParagraphContent::Run(_))
+                        | Token::Other(content @ ParagraphContent::Link(_)) => {
+                            let deleted = changes.delete(strip_references(vec![content]));
+                            pieces.extend(deleted, changes);
+                        }
+                        Token::Other(_) => (),
+                    }
+                }
+
+                for token in revised_tokens.by_ref().take(inserted) {
+                    match token {
+                        Token::Text(text, revised) => {
+                            pieces.push_text(text, Change::Inserted(revised), changes)
+                        }
+                        Token::Other(content @ ParagraphContent::Run(_))
+                        | Token::Other(content @ ParagraphContent::Link(_)) => {
+                            let inserted = changes.insert(content);
+                            pieces.push(inserted.into(), changes);
+                        }
+                        Token::Other(content) => pieces.push(content, changes),
+                    }
+                }
+            }
+        }
+    }
+
+    pieces.flush(changes);
+    revised.content = pieces.content;
+    revised
+}
+
+/// Formatting of the paragraph, which excludes the section properties and
+/// tracked changes
+fn paragraph_format(property: &mut ParagraphProperty) -> String {
+    let mark = property.mark.take();
+    let section = property.section_property.take();
+    let change = property.change.take();
+
+    let format = property.to_string().unwrap_or_default();
+
+    property.mark = mark;
+    property.section_property = section;
+    property.change = change;
+
+    format
+}
+
+fn run_format(property: &CharacterProperty) -> String {
+    property.to_string().unwrap_or_default()
+}
+
+/// Content of the compared paragraph, consecutive text pieces of the same
+/// kind are put into one run
+struct Pieces<'a> {
+    content: Vec<ParagraphContent<'a>>,
+    pending: Option<(String, Change)>,
+    original: Vec<CharacterProperty<'a>>,
+    revised: Vec<CharacterProperty<'a>>,
+}
+
+impl<'a> Pieces<'a> {
+    fn push_text(&mut self, text: String, change: Change, changes: &mut TrackChanges<'a>) {
+        match &mut self.pending {
+            Some((pending, last)) if *last == change => pending.push_str(&text),
+            _ => {
+                self.flush(changes);
+                self.pending = Some((text, change));
+            }
+        }
+    }
+
+    fn push(&mut self, content: ParagraphContent<'a>, changes: &mut TrackChanges<'a>) {
+        self.flush(changes);
+        self.content.push(content);
+    }
+
+    fn extend(&mut self, content: Vec<ParagraphContent<'a>>, changes: &mut TrackChanges<'a>) {
+        self.flush(changes);
+        self.content.extend(content);
+    }
+
+    fn flush(&mut self, changes: &mut TrackChanges<'a>) {
+        let (text, change) = match self.pending.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+
+        let text = if text.starts_with(char::is_whitespace) || text.ends_with(char::is_whitespace) {
+            Text::from((text, TextSpace::Preserve))
+        } else {
+            Text::from(text)
+        };
+
+        match change {
+            Change::Kept(revised) => {
+                let run = Run::default()
+                    .property(self.revised[revised].clone())
+                    .push_text(text);
+                self.content.push(run.into());
+            }
+            Change::Formatted(revised, original) => {
+                let mut run = Run::default()
+                    .property(self.original[original].clone())
+                    .push_text(text);
+                changes.set_property(&mut run, self.revised[revised].clone());
+                self.content.push(run.into());
+            }
+            Change::Inserted(revised) => {
+                let run = Run::default()
+                    .property(self.revised[revised].clone())
+                    .push_text(text);
+                self.content.push(changes.insert(run).into());
+            }
+            Change::Deleted(original) => {
+                let run = Run::default()
+                    .property(self.original[original].clone())
+                    .push_text(text);
+                self.content.extend(changes.delete(vec![run.into()]));
+            }
+        }
+    }
+}
+
+/// The largest number of pairs of elements compared by the longest common
+/// subsequence, larger blocks of changed elements are deleted and inserted
+/// as a whole
+const MAX_DIFF_PAIRS: usize = 1 << 22;
+
+/// The largest number of pairs of elements compared again by `refine`,
+/// since its comparisons are usually expensive
+const MAX_REFINE_PAIRS: usize = 1 << 12;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    /// The next elements of both sequences are kept
+    Equal,
+    /// The next elements of the original sequence are deleted, and the next
+    /// elements of the revised one are inserted
+    Changed(usize, usize),
+}
+
+/// Aligns two sequences by their longest common subsequence
+fn diff<T: PartialEq>(original: &[T], revised: &[T]) -> Vec<Op> {
+    diff_by(original, revised, |a, b| a == b)
+}
+
+/// Aligns the changed elements again with another comparison
+fn refine<T, F>(ops: Vec<Op>, original: &[T], revised: &[T], eq: F) -> Vec<Op>
+where
+    F: Fn(&T, &T) -> bool + Copy,
+{
+    let (mut i, mut j) = (0, 0);
+    let mut refined = Vec::with_capacity(ops.len());
+
+    for op in ops {
+        match op {
+            Op::Equal => {
+                refined.push(op);
+                i += 1;
+                j += 1;
+            }
+            Op::Changed(deleted, inserted) if deleted * inserted > MAX_REFINE_PAIRS => {
+                refined.push(op);
+                i += deleted;
+                j += inserted;
+            }
+            Op::Changed(deleted, inserted) => {
+                refined.extend(diff_by(
+                    &original[i..i + deleted],
+                    &revised[j..j + inserted],
+                    eq,
+                ));
+                i += deleted;
+                j += inserted;
+            }
+        }
+    }
+
+    refined
+}
+
+fn diff_by<T, F>(original: &[T], revised: &[T], eq: F) -> Vec<Op>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let prefix = original
+        .iter()
+        .zip(revised)
+        .take_while(|(a, b)| eq(a, b))
+        .count();
+    let suffix = original[prefix..]
+        .iter()
+        .rev()
+        .zip(revised[prefix..].iter().rev())
+        .take_while(|(a, b)| eq(a, b))
+        .count();
+
+    let a = &original[prefix..original.len() - suffix];
+    let b = &revised[prefix..revised.len() - suffix];
+
+    let mut ops = vec![Op::Equal; prefix];
+
+    if a.len() * b.len() > MAX_DIFF_PAIRS {
+        ops.push(Op::Changed(a.len(), b.len()));
+        ops.resize(ops.len() + suffix, Op::Equal);
+        return ops;
+    }
+
+    let width = b.len() + 1;
+
+    // lengths[i * width + j] is the length of the longest common
+    // subsequence of a[i..] and b[j..]
+    let mut lengths = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i * width + j] = if eq(&a[i], &b[j]) {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let (mut deleted, mut inserted) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && eq(&a[i], &b[j]) {
+            if deleted + inserted > 0 {
+                ops.push(Op::Changed(deleted, inserted));
+                deleted = 0;
+                inserted = 0;
+            }
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if j == b.len()
+            || (i < a.len() && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1])
+        {
+            deleted += 1;
+            i += 1;
+        } else {
+            inserted += 1;
+            j += 1;
+        }
+    }
+
+    if deleted + inserted > 0 {
+        ops.push(Op::Changed(deleted, inserted));
+    }
+
+    ops.resize(ops.len() + suffix, Op::Equal);
+
+    ops
+}
+
+#[test]
+fn diff_sequences() {
+    assert_eq!(diff(&[1, 2, 3], &[1, 2, 3]), [Op::Equal; 3]);
+    assert_eq!(
+        diff(&[1, 2, 3, 4], &[1, 5, 3, 6, 7]),
+        [Op::Equal, Op::Changed(1, 1), Op::Equal, Op::Changed(1, 2)]
+    );
+    assert_eq!(diff(&[1, 2], &[]), [Op::Changed(2, 0)]);
+
+    // large blocks aren't aligned
+    let original: Vec<_> = (0..4096).collect();
+    let revised: Vec<_> = (0..4096).rev().collect();
+    assert_eq!(diff(&original, &revised), [Op::Changed(4096, 4096)]);
+    assert_eq!(
+        refine(vec![Op::Changed(128, 128)], &original, &revised, |_, _| {
+            true
+        }),
+        [Op::Changed(128, 128)]
+    );
+    assert_eq!(
+        split_words("The Seller's  fee, 5%"),
+        ["The", " ", "Seller", "'", "s", "  ", "fee", ",", " ", "5", "%"]
+    );
+}
+
+#[test]
+fn compare_formatting() {
+    use crate::formatting::JustificationVal;
+
+    let original = vec![Paragraph::default().push_text("foo bar").into()];
+    let mut revised = Paragraph::default().push_text("foo ").push(
+        Run::default()
+            .property(CharacterProperty::default().bold(true))
+            .push_text("bar"),
+    );
+    revised.property = ParagraphProperty::default().justification(JustificationVal::Center);
+
+    let mut changes = TrackChanges::new("foo");
+    changes.date = None;
+
+    let content = compare_content(original, vec![revised.into()], &mut changes);
+
+    assert_eq!(
+        content[0].to_string().unwrap(),
+        r#"<w:p><w:pPr><w:jc w:val="center"/><w:pPrChange w:id="0" w:author="foo"><w:pPr/></w:pPrChange></w:pPr><w:r><w:rPr/><w:t xml:space="preserve">foo </w:t></w:r><w:r><w:rPr><w:b w:val="true"/><w:rPrChange w:id="1" w:author="foo"><w:rPr/></w:rPrChange></w:rPr><w:t>bar</w:t></w:r></w:p>"#
+    );
+}
+
+#[test]
+fn compare_nested_tables() {
+    use crate::document::Table;
+
+    let table = |text| -> BodyContent {
+        let nested = Table::default()
+            .push_row(TableRow::default().push_cell(Paragraph::default().push_text(text)));
+        Table::default()
+            .push_row(TableRow::default().push_cell(nested))
+            .into()
+    };
+
+    let mut changes = TrackChanges::new("foo");
+    changes.date = None;
+
+    let content = compare_content(vec![table("foo")], vec![table("bar")], &mut changes);
+
+    let xml = content[0].to_string().unwrap();
+    assert!(xml.contains("<w:tc><w:tcPr/><w:tbl>"));
+    assert!(xml.contains("<w:delText>foo</w:delText>"));
+    assert!(xml.contains("<w:t>bar</w:t>"));
+}
+
+#[test]
+fn document_comparison() {
+    use crate::document::{paragraph_texts, RevisionView};
+    use crate::Docx;
+
+    fn docx(paras: &[&'static str], rows: &[[&'static str; 2]]) -> Docx<'static> {
+        let mut docx = Docx::default();
+        for para in paras {
+            docx.document.push(Paragraph::default().push_text(*para));
+        }
+        let mut table = Table::default();
+        for [a, b] in rows {
+            table = table.push_row(
+                TableRow::default()
+                    .push_cell(Paragraph::default().push_text(*a))
+                    .push_cell(Paragraph::default().push_text(*b)),
+            );
+        }
+        docx.document.push(table);
+        docx.document.push(Paragraph::default());
+        docx
+    }
+
+    fn texts(docx: &Docx<'static>, view: RevisionView) -> Vec<String> {
+        paragraph_texts(&docx.document.body.content, view)
+    }
+
+    let original = [
+        "The Seller shall deliver the goods.",
+        "This clause is removed.",
+        "Payment is due in 30 days.",
+    ];
+    let revised = [
+        "The Seller shall promptly deliver the goods.",
+        "Payment is due in 45 days.",
+        "Governing law: England.",
+    ];
+    let original_rows = [["Item", "Price"], ["Widget", "10"]];
+    let revised_rows = [["Item", "Price"], ["Widget", "12"], ["Gadget", "20"]];
+
+    let compare = || {
+        let mut revised = docx(&revised, &revised_rows);
+        if let BodyContent::Paragraph(para) = &mut revised.document.body.content[0] {
+            para.content.push(
+                Run::default()
+                    .property(CharacterProperty::default().bold(true))
+                    .push_text(" Time is of the essence.")
+                    .into(),
+            );
+        }
+        let mut changes = TrackChanges::new("foo");
+        Docx::compare(docx(&original, &original_rows), revised, &mut changes)
+    };
+
+    let expected_original = texts(&docx(&original, &original_rows), RevisionView::Accepted);
+    let expected_revised = {
+        let mut texts = texts(&docx(&revised, &revised_rows), RevisionView::Accepted);
+        texts[0].push_str(" Time is of the essence.");
+        texts
+    };
+
+    let redline = compare();
+    assert_eq!(
+        texts(&redline, RevisionView::Accepted)
+            .into_iter()
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>(),
+        expected_revised
+            .iter()
+            .filter(|text| !text.is_empty())
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(
+        texts(&redline, RevisionView::Original)
+            .into_iter()
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>(),
+        expected_original
+            .iter()
+            .filter(|text| !text.is_empty())
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+
+    let xml = redline.document.to_string().unwrap();
+    assert!(xml.contains(r#"<w:ins w:id="0" w:author="foo""#));
+    assert!(xml.contains(r#"<w:delText>30</w:delText>"#));
+    assert!(xml.contains(r#"<w:trPr><w:ins "#));
+
+    let mut accepted = compare();
+    accepted.accept_all_revisions();
+    assert_eq!(texts(&accepted, RevisionView::Original), expected_revised);
+    assert!(!accepted.document.to_string().unwrap().contains("w:author"));
+
+    let mut rejected = compare();
+    rejected.reject_all_revisions();
+    assert_eq!(texts(&rejected, RevisionView::Original), expected_original);
+    assert!(!rejected.document.to_string().unwrap().contains("w:author"));
+}
+
+#[test]
+fn compare_deleted_references() {
+    use crate::document::{Hyperlink, SdtRun};
+    use crate::Docx;
+
+    let mut original = Docx::default();
+    let footnote = original.add_footnote(vec![Paragraph::default().push_text("Note")]);
+    original
+        .document
+        .push(
+            Paragraph::default()
+                .push_text("See ")
+                .push(
+                    Hyperlink::default()
+                        .id("rId9")
+                        .content(Run::default().push_text("terms")),
+                )
+                .push(Run::default().push_footnote(footnote)),
+        )
+        .push(Paragraph::default().push(
+            SdtRun::default().push(Run::default().push_text("Annex").push_footnote(footnote)),
+        ));
+
+    let mut revised = Docx::default();
+    revised
+        .document
+        .push(Paragraph::default().push_text("See "));
+
+    let redline = Docx::compare(original, revised, &mut TrackChanges::new("foo"));
+
+    let xml = redline.document.to_string().unwrap();
+    assert!(xml.contains("<w:delText>terms</w:delText>"));
+    assert!(xml.contains("<w:delText>Annex</w:delText>"));
+    assert!(!xml.contains("w:hyperlink"));
+    assert!(!xml.contains("r:id"));
+    assert!(!xml.contains("w:footnoteReference"));
+}
@@ -17,6 +17,32 @@ const CONTENT_TYPE_DOCUMENT: &str =
     "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml";
 const CONTENT_TYPE_STYLES: &str =
     "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml";
+pub(crate) const CONTENT_TYPE_CUSTOM: &str =
+    "application/vnd.openxmlformats-officedocument.custom-properties+xml";
+pub(crate) const CONTENT_TYPE_SETTINGS: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml";
+pub(crate) const CONTENT_TYPE_HEADER: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.header+xml";
+pub(crate) const CONTENT_TYPE_FOOTER: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml";
+pub(crate) const CONTENT_TYPE_FOOTNOTES: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml";
+pub(crate) const CONTENT_TYPE_ENDNOTES: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml";
+pub(crate) const CONTENT_TYPE_COMMENTS: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml";
+pub(crate) const CONTENT_TYPE_COMMENTS_EXTENDED: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsExtended+xml";
+pub(crate) const CONTENT_TYPE_COMMENTS_IDS: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsIds+xml";
+pub(crate) const CONTENT_TYPE_PEOPLE: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.people+xml";
+pub(crate) const CONTENT_TYPE_CUSTOM_XML_PROPERTIES: &str =
+    "application/vnd.openxmlformats-officedocument.customXmlProperties+xml";
+pub(crate) const CONTENT_TYPE_CHART: &str =
+    "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
+pub(crate) const CONTENT_TYPE_XLSX: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
 
 #[derive(Debug, XmlRead)]
 #[xml(tag = "Types")]
@@ -62,6 +88,32 @@ impl Default for ContentTypes<'static> {
     }
 }
 
+impl<'a> ContentTypes<'a> {
+    /// Adds an override for the part, unless the part already has one
+    pub fn add_override<T: Into<Cow<'a, str>>>(&mut self, part: T, ty: &'a str) {
+        let part = part.into();
+        if self.overrides.iter().all(|ele| ele.part != part) {
+            self.overrides.push(OverrideContentType {
+                part,
+                ty: ty.into(),
+            });
+        }
+    }
+
+    /// Adds a default for the extension, unless the extension already has one
+    pub fn add_default<T: Into<Cow<'a, str>>>(&mut self, ext: T, ty: &'a str) {
+        let ext = ext.into();
+        if self
+            .defaults
+            .iter()
+            .all(|ele| !ele.ext.eq_ignore_ascii_case(&ext))
+        {
+            self.defaults
+                .push(DefaultContentType { ext, ty: ty.into() });
+        }
+    }
+}
+
 impl<'a> XmlWrite for ContentTypes<'a> {
     fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
         let ContentTypes {
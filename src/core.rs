@@ -4,27 +4,178 @@
 
 use std::borrow::Cow;
 use std::io::Write;
-use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+use strong_xml::xmlparser::{ElementEnd, Token};
+use strong_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
 
-use crate::schema::SCHEMA_CORE;
+use crate::schema::{
+    SCHEMA_CORE_PROPERTIES, SCHEMA_DC, SCHEMA_DC_MI_TYPE, SCHEMA_DC_TERMS, SCHEMA_XSI,
+};
+use crate::{__setter, __xml_test_suites, DateTime};
 
-#[derive(Debug, Default, XmlRead)]
-#[xml(tag = "cp:coreProperties")]
+/// Core properties of the package
+///
+/// ```rust
+/// use docx::core::Core;
+/// use docx::DateTime;
+///
+/// let core = Core::default()
+///     .title("Contract")
+///     .creator("Jane Doe")
+///     .created(DateTime::now())
+///     .revision("1");
+/// ```
+///
+/// Dates which are empty or not in the W3CDTF format are read as `None`.
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
 pub struct Core<'a> {
-    #[xml(flatten_text = "dc:title")]
+    /// Specifies the name of the document.
     pub title: Option<Cow<'a, str>>,
-    #[xml(flatten_text = "dc:subject")]
+    /// Specifies the topic of the document.
     pub subject: Option<Cow<'a, str>>,
-    #[xml(flatten_text = "dc:creator")]
+    /// Specifies the entity primarily responsible for making the document.
     pub creator: Option<Cow<'a, str>>,
-    #[xml(flatten_text = "cp:keywords")]
+    /// Specifies a delimited set of keywords to support searching and indexing.
     pub keywords: Option<Cow<'a, str>>,
-    #[xml(flatten_text = "dc:description")]
+    /// Specifies an explanation of the content of the document.
     pub description: Option<Cow<'a, str>>,
-    #[xml(flatten_text = "cp:lastModifiedBy")]
+    /// Specifies the user who performed the last modification.
     pub last_modified_by: Option<Cow<'a, str>>,
-    #[xml(flatten_text = "cp:revision")]
+    /// Specifies the revision number.
+    ///
+    /// It's usually a number, but is kept as text since any string is
+    /// allowed, e.g. `1.2`. Use `revision_number` to read it as a number.
     pub revision: Option<Cow<'a, str>>,
+    /// Specifies the date and time of the last printing.
+    pub last_printed: Option<DateTime>,
+    /// Specifies the date and time of creation.
+    pub created: Option<DateTime>,
+    /// Specifies the date and time of the last modification.
+    pub modified: Option<DateTime>,
+    /// Specifies a categorization of the content of the document.
+    pub category: Option<Cow<'a, str>>,
+    /// Specifies the status of the content, e.g. `Draft` or `Final`.
+    pub content_status: Option<Cow<'a, str>>,
+    /// Specifies the language of the content.
+    pub language: Option<Cow<'a, str>>,
+    /// Specifies an unambiguous reference to the document.
+    pub identifier: Option<Cow<'a, str>>,
+    /// Specifies the version number.
+    pub version: Option<Cow<'a, str>>,
+}
+
+impl<'a> Core<'a> {
+    __setter!(title: Option<Cow<'a, str>>);
+    __setter!(subject: Option<Cow<'a, str>>);
+    __setter!(creator: Option<Cow<'a, str>>);
+    __setter!(keywords: Option<Cow<'a, str>>);
+    __setter!(description: Option<Cow<'a, str>>);
+    __setter!(last_modified_by: Option<Cow<'a, str>>);
+    __setter!(revision: Option<Cow<'a, str>>);
+    __setter!(last_printed: Option<DateTime>);
+    __setter!(created: Option<DateTime>);
+    __setter!(modified: Option<DateTime>);
+    __setter!(category: Option<Cow<'a, str>>);
+    __setter!(content_status: Option<Cow<'a, str>>);
+    __setter!(language: Option<Cow<'a, str>>);
+    __setter!(identifier: Option<Cow<'a, str>>);
+    __setter!(version: Option<Cow<'a, str>>);
+
+    /// Returns the revision number, or `None` if the revision isn't a number
+    ///
+    /// ```rust
+    /// use docx::core::Core;
+    ///
+    /// assert_eq!(Core::default().revision("3").revision_number(), Some(3));
+    /// assert_eq!(Core::default().revision("1.2").revision_number(), None);
+    /// ```
+    pub fn revision_number(&self) -> Option<u32> {
+        self.revision.as_ref()?.trim().parse().ok()
+    }
+}
+
+/// The tags of the properties, other elements are skipped when reading
+const PROPERTIES: [&str; 15] = [
+    "dc:title",
+    "dc:subject",
+    "dc:creator",
+    "cp:keywords",
+    "dc:description",
+    "cp:lastModifiedBy",
+    "cp:revision",
+    "cp:lastPrinted",
+    "dcterms:created",
+    "dcterms:modified",
+    "cp:category",
+    "cp:contentStatus",
+    "dc:language",
+    "dc:identifier",
+    "cp:version",
+];
+
+impl<'i: 'a, 'a> XmlRead<'i> for Core<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let mut core = Core::default();
+
+        reader.read_till_element_start("cp:coreProperties")?;
+        while reader.find_attribute()?.is_some() {}
+        if let Some(Ok(Token::ElementEnd {
+            end: ElementEnd::Empty,
+            ..
+        })) = reader.next()
+        {
+            return Ok(core);
+        }
+
+        while let Some(tag) = reader.find_element_start(Some("cp:coreProperties"))? {
+            // skip element start
+            reader.next();
+
+            if !PROPERTIES.contains(&tag) {
+                reader.read_to_end(tag)?;
+                continue;
+            }
+
+            while reader.find_attribute()?.is_some() {}
+
+            let text = match reader.peek() {
+                // empty elements are written by some applications, e.g. `<dc:title/>`
+                Some(Ok(Token::ElementEnd {
+                    end: ElementEnd::Empty,
+                    ..
+                })) => {
+                    reader.next();
+                    Cow::Borrowed("")
+                }
+                _ => reader.read_text(tag)?,
+            };
+
+            // files written by other applications may contain malformed
+            // dates, which shouldn't prevent opening them
+            let date = |text: &str| text.parse().ok();
+
+            match tag {
+                "dc:title" => core.title = Some(text),
+                "dc:subject" => core.subject = Some(text),
+                "dc:creator" => core.creator = Some(text),
+                "cp:keywords" => core.keywords = Some(text),
+                "dc:description" => core.description = Some(text),
+                "cp:lastModifiedBy" => core.last_modified_by = Some(text),
+                "cp:revision" => core.revision = Some(text),
+                "cp:lastPrinted" => core.last_printed = date(&text),
+                "dcterms:created" => core.created = date(&text),
+                "dcterms:modified" => core.modified = date(&text),
+                "cp:category" => core.category = Some(text),
+                "cp:contentStatus" => core.content_status = Some(text),
+                "dc:language" => core.language = Some(text),
+                "dc:identifier" => core.identifier = Some(text),
+                "cp:version" => core.version = Some(text),
+                _ => (),
+            }
+        }
+
+        Ok(core)
+    }
 }
 
 impl<'a> XmlWrite for Core<'a> {
@@ -37,13 +188,25 @@ impl<'a> XmlWrite for Core<'a> {
             description,
             last_modified_by,
             revision,
+            last_printed,
+            created,
+            modified,
+            category,
+            content_status,
+            language,
+            identifier,
+            version,
         } = self;
 
         log::debug!("[Core] Started writing.");
 
         writer.write_element_start("cp:coreProperties")?;
 
-        writer.write_attribute("xmlns:cp", SCHEMA_CORE)?;
+        writer.write_attribute("xmlns:cp", SCHEMA_CORE_PROPERTIES)?;
+        writer.write_attribute("xmlns:dc", SCHEMA_DC)?;
+        writer.write_attribute("xmlns:dcterms", SCHEMA_DC_TERMS)?;
+        writer.write_attribute("xmlns:dcmitype", SCHEMA_DC_MI_TYPE)?;
+        writer.write_attribute("xmlns:xsi", SCHEMA_XSI)?;
 
         if title.is_none()
             && subject.is_none()
@@ -52,6 +215,14 @@ impl<'a> XmlWrite for Core<'a> {
             && description.is_none()
             && last_modified_by.is_none()
             && revision.is_none()
+            && last_printed.is_none()
+            && created.is_none()
+            && modified.is_none()
+            && category.is_none()
+            && content_status.is_none()
+            && language.is_none()
+            && identifier.is_none()
+            && version.is_none()
         {
             writer.write_element_end_empty()?;
         } else {
@@ -77,6 +248,30 @@ impl<'a> XmlWrite for Core<'a> {
             if let Some(val) = revision {
                 writer.write_flatten_text("cp:revision", val)?;
             }
+            if let Some(val) = last_printed {
+                writer.write_flatten_text("cp:lastPrinted", &val.to_string())?;
+            }
+            if let Some(val) = created {
+                write_w3cdtf(writer, "dcterms:created", val)?;
+            }
+            if let Some(val) = modified {
+                write_w3cdtf(writer, "dcterms:modified", val)?;
+            }
+            if let Some(val) = category {
+                writer.write_flatten_text("cp:category", val)?;
+            }
+            if let Some(val) = content_status {
+                writer.write_flatten_text("cp:contentStatus", val)?;
+            }
+            if let Some(val) = language {
+                writer.write_flatten_text("dc:language", val)?;
+            }
+            if let Some(val) = identifier {
+                writer.write_flatten_text("dc:identifier", val)?;
+            }
+            if let Some(val) = version {
+                writer.write_flatten_text("cp:version", val)?;
+            }
             writer.write_element_end_close("cp:coreProperties")?;
         }
 
@@ -85,3 +280,64 @@ impl<'a> XmlWrite for Core<'a> {
         Ok(())
     }
 }
+
+/// Writes a `dcterms` date, which must be typed as `dcterms:W3CDTF`
+fn write_w3cdtf<W: Write>(writer: &mut XmlWriter<W>, tag: &str, val: &DateTime) -> XmlResult<()> {
+    writer.write_element_start(tag)?;
+    writer.write_attribute("xsi:type", "dcterms:W3CDTF")?;
+    writer.write_element_end_open()?;
+    writer.write_text(&val.to_string())?;
+    writer.write_element_end_close(tag)?;
+    Ok(())
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#;
+
+__xml_test_suites!(
+    Core,
+    Core::default(),
+    format!(r#"<cp:coreProperties {}/>"#, NAMESPACES).as_str(),
+    Core::default().title("title").revision("3"),
+    format!(
+        r#"<cp:coreProperties {}><dc:title>title</dc:title><cp:revision>3</cp:revision></cp:coreProperties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+    Core::default().revision("1.2"),
+    format!(
+        r#"<cp:coreProperties {}><cp:revision>1.2</cp:revision></cp:coreProperties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+    Core::default()
+        .created("2020-01-01T00:00:00Z".parse::<DateTime>().unwrap())
+        .modified("2020-01-02T12:30:00Z".parse::<DateTime>().unwrap())
+        .language("en-US"),
+    format!(
+        r#"<cp:coreProperties {}><dcterms:created xsi:type="dcterms:W3CDTF">2020-01-01T00:00:00Z</dcterms:created><dcterms:modified xsi:type="dcterms:W3CDTF">2020-01-02T12:30:00Z</dcterms:modified><dc:language>en-US</dc:language></cp:coreProperties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
+
+#[test]
+fn malformed_dates() -> XmlResult<()> {
+    let xml = format!(
+        r#"<cp:coreProperties {}><cp:revision>7</cp:revision><dcterms:created xsi:type="dcterms:W3CDTF"/><dcterms:modified xsi:type="dcterms:W3CDTF">2020-01-01 10:00</dcterms:modified><cp:lastPrinted>2020-01-02T00:00:00Z</cp:lastPrinted><dc:title/><cp:unknown><foo/></cp:unknown><dc:creator>Jane</dc:creator></cp:coreProperties>"#,
+        NAMESPACES
+    );
+    let core = Core::from_str(&xml)?;
+
+    assert_eq!(core.revision_number(), Some(7));
+    assert_eq!(core.title.as_deref(), Some(""));
+    assert_eq!(core.creator.as_deref(), Some("Jane"));
+    assert_eq!(core.created, None);
+    assert_eq!(core.modified, None);
+    assert_eq!(
+        core.last_printed.unwrap().to_string(),
+        "2020-01-02T00:00:00Z"
+    );
+
+    Ok(())
+}
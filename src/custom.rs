@@ -0,0 +1,348 @@
+//! Custom File Properties part
+//!
+//! The corresponding ZIP item is `/docProps/custom.xml`.
+
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::xmlparser::{ElementEnd, Token};
+use strong_xml::{XmlError, XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+
+use crate::schema::{SCHEMA_CUSTOM, SCHEMA_DOC_PROPS_V_TYPES};
+use crate::{__xml_test_suites, DateTime};
+
+/// The format identifier of user-defined properties
+const FMTID_USER_DEFINED: &str = "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}";
+
+/// Custom properties of the package
+///
+/// ```rust
+/// use docx::custom::*;
+///
+/// let mut custom = Custom::default();
+/// custom
+///     .set("ContractId", "C-2020-001")
+///     .set("ClientNumber", 42)
+///     .set("Confidential", true);
+///
+/// assert_eq!(custom.get("ClientNumber"), Some(&CustomValue::I4(42)));
+/// ```
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "Properties")]
+pub struct Custom<'a> {
+    #[xml(child = "property")]
+    pub properties: Vec<CustomProperty<'a>>,
+}
+
+impl<'a> Custom<'a> {
+    /// Returns the value of the property with the given name
+    pub fn get(&self, name: &str) -> Option<&CustomValue<'a>> {
+        self.properties
+            .iter()
+            .find(|prop| prop.name.as_deref() == Some(name))
+            .and_then(|prop| prop.value.as_ref())
+    }
+
+    /// Sets the value of the property with the given name
+    ///
+    /// A new property is appended if it doesn't exist.
+    pub fn set<N, V>(&mut self, name: N, value: V) -> &mut Self
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<CustomValue<'a>>,
+    {
+        let name = name.into();
+
+        if let Some(prop) = self
+            .properties
+            .iter_mut()
+            .find(|prop| prop.name.as_deref() == Some(&*name))
+        {
+            prop.value = Some(value.into());
+        } else {
+            // property ids 0 and 1 are reserved
+            let pid = self
+                .properties
+                .iter()
+                .map(|prop| prop.pid + 1)
+                .max()
+                .unwrap_or(2)
+                .max(2);
+
+            self.properties.push(CustomProperty {
+                fmtid: FMTID_USER_DEFINED.into(),
+                pid,
+                name: Some(name),
+                value: Some(value.into()),
+            });
+        }
+
+        self
+    }
+
+    /// Removes the property with the given name, returns its value if any
+    pub fn remove(&mut self, name: &str) -> Option<CustomValue<'a>> {
+        let index = self
+            .properties
+            .iter()
+            .position(|prop| prop.name.as_deref() == Some(name))?;
+
+        self.properties.remove(index).value
+    }
+}
+
+impl<'a> XmlWrite for Custom<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Custom { properties } = self;
+
+        log::debug!("[Custom] Started writing.");
+
+        writer.write_element_start("Properties")?;
+
+        writer.write_attribute("xmlns", SCHEMA_CUSTOM)?;
+        writer.write_attribute("xmlns:vt", SCHEMA_DOC_PROPS_V_TYPES)?;
+
+        // properties without a value aren't valid, so the ones of
+        // unsupported types are dropped
+        let mut properties = properties
+            .iter()
+            .filter(|prop| prop.value.is_some())
+            .peekable();
+
+        if properties.peek().is_none() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in properties {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("Properties")?;
+        }
+
+        log::debug!("[Custom] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Custom Property
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "property")]
+pub struct CustomProperty<'a> {
+    /// Specifies the format identifier of the property.
+    #[xml(attr = "fmtid")]
+    pub fmtid: Cow<'a, str>,
+    /// Specifies the unique identifier of the property.
+    #[xml(attr = "pid")]
+    pub pid: usize,
+    /// Specifies the name of the property.
+    #[xml(attr = "name")]
+    pub name: Option<Cow<'a, str>>,
+    /// Specifies the value of the property.
+    ///
+    /// It's `None` if the value has an unsupported type, and such properties
+    /// are dropped when writing.
+    #[xml(
+        child = "vt:lpwstr",
+        child = "vt:lpstr",
+        child = "vt:i4",
+        child = "vt:r8",
+        child = "vt:bool",
+        child = "vt:filetime"
+    )]
+    pub value: Option<CustomValue<'a>>,
+}
+
+/// The value of a custom property
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomValue<'a> {
+    /// Wide string, `vt:lpwstr`
+    LpWStr(Cow<'a, str>),
+    /// String, `vt:lpstr`
+    LpStr(Cow<'a, str>),
+    /// 4-byte signed integer, `vt:i4`
+    I4(i32),
+    /// 8-byte real number, `vt:r8`
+    R8(f64),
+    /// Boolean, `vt:bool`
+    Bool(bool),
+    /// Date and time, `vt:filetime`
+    FileTime(DateTime),
+}
+
+impl<'a> CustomValue<'a> {
+    fn tag(&self) -> &'static str {
+        match self {
+            CustomValue::LpWStr(_) => "vt:lpwstr",
+            CustomValue::LpStr(_) => "vt:lpstr",
+            CustomValue::I4(_) => "vt:i4",
+            CustomValue::R8(_) => "vt:r8",
+            CustomValue::Bool(_) => "vt:bool",
+            CustomValue::FileTime(_) => "vt:filetime",
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CustomValue<'a> {
+    fn from(val: &'a str) -> Self {
+        CustomValue::LpWStr(val.into())
+    }
+}
+
+impl From<String> for CustomValue<'_> {
+    fn from(val: String) -> Self {
+        CustomValue::LpWStr(val.into())
+    }
+}
+
+impl From<i32> for CustomValue<'_> {
+    fn from(val: i32) -> Self {
+        CustomValue::I4(val)
+    }
+}
+
+impl From<f64> for CustomValue<'_> {
+    fn from(val: f64) -> Self {
+        CustomValue::R8(val)
+    }
+}
+
+impl From<bool> for CustomValue<'_> {
+    fn from(val: bool) -> Self {
+        CustomValue::Bool(val)
+    }
+}
+
+impl From<DateTime> for CustomValue<'_> {
+    fn from(val: DateTime) -> Self {
+        CustomValue::FileTime(val)
+    }
+}
+
+impl<'i: 'a, 'a> XmlRead<'i> for CustomValue<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let tag = reader
+            .find_element_start(None)?
+            .ok_or(XmlError::UnexpectedEof)?;
+
+        // skip element start
+        reader.next();
+
+        let text = match reader.peek() {
+            // an empty value is written as an empty element, e.g. `<vt:lpwstr/>`
+            Some(Ok(Token::ElementEnd {
+                end: ElementEnd::Empty,
+                ..
+            })) => {
+                reader.next();
+                Cow::Borrowed("")
+            }
+            _ => reader.read_text(tag)?,
+        };
+
+        fn parse<T>(text: &str) -> XmlResult<T>
+        where
+            T: std::str::FromStr,
+            T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+        {
+            text.trim()
+                .parse()
+                .map_err(|e: T::Err| XmlError::FromStr(e.into()))
+        }
+
+        match tag {
+            "vt:lpwstr" => Ok(CustomValue::LpWStr(text)),
+            "vt:lpstr" => Ok(CustomValue::LpStr(text)),
+            "vt:i4" => Ok(CustomValue::I4(parse(&text)?)),
+            "vt:r8" => Ok(CustomValue::R8(parse(&text)?)),
+            "vt:bool" => match text.trim() {
+                "true" | "1" => Ok(CustomValue::Bool(true)),
+                "false" | "0" => Ok(CustomValue::Bool(false)),
+                _ => Ok(CustomValue::Bool(parse(&text)?)),
+            },
+            "vt:filetime" => Ok(CustomValue::FileTime(parse(&text)?)),
+            tag => Err(XmlError::UnexpectedToken {
+                token: tag.to_owned(),
+            }),
+        }
+    }
+}
+
+impl<'a> XmlWrite for CustomValue<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let text = match self {
+            CustomValue::LpWStr(val) | CustomValue::LpStr(val) => val.to_string(),
+            CustomValue::I4(val) => val.to_string(),
+            CustomValue::R8(val) => val.to_string(),
+            CustomValue::Bool(val) => val.to_string(),
+            CustomValue::FileTime(val) => val.to_string(),
+        };
+
+        writer.write_flatten_text(self.tag(), &text)?;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn set_custom_property() {
+    let mut custom = Custom::default();
+    custom.set("foo", "bar").set("baz", 1.5).set("foo", false);
+
+    assert_eq!(custom.properties.len(), 2);
+    assert_eq!(custom.properties[0].pid, 2);
+    assert_eq!(custom.properties[1].pid, 3);
+    assert_eq!(custom.get("foo"), Some(&CustomValue::Bool(false)));
+    assert_eq!(custom.remove("baz"), Some(CustomValue::R8(1.5)));
+    assert_eq!(custom.get("baz"), None);
+}
+
+#[test]
+fn read_custom_property() {
+    let xml = format!(
+        r#"<Properties {}><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="2" name="Empty"><vt:lpwstr/></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="3" name="Large"><vt:i8>4294967296</vt:i8></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="4" name="Name"><vt:lpwstr>foo</vt:lpwstr></property></Properties>"#,
+        NAMESPACES
+    );
+
+    let custom = Custom::from_str(&xml).unwrap();
+    assert_eq!(custom.properties.len(), 3);
+    assert_eq!(custom.get("Empty"), Some(&CustomValue::LpWStr("".into())));
+    assert_eq!(custom.get("Large"), None);
+    assert_eq!(custom.get("Name"), Some(&CustomValue::LpWStr("foo".into())));
+
+    assert_eq!(
+        custom.to_string().unwrap(),
+        format!(
+            r#"<Properties {}><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="2" name="Empty"><vt:lpwstr></vt:lpwstr></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="4" name="Name"><vt:lpwstr>foo</vt:lpwstr></property></Properties>"#,
+            NAMESPACES
+        )
+    );
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes""#;
+
+__xml_test_suites!(
+    Custom,
+    Custom::default(),
+    format!(r#"<Properties {}/>"#, NAMESPACES).as_str(),
+    {
+        let mut custom = Custom::default();
+        custom
+            .set("ContractId", "C-1")
+            .set("Client", -7)
+            .set("Rate", 0.25)
+            .set("Signed", true)
+            .set(
+                "Date",
+                "2020-01-01T00:00:00Z".parse::<DateTime>().unwrap(),
+            );
+        custom
+    },
+    format!(
+        r#"<Properties {}><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="2" name="ContractId"><vt:lpwstr>C-1</vt:lpwstr></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="3" name="Client"><vt:i4>-7</vt:i4></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="4" name="Rate"><vt:r8>0.25</vt:r8></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="5" name="Signed"><vt:bool>true</vt:bool></property><property fmtid="{{D5CDD505-2E9C-101B-9397-08002B2CF9AE}}" pid="6" name="Date"><vt:filetime>2020-01-01T00:00:00Z</vt:filetime></property></Properties>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::schema::SCHEMA_CUSTOM_XML;
+use crate::{__setter, __xml_test_suites};
+
+/// Custom XML Data Properties
+///
+/// The corresponding ZIP item is `/customXml/itemProps{n}.xml`.
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "ds:datastoreItem")]
+pub struct DataStoreItem<'a> {
+    /// Specifies the unique identifier of the custom XML part, a GUID in braces.
+    #[xml(attr = "ds:itemID")]
+    pub item_id: Cow<'a, str>,
+    /// Specifies the schemas used by the custom XML part.
+    #[xml(child = "ds:schemaRefs")]
+    pub schema_refs: Option<SchemaRefs<'a>>,
+}
+
+impl<'a> DataStoreItem<'a> {
+    __setter!(schema_refs: Option<SchemaRefs<'a>>);
+}
+
+impl<'a> XmlWrite for DataStoreItem<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let DataStoreItem {
+            item_id,
+            schema_refs,
+        } = self;
+
+        log::debug!("[DataStoreItem] Started writing.");
+
+        writer.write_element_start("ds:datastoreItem")?;
+
+        writer.write_attribute("ds:itemID", item_id)?;
+        writer.write_attribute("xmlns:ds", SCHEMA_CUSTOM_XML)?;
+
+        if let Some(schema_refs) = schema_refs {
+            writer.write_element_end_open()?;
+            schema_refs.to_writer(writer)?;
+            writer.write_element_end_close("ds:datastoreItem")?;
+        } else {
+            writer.write_element_end_empty()?;
+        }
+
+        log::debug!("[DataStoreItem] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Set of Associated XML Schemas
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "ds:schemaRefs")]
+pub struct SchemaRefs<'a> {
+    #[xml(child = "ds:schemaRef")]
+    pub refs: Vec<SchemaRef<'a>>,
+}
+
+impl<'a> SchemaRefs<'a> {
+    #[inline(always)]
+    pub fn push<T: Into<Cow<'a, str>>>(mut self, uri: T) -> Self {
+        self.refs.push(SchemaRef { uri: uri.into() });
+        self
+    }
+}
+
+/// Associated XML Schema
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "ds:schemaRef")]
+pub struct SchemaRef<'a> {
+    /// Specifies the target namespace of the schema.
+    #[xml(attr = "ds:uri")]
+    pub uri: Cow<'a, str>,
+}
+
+#[cfg(test)]
+const NAMESPACES: &str =
+    r#"xmlns:ds="http://schemas.openxmlformats.org/officeDocument/2006/customXml""#;
+
+__xml_test_suites!(
+    DataStoreItem,
+    DataStoreItem {
+        item_id: "{0}".into(),
+        schema_refs: None,
+    },
+    format!(r#"<ds:datastoreItem ds:itemID="{{0}}" {}/>"#, NAMESPACES).as_str(),
+    DataStoreItem {
+        item_id: "{0}".into(),
+        schema_refs: Some(SchemaRefs::default().push("urn:contract")),
+    },
+    format!(
+        r#"<ds:datastoreItem ds:itemID="{{0}}" {}><ds:schemaRefs><ds:schemaRef ds:uri="urn:contract"/></ds:schemaRefs></ds:datastoreItem>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
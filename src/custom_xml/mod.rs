@@ -0,0 +1,118 @@
+//! Custom XML Data Storage part
+//!
+//! The corresponding ZIP items are `/customXml/item{n}.xml` and
+//! `/customXml/itemProps{n}.xml`.
+
+mod datastore_item;
+mod xpath;
+
+pub use self::datastore_item::*;
+
+use std::borrow::Cow;
+
+use crate::error::DocxResult;
+
+/// A custom XML part and its properties
+///
+/// Content controls bound to the part via `w:dataBinding` can be updated
+/// with `Docx::update_data_bindings`.
+///
+/// ```rust
+/// use docx::custom_xml::CustomXml;
+///
+/// let mut xml =
+///     CustomXml::new(r#"<contract xmlns="urn:contract"><client/></contract>"#).unwrap();
+/// let mappings = Some("xmlns:ns0='urn:contract'");
+///
+/// xml.set("/ns0:contract[1]/ns0:client[1]", mappings, "ACME").unwrap();
+/// assert_eq!(
+///     xml.get("/ns0:contract[1]/ns0:client[1]", mappings).unwrap(),
+///     Some("ACME".to_owned())
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct CustomXml<'a> {
+    /// Specifies the content of the part.
+    pub data: Cow<'a, str>,
+    /// Specifies the properties of the part.
+    pub properties: Option<DataStoreItem<'a>>,
+}
+
+impl<'a> CustomXml<'a> {
+    /// Creates a custom XML part with a random item id
+    ///
+    /// Returns an error if no random numbers are available.
+    pub fn new<T: Into<Cow<'a, str>>>(data: T) -> DocxResult<Self> {
+        Ok(CustomXml {
+            data: data.into(),
+            properties: Some(DataStoreItem {
+                item_id: new_guid()?.into(),
+                schema_refs: None,
+            }),
+        })
+    }
+
+    /// Returns the item id which data bindings refer to
+    pub fn item_id(&self) -> Option<&str> {
+        self.properties.as_ref().map(|props| &*props.item_id)
+    }
+
+    /// Returns the text of the element or attribute selected by `xpath`
+    pub fn get(&self, xpath: &str, prefix_mappings: Option<&str>) -> DocxResult<Option<String>> {
+        match xpath::select(&self.data, xpath, prefix_mappings)? {
+            Some(node) => Ok(Some(node.text(&self.data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Replaces the text of the element or attribute selected by `xpath`
+    ///
+    /// Returns `false` if no such node exists.
+    pub fn set(
+        &mut self,
+        xpath: &str,
+        prefix_mappings: Option<&str>,
+        text: &str,
+    ) -> DocxResult<bool> {
+        let data = match xpath::select(&self.data, xpath, prefix_mappings)? {
+            Some(node) => node.replace(&self.data, text),
+            None => return Ok(false),
+        };
+        self.data = data.into();
+        Ok(true)
+    }
+}
+
+/// Generates a random GUID in braces, e.g. `{B3A8F4E2-6D0C-4E5B-9F8A-2C1D7E3F4A5B}`
+fn new_guid() -> DocxResult<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes)?;
+
+    // version 4, variant 1
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+
+    Ok(format!(
+        "{{{}-{}-{}-{}-{}}}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+#[test]
+fn new_item_id() -> DocxResult<()> {
+    let xml = CustomXml::new("<root/>")?;
+    let id = xml.item_id().unwrap();
+
+    assert_eq!(id.len(), 38);
+    assert!(id.starts_with('{') && id.ends_with('}'));
+    assert_eq!(&id[15..16], "4");
+    assert_ne!(id, CustomXml::new("<root/>")?.item_id().unwrap());
+
+    Ok(())
+}
@@ -0,0 +1,350 @@
+//! A minimal XPath evaluator for data bindings
+//!
+//! Only location paths made of child steps are supported, like
+//! `/ns0:contract[1]/ns0:client[1]/@ns0:id`, which is what Word generates.
+
+use std::ops::Range;
+use strong_xml::utils::{xml_escape, xml_unescape};
+use strong_xml::xmlparser::{ElementEnd, Token, Tokenizer};
+
+use crate::error::{DocxError, DocxResult};
+
+/// An element of the custom XML
+struct Element<'s> {
+    parent: Option<usize>,
+    namespace: Option<&'s str>,
+    local: &'s str,
+    name: &'s str,
+    attributes: Vec<Attribute<'s>>,
+    /// Range of the content, or the `/>` of an empty element
+    content: Range<usize>,
+    empty: bool,
+    text: String,
+}
+
+struct Attribute<'s> {
+    namespace: Option<&'s str>,
+    local: &'s str,
+    value: Range<usize>,
+}
+
+/// The node selected by a XPath expression
+pub(crate) enum Node<'s> {
+    Element {
+        name: &'s str,
+        content: Range<usize>,
+        empty: bool,
+        text: String,
+    },
+    Attribute {
+        value: Range<usize>,
+    },
+}
+
+impl Node<'_> {
+    /// Returns the text of the node
+    pub fn text(&self, data: &str) -> DocxResult<String> {
+        match self {
+            Node::Element { text, .. } => Ok(text.clone()),
+            Node::Attribute { value } => Ok(xml_unescape(&data[value.clone()])?.into_owned()),
+        }
+    }
+
+    /// Returns a copy of the XML with the text of the node replaced
+    pub fn replace(&self, data: &str, text: &str) -> String {
+        let text = xml_escape(text);
+        let (range, replacement) = match self {
+            Node::Element {
+                name,
+                content,
+                empty: true,
+                ..
+            } => (content.clone(), format!(">{}</{}>", text, name)),
+            Node::Element { content, .. } | Node::Attribute { value: content } => {
+                (content.clone(), text.into_owned())
+            }
+        };
+
+        let mut result = String::with_capacity(data.len() + replacement.len());
+        result.push_str(&data[..range.start]);
+        result.push_str(&replacement);
+        result.push_str(&data[range.end..]);
+        result
+    }
+}
+
+/// Selects the node in `data` using the XPath expression
+pub(crate) fn select<'s>(
+    data: &'s str,
+    xpath: &str,
+    prefix_mappings: Option<&str>,
+) -> DocxResult<Option<Node<'s>>> {
+    let mappings = parse_prefix_mappings(prefix_mappings.unwrap_or_default())?;
+    let resolve = |prefix: &str| -> DocxResult<Option<String>> {
+        if prefix.is_empty() {
+            return Ok(None);
+        }
+        mappings
+            .iter()
+            .find(|(p, _)| p == prefix)
+            .map(|(_, uri)| Some(uri.clone()))
+            .ok_or_else(|| DocxError::CustomXml(format!("Unknown prefix `{}` in XPath", prefix)))
+    };
+
+    let elements = parse(data)?;
+
+    let steps: Vec<&str> = xpath
+        .trim()
+        .strip_prefix('/')
+        .ok_or_else(|| DocxError::CustomXml(format!("Unsupported XPath `{}`", xpath)))?
+        .split('/')
+        .collect();
+
+    let mut current: Option<usize> = None;
+
+    for (i, step) in steps.iter().enumerate() {
+        if let Some(attr) = step.strip_prefix('@') {
+            if i != steps.len() - 1 {
+                return Err(DocxError::CustomXml(format!(
+                    "Unsupported XPath `{}`",
+                    xpath
+                )));
+            }
+            let element = match current {
+                Some(current) => &elements[current],
+                None => return Ok(None),
+            };
+            let (prefix, local) = split_name(attr);
+            let namespace = resolve(prefix)?;
+            return Ok(element
+                .attributes
+                .iter()
+                .find(|a| a.local == local && a.namespace == namespace.as_deref())
+                .map(|a| Node::Attribute {
+                    value: a.value.clone(),
+                }));
+        }
+
+        let (name, position) = match step.find('[') {
+            Some(index) => {
+                let position = step[index + 1..]
+                    .strip_suffix(']')
+                    .and_then(|n| n.trim().parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| {
+                        DocxError::CustomXml(format!("Unsupported XPath `{}`", xpath))
+                    })?;
+                (&step[..index], position)
+            }
+            None => (*step, 1),
+        };
+        let (prefix, local) = split_name(name);
+        let namespace = resolve(prefix)?;
+
+        match elements
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                e.parent == current && e.local == local && e.namespace == namespace.as_deref()
+            })
+            .nth(position - 1)
+        {
+            Some((index, _)) => current = Some(index),
+            None => return Ok(None),
+        }
+    }
+
+    Ok(current.map(|index| {
+        let element = &elements[index];
+        Node::Element {
+            name: element.name,
+            content: element.content.clone(),
+            empty: element.empty,
+            text: element.text.clone(),
+        }
+    }))
+}
+
+fn split_name(name: &str) -> (&str, &str) {
+    match name.find(':') {
+        Some(index) => (&name[..index], &name[index + 1..]),
+        None => ("", name),
+    }
+}
+
+/// Parses prefix mappings like `xmlns:ns0='urn:a' xmlns:ns1='urn:b'`
+fn parse_prefix_mappings(mappings: &str) -> DocxResult<Vec<(String, String)>> {
+    let mappings = xml_unescape(mappings)?;
+
+    mappings
+        .split_whitespace()
+        .map(|mapping| {
+            let (name, uri) = match mapping.find('=') {
+                Some(index) => (&mapping[..index], &mapping[index + 1..]),
+                None => (mapping, ""),
+            };
+            let prefix = name.strip_prefix("xmlns:").ok_or_else(|| {
+                DocxError::CustomXml(format!("Invalid prefix mapping `{}`", mapping))
+            })?;
+            let uri = uri.trim_matches(|c| c == '\'' || c == '"');
+            Ok((prefix.to_owned(), uri.to_owned()))
+        })
+        .collect()
+}
+
+/// Position, prefix, local name and attributes of the element being parsed
+type StartTag<'s> = (
+    usize,
+    &'s str,
+    &'s str,
+    Vec<(&'s str, &'s str, Range<usize>)>,
+);
+
+/// Parses the elements of the XML in document order
+fn parse(data: &str) -> DocxResult<Vec<Element<'_>>> {
+    let mut elements: Vec<Element> = Vec::new();
+    // open elements and their namespace declarations
+    let mut stack: Vec<(usize, Vec<(&str, &str)>)> = Vec::new();
+    let mut pending: Option<StartTag> = None;
+
+    fn resolve<'s>(stack: &[(usize, Vec<(&str, &'s str)>)], prefix: &str) -> Option<&'s str> {
+        stack
+            .iter()
+            .rev()
+            .flat_map(|(_, decls)| decls.iter().rev())
+            .find(|(p, _)| *p == prefix)
+            .map(|(_, uri)| *uri)
+            .filter(|uri| !uri.is_empty())
+    }
+
+    for token in Tokenizer::from(data) {
+        let token = token.map_err(|err| DocxError::CustomXml(err.to_string()))?;
+
+        match token {
+            Token::ElementStart {
+                prefix,
+                local,
+                span,
+            } => {
+                pending = Some((span.start(), prefix.as_str(), local.as_str(), Vec::new()));
+            }
+            Token::Attribute {
+                prefix,
+                local,
+                value,
+                ..
+            } => {
+                if let Some((_, _, _, attrs)) = &mut pending {
+                    attrs.push((prefix.as_str(), local.as_str(), value.range()));
+                }
+            }
+            Token::ElementEnd { end, span } => match end {
+                ElementEnd::Open | ElementEnd::Empty => {
+                    let (start, prefix, local, attrs) = match pending.take() {
+                        Some(pending) => pending,
+                        None => continue,
+                    };
+
+                    let decls: Vec<(&str, &str)> = attrs
+                        .iter()
+                        .filter_map(|(p, l, value)| match (*p, *l) {
+                            ("xmlns", l) => Some((l, &data[value.clone()])),
+                            ("", "xmlns") => Some(("", &data[value.clone()])),
+                            _ => None,
+                        })
+                        .collect();
+
+                    stack.push((elements.len(), decls));
+
+                    let attributes = attrs
+                        .into_iter()
+                        .filter(|(p, l, _)| *p != "xmlns" && !(p.is_empty() && *l == "xmlns"))
+                        .map(|(p, l, value)| Attribute {
+                            namespace: if p.is_empty() {
+                                None
+                            } else {
+                                resolve(&stack, p)
+                            },
+                            local: l,
+                            value,
+                        })
+                        .collect();
+
+                    let empty = matches!(end, ElementEnd::Empty);
+                    let name_end =
+                        start + 1 + prefix.len() + local.len() + usize::from(!prefix.is_empty());
+
+                    elements.push(Element {
+                        parent: stack.iter().rev().nth(1).map(|(index, _)| *index),
+                        namespace: resolve(&stack, prefix),
+                        local,
+                        name: &data[start + 1..name_end],
+                        attributes,
+                        content: if empty {
+                            span.range()
+                        } else {
+                            span.end()..span.end()
+                        },
+                        empty,
+                        text: String::new(),
+                    });
+
+                    if empty {
+                        stack.pop();
+                    }
+                }
+                ElementEnd::Close(_, _) => {
+                    if let Some((index, _)) = stack.pop() {
+                        elements[index].content.end = span.start();
+                    }
+                }
+            },
+            Token::Text { text } => {
+                let text = xml_unescape(text.as_str())?;
+                for (index, _) in &stack {
+                    elements[*index].text.push_str(&text);
+                }
+            }
+            Token::Cdata { text, .. } => {
+                for (index, _) in &stack {
+                    elements[*index].text.push_str(text.as_str());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(elements)
+}
+
+#[test]
+fn select_nodes() -> DocxResult<()> {
+    let data = r#"<?xml version="1.0"?><c:contract xmlns:c="urn:c" id="1"><c:client>ACME &amp; Co</c:client><c:client c:id="x"/><other/></c:contract>"#;
+    let mappings = Some("xmlns:ns0='urn:c'");
+
+    let node = select(data, "/ns0:contract[1]/ns0:client[1]", mappings)?.unwrap();
+    assert_eq!(node.text(data)?, "ACME & Co");
+    assert_eq!(
+        node.replace(data, "Foo < Bar"),
+        data.replace("ACME &amp; Co", "Foo &lt; Bar")
+    );
+
+    let node = select(data, "/ns0:contract[1]/ns0:client[2]", mappings)?.unwrap();
+    assert_eq!(node.text(data)?, "");
+    assert_eq!(
+        node.replace(data, "Baz"),
+        data.replace(r#"c:id="x"/>"#, r#"c:id="x">Baz</c:client>"#)
+    );
+
+    let node = select(data, "/ns0:contract[1]/ns0:client[2]/@ns0:id", mappings)?.unwrap();
+    assert_eq!(node.text(data)?, "x");
+
+    let node = select(data, "/ns0:contract/@id", mappings)?.unwrap();
+    assert_eq!(node.text(data)?, "1");
+
+    assert!(select(data, "/ns0:contract[1]/ns0:client[3]", mappings)?.is_none());
+    assert!(select(data, "/ns0:contract[1]/other[1]", mappings)?.is_some());
+    assert!(select(data, "/ns1:contract[1]", mappings).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::fmt;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+/// A date and time in UTC
+///
+/// It's parsed from and formatted as the W3C Date and Time Format (W3CDTF),
+/// which is a profile of ISO 8601 used by document properties and revisions.
+///
+/// ```rust
+/// use docx::DateTime;
+///
+/// let date: DateTime = "2020-04-01T08:30:00+08:00".parse().unwrap();
+/// assert_eq!(date.to_string(), "2020-04-01T00:30:00Z");
+///
+/// let date: DateTime = "2020-04".parse().unwrap();
+/// assert_eq!(date.to_string(), "2020-04-01T00:00:00Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime(pub chrono::DateTime<Utc>);
+
+impl DateTime {
+    /// Returns the current date and time
+    pub fn now() -> Self {
+        DateTime::from(SystemTime::now())
+    }
+}
+
+impl From<chrono::DateTime<Utc>> for DateTime {
+    fn from(val: chrono::DateTime<Utc>) -> Self {
+        DateTime(val)
+    }
+}
+
+impl From<SystemTime> for DateTime {
+    fn from(val: SystemTime) -> Self {
+        DateTime(val.into())
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.format("%Y-%m-%dT%H:%M:%SZ"))
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // complete date plus time with time zone designator
+        let with_offset = match s.strip_suffix('Z') {
+            Some(s) => format!("{}+00:00", s),
+            None => s.to_owned(),
+        };
+        for fmt in &["%Y-%m-%dT%H:%M:%S%.f%:z", "%Y-%m-%dT%H:%M%:z"] {
+            if let Ok(date) = chrono::DateTime::parse_from_str(&with_offset, fmt) {
+                return Ok(DateTime(date.with_timezone(&Utc)));
+            }
+        }
+
+        // complete date plus time without time zone designator, treated as UTC
+        for fmt in &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"] {
+            if let Ok(date) = NaiveDateTime::parse_from_str(s, fmt) {
+                return Ok(DateTime(Utc.from_utc_datetime(&date)));
+            }
+        }
+
+        // year, year and month, or complete date
+        let date = match s.len() {
+            4 => format!("{}-01-01", s),
+            7 => format!("{}-01", s),
+            _ => s.to_owned(),
+        };
+        if let Ok(date) = NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
+            if let Some(date) = date.and_hms_opt(0, 0, 0) {
+                return Ok(DateTime(Utc.from_utc_datetime(&date)));
+            }
+        }
+
+        Err(format!("Invalid W3CDTF date time. Found `{}`", s))
+    }
+}
+
+#[test]
+fn parse_w3cdtf() {
+    fn parse(s: &str) -> String {
+        s.parse::<DateTime>().unwrap().to_string()
+    }
+
+    assert_eq!(parse("1997"), "1997-01-01T00:00:00Z");
+    assert_eq!(parse("1997-07"), "1997-07-01T00:00:00Z");
+    assert_eq!(parse("1997-07-16"), "1997-07-16T00:00:00Z");
+    assert_eq!(parse("1997-07-16T19:20+01:00"), "1997-07-16T18:20:00Z");
+    assert_eq!(parse("1997-07-16T19:20:30+01:00"), "1997-07-16T18:20:30Z");
+    assert_eq!(
+        parse("1997-07-16T19:20:30.45+01:00"),
+        "1997-07-16T18:20:30Z"
+    );
+    assert_eq!(parse("1997-07-16T19:20:30Z"), "1997-07-16T19:20:30Z");
+    assert_eq!(parse("1997-07-16T19:20:30"), "1997-07-16T19:20:30Z");
+    assert!("16/07/1997".parse::<DateTime>().is_err());
+}
@@ -0,0 +1,307 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    document::{
+        for_each_paragraph, for_each_run, Drawing, Paragraph, Pict, PictContent, Run, RunContent,
+        Text, TextBoxContent, TextSpace,
+    },
+    drawing::Fill,
+    vml::{Line, Rect, RoundRect, TextBox},
+};
+
+/// Alternate Content
+///
+/// Contains a DrawingML shape along with its VML fallback, for readers
+/// which don't support DrawingML shapes. `From<Drawing>` creates the
+/// fallback from the shape of the drawing, keeping the plain text of its
+/// text box.
+///
+/// ```rust
+/// use docx::document::*;
+/// use docx::drawing::*;
+///
+/// let shape = Shape::text_box(1828800, 457200).push(Paragraph::default().push_text("Note"));
+/// let content = AlternateContent::from(Drawing::inline_shape(shape));
+///
+/// assert!(content.fallback.is_some());
+///
+/// let para = Paragraph::default().push(Run::default().push(content));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "mc:AlternateContent")]
+pub struct AlternateContent<'a> {
+    /// Specifies the preferred content.
+    #[xml(child = "mc:Choice")]
+    pub choice: Option<Choice<'a>>,
+    /// Specifies the content used if the preferred one isn't supported.
+    #[xml(child = "mc:Fallback")]
+    pub fallback: Option<Fallback<'a>>,
+}
+
+impl<'a> AlternateContent<'a> {
+    /// Returns the drawing of the preferred content
+    pub fn drawing(&self) -> Option<&Drawing<'a>> {
+        self.choice
+            .as_ref()
+            .and_then(|choice| choice.drawing.as_ref())
+    }
+
+    pub fn drawing_mut(&mut self) -> Option<&mut Drawing<'a>> {
+        self.choice
+            .as_mut()
+            .and_then(|choice| choice.drawing.as_mut())
+    }
+
+    /// Returns if the preferred content is a modeled word processing shape
+    ///
+    /// Other content, e.g. group shapes, canvases or ink, would be written
+    /// back without its content.
+    pub(crate) fn is_known(&self) -> bool {
+        match &self.choice {
+            Some(choice) => {
+                choice.requires == "wps"
+                    && matches!(&choice.drawing, Some(drawing) if drawing.shape().is_some())
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a> From<Drawing<'a>> for AlternateContent<'a> {
+    fn from(drawing: Drawing<'a>) -> Self {
+        let fallback = fallback(&drawing).map(|pict| Fallback { pict: Some(pict) });
+        AlternateContent {
+            choice: Some(Choice {
+                requires: "wps".into(),
+                drawing: Some(drawing),
+            }),
+            fallback,
+        }
+    }
+}
+
+/// Preferred Content
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "mc:Choice")]
+pub struct Choice<'a> {
+    /// Specifies the prefixes of the namespaces required by the content.
+    #[xml(attr = "Requires")]
+    pub requires: Cow<'a, str>,
+    #[xml(child = "w:drawing")]
+    pub drawing: Option<Drawing<'a>>,
+}
+
+/// Fallback Content
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "mc:Fallback")]
+pub struct Fallback<'a> {
+    #[xml(child = "w:pict")]
+    pub pict: Option<Pict<'a>>,
+}
+
+const EMU_PER_POINT: f64 = 12700.0;
+
+/// Creates the VML fallback of the shape in the drawing
+///
+/// Preset geometries other than lines and rounded rectangles fall back to
+/// rectangles.
+fn fallback<'a>(drawing: &Drawing<'a>) -> Option<Pict<'a>> {
+    let shape = drawing.shape()?;
+    let (width, height) = drawing.extent()?;
+
+    let mut position = String::new();
+    if let Some(anchor) = &drawing.anchor {
+        position.push_str("position:absolute;");
+        if let Some(offset) = anchor.position_h.offset {
+            let _ = write!(position, "margin-left:{}pt;", offset as f64 / EMU_PER_POINT);
+        }
+        if let Some(offset) = anchor.position_v.offset {
+            let _ = write!(position, "margin-top:{}pt;", offset as f64 / EMU_PER_POINT);
+        }
+        if anchor.behind_doc {
+            position.push_str("z-index:-1;");
+        }
+    }
+
+    let width = width as f64 / EMU_PER_POINT;
+    let height = height as f64 / EMU_PER_POINT;
+    let style = format!("{}width:{}pt;height:{}pt", position, width, height);
+
+    let (stroke_color, stroke_weight, stroked) = match &shape.property.outline {
+        Some(outline) => (
+            outline.fill.as_ref().and_then(Fill::color).map(hex_color),
+            outline
+                .width
+                .map(|width| format!("{}pt", width as f64 / EMU_PER_POINT).into()),
+            match outline.fill {
+                Some(Fill::None(_)) => Some(false),
+                _ => None,
+            },
+        ),
+        None => (None, None, None),
+    };
+
+    if shape.geometry() == Some("line") {
+        let position = position.trim_end_matches(';');
+        return Some(Pict::default().push(Line {
+            id: None,
+            style: Some(position.to_string().into()).filter(|_| !position.is_empty()),
+            from: Some("0,0".into()),
+            to: Some(format!("{}pt,{}pt", width, height).into()),
+            stroke_color,
+            stroke_weight,
+            stroked,
+        }));
+    }
+
+    let fill_color = shape
+        .property
+        .fill
+        .as_ref()
+        .and_then(Fill::color)
+        .map(hex_color);
+    let filled = match shape.property.fill {
+        Some(Fill::None(_)) => Some(false),
+        _ => None,
+    };
+    let text_box = shape
+        .text_box_content()
+        .map(|content| TextBox::from(plain_text(content)));
+
+    let content = if shape.geometry() == Some("roundRect") {
+        PictContent::from(RoundRect {
+            id: None,
+            style: Some(style.into()),
+            arc_size: None,
+            fill_color,
+            filled,
+            stroke_color,
+            stroke_weight,
+            stroked,
+            text_box,
+        })
+    } else {
+        PictContent::from(Rect {
+            id: None,
+            style: Some(style.into()),
+            fill_color,
+            filled,
+            stroke_color,
+            stroke_weight,
+            stroked,
+            text_box,
+        })
+    };
+
+    Some(Pict::default().push(content))
+}
+
+fn hex_color<'a>(color: &str) -> Cow<'a, str> {
+    format!("#{}", color).into()
+}
+
+/// Copies the text of the paragraphs with the run properties
+fn plain_text<'a>(content: &TextBoxContent<'a>) -> TextBoxContent<'a> {
+    let mut copy = TextBoxContent::default();
+
+    for_each_paragraph(&content.content, &mut |para| {
+        let mut paragraph = Paragraph::default();
+        for_each_run(&para.content, &mut |run| {
+            let text: Vec<RunContent> = run
+                .content
+                .iter()
+                .filter_map(|content| match content {
+                    RunContent::Text(text) => Some(
+                        Text {
+                            space: Some(TextSpace::Preserve),
+                            text: text.text.clone(),
+                        }
+                        .into(),
+                    ),
+                    _ => None,
+                })
+                .collect();
+            if !text.is_empty() {
+                paragraph.content.push(
+                    Run {
+                        property: run.property.clone(),
+                        content: text,
+                    }
+                    .into(),
+                );
+            }
+        });
+        copy.push(paragraph);
+    });
+
+    copy
+}
+
+#[cfg(test)]
+use crate::drawing::{Outline, Shape};
+
+__xml_test_suites!(
+    AlternateContent,
+    AlternateContent::default(),
+    r#"<mc:AlternateContent/>"#,
+    AlternateContent::from(Drawing::inline_shape(
+        Shape::new("rect", 25400, 12700)
+            .fill(Fill::solid("FF0000"))
+            .outline(
+                Outline::default()
+                    .width(6350u64)
+                    .fill(Fill::solid("000000"))
+            )
+    )),
+    r##"<mc:AlternateContent><mc:Choice Requires="wps"><w:drawing><wp:inline><wp:extent cx="25400" cy="12700"/><wp:docPr id="0" name="Shape"/><a:graphic><a:graphicData uri="http://schemas.microsoft.com/office/word/2010/wordprocessingShape"><wps:wsp><wps:cNvSpPr/><wps:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="25400" cy="12700"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:solidFill><a:srgbClr val="FF0000"/></a:solidFill><a:ln w="6350"><a:solidFill><a:srgbClr val="000000"/></a:solidFill></a:ln></wps:spPr><wps:bodyPr/></wps:wsp></a:graphicData></a:graphic></wp:inline></w:drawing></mc:Choice><mc:Fallback><w:pict><v:rect style="width:2pt;height:1pt" fillcolor="#FF0000" strokecolor="#000000" strokeweight="0.5pt"/></w:pict></mc:Fallback></mc:AlternateContent>"##,
+);
+
+#[test]
+fn text_box_fallback() {
+    use crate::drawing::*;
+
+    let shape = Shape::text_box(914400, 457200)
+        .push(Paragraph::default().push_text("Lorem ").push_text("ipsum"))
+        .push(Paragraph::default().push(Run::default().push(Drawing::default())));
+    let drawing = Drawing::inline_shape(shape).anchor(
+        Anchor::default()
+            .position_h(HorizontalPosition::offset(
+                HorizontalRelativeFrom::Page,
+                12700,
+            ))
+            .position_v(VerticalPosition::offset(VerticalRelativeFrom::Page, 25400)),
+    );
+    let content = AlternateContent::from(drawing);
+
+    let pict = content.fallback.unwrap().pict.unwrap();
+    let rect = match &pict.content[0] {
+        PictContent::Rect(rect) => rect,
+        _ => panic!("text boxes fall back to rectangles"),
+    };
+    assert_eq!(
+        rect.style.as_deref(),
+        Some("position:absolute;margin-left:1pt;margin-top:2pt;width:72pt;height:36pt")
+    );
+    assert_eq!(rect.fill_color.as_deref(), Some("#FFFFFF"));
+
+    let text_box = &rect.text_box.as_ref().unwrap().content;
+    assert_eq!(text_box.content.len(), 2);
+    assert_eq!(
+        text_box
+            .iter_text_in(crate::document::RevisionView::Accepted)
+            .collect::<Vec<_>>(),
+        ["Lorem ", "ipsum"]
+    );
+
+    let line = AlternateContent::from(Drawing::inline_shape(Shape::line(914400, 0)));
+    match &line.fallback.unwrap().pict.unwrap().content[0] {
+        PictContent::Line(line) => assert_eq!(line.to.as_deref(), Some("72pt,0pt")),
+        _ => panic!("lines fall back to lines"),
+    }
+}
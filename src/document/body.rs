@@ -2,7 +2,8 @@ use derive_more::From;
 use strong_xml::{XmlRead, XmlWrite};
 
 use crate::__xml_test_suites;
-use crate::document::{Paragraph, Table};
+use crate::document::{Paragraph, PermissionEnd, PermissionStart, SdtBlock, Table};
+use crate::formatting::SectionProperty;
 
 /// Document Body
 ///
@@ -12,8 +13,17 @@ use crate::document::{Paragraph, Table};
 #[xml(tag = "w:body")]
 pub struct Body<'a> {
     /// Specifies the contents of the body of the document.
-    #[xml(child = "w:p", child = "w:tbl")]
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
     pub content: Vec<BodyContent<'a>>,
+    /// Specifies the properties of the last section of the document.
+    #[xml(child = "w:sectPr")]
+    pub property: Option<SectionProperty<'a>>,
 }
 
 impl<'a> Body<'a> {
@@ -44,12 +54,19 @@ impl<'a> Body<'a> {
 /// A set of elements that can be contained in the body
 #[derive(Debug, From, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
+// paragraphs are by far the most common content, boxing them isn't worth it
+#[allow(clippy::large_enum_variant)]
 pub enum BodyContent<'a> {
     #[xml(tag = "w:p")]
     Paragraph(Paragraph<'a>),
     #[xml(tag = "w:tbl")]
     Table(Table<'a>),
-    // SecProp,
+    #[xml(tag = "w:sdt")]
+    Sdt(SdtBlock<'a>),
+    #[xml(tag = "w:permStart")]
+    PermissionStart(PermissionStart<'a>),
+    #[xml(tag = "w:permEnd")]
+    PermissionEnd(PermissionEnd<'a>),
 }
 
 __xml_test_suites!(
@@ -57,11 +74,31 @@ __xml_test_suites!(
     Body::default(),
     r#"<w:body/>"#,
     Body {
-        content: vec![Paragraph::default().into()]
+        content: vec![Paragraph::default().into()],
+        ..Default::default()
     },
     r#"<w:body><w:p><w:pPr/></w:p></w:body>"#,
     Body {
-        content: vec![Table::default().into()]
+        content: vec![Table::default().into()],
+        ..Default::default()
     },
     r#"<w:body><w:tbl><w:tblPr/></w:tbl></w:body>"#,
+    Body {
+        content: vec![
+            PermissionStart::default().into(),
+            PermissionEnd::default().into()
+        ],
+        ..Default::default()
+    },
+    r#"<w:body><w:permStart/><w:permEnd/></w:body>"#,
+    Body {
+        content: vec![SdtBlock::default().into()],
+        ..Default::default()
+    },
+    r#"<w:body><w:sdt><w:sdtPr/><w:sdtContent/></w:sdt></w:body>"#,
+    Body {
+        content: vec![Paragraph::default().into()],
+        property: Some(SectionProperty::default())
+    },
+    r#"<w:body><w:p><w:pPr/></w:p><w:sectPr/></w:body>"#,
 );
@@ -0,0 +1,25 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// End of a comment anchor
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:commentRangeEnd")]
+pub struct CommentRangeEnd {
+    /// Specifies the id of the comment.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+}
+
+impl From<usize> for CommentRangeEnd {
+    fn from(id: usize) -> Self {
+        CommentRangeEnd { id }
+    }
+}
+
+__xml_test_suites!(
+    CommentRangeEnd,
+    CommentRangeEnd::from(0),
+    r#"<w:commentRangeEnd w:id="0"/>"#,
+);
@@ -0,0 +1,34 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Beginning of a comment anchor
+///
+/// Specifies the start of the range which the comment with the given id
+/// refers to.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let start = CommentRangeStart::from(0);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:commentRangeStart")]
+pub struct CommentRangeStart {
+    /// Specifies the id of the comment.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+}
+
+impl From<usize> for CommentRangeStart {
+    fn from(id: usize) -> Self {
+        CommentRangeStart { id }
+    }
+}
+
+__xml_test_suites!(
+    CommentRangeStart,
+    CommentRangeStart::from(0),
+    r#"<w:commentRangeStart w:id="0"/>"#,
+);
@@ -0,0 +1,35 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Comment Reference
+///
+/// Refers to a comment by its id, which is returned by `Docx::add_comment`.
+/// Word displays the comment next to the run containing it.
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:commentReference")]
+pub struct CommentReference {
+    #[xml(attr = "w:id")]
+    pub id: usize,
+}
+
+impl From<usize> for CommentReference {
+    fn from(id: usize) -> Self {
+        CommentReference { id }
+    }
+}
+
+/// Annotation Reference Mark
+///
+/// Marks the start of the content of a comment.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:annotationRef")]
+pub struct AnnotationRef {}
+
+__xml_test_suites!(
+    CommentReference,
+    CommentReference::from(1),
+    r#"<w:commentReference w:id="1"/>"#,
+);
@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+/// XML Mapping
+///
+/// Binds the content of a structured document tag to an XML element or
+/// attribute in a custom XML part.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let binding = DataBinding::new(
+///     "/ns0:contract[1]/ns0:client[1]",
+///     "{B3A8F4E2-6D0C-4E5B-9F8A-2C1D7E3F4A5B}",
+/// )
+/// .prefix_mappings("xmlns:ns0='urn:contract'");
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:dataBinding")]
+pub struct DataBinding<'a> {
+    /// Specifies the namespace prefixes used in the XPath expression,
+    /// e.g. `xmlns:ns0='urn:contract'`.
+    #[xml(attr = "w:prefixMappings")]
+    pub prefix_mappings: Option<Cow<'a, str>>,
+    /// Specifies the XPath expression of the bound node.
+    #[xml(attr = "w:xpath")]
+    pub xpath: Cow<'a, str>,
+    /// Specifies the item id of the custom XML part.
+    #[xml(attr = "w:storeItemID")]
+    pub store_item_id: Cow<'a, str>,
+}
+
+impl<'a> DataBinding<'a> {
+    __setter!(prefix_mappings: Option<Cow<'a, str>>);
+
+    pub fn new<X, S>(xpath: X, store_item_id: S) -> Self
+    where
+        X: Into<Cow<'a, str>>,
+        S: Into<Cow<'a, str>>,
+    {
+        DataBinding {
+            prefix_mappings: None,
+            xpath: xpath.into(),
+            store_item_id: store_item_id.into(),
+        }
+    }
+}
+
+__xml_test_suites!(
+    DataBinding,
+    DataBinding::new("/root[1]", "{0}"),
+    r#"<w:dataBinding w:xpath="/root[1]" w:storeItemID="{0}"/>"#,
+    DataBinding::new("/ns0:root[1]", "{0}").prefix_mappings("xmlns:ns0=urn:test"),
+    r#"<w:dataBinding w:prefixMappings="xmlns:ns0=urn:test" w:xpath="/ns0:root[1]" w:storeItemID="{0}"/>"#,
+);
+
+#[test]
+fn escape_prefix_mappings() {
+    assert_eq!(
+        DataBinding::new("/ns0:root[1]", "{0}")
+            .prefix_mappings("xmlns:ns0='urn:test'")
+            .to_string()
+            .unwrap(),
+        r#"<w:dataBinding w:prefixMappings="xmlns:ns0=&apos;urn:test&apos;" w:xpath="/ns0:root[1]" w:storeItemID="{0}"/>"#
+    );
+}
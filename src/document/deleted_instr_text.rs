@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__xml_test_suites, document::TextSpace};
+
+/// Deleted Field Code
+///
+/// The instruction of a complex field which has been deleted as a tracked
+/// change.
+///
+/// ```rust
+/// use docx::document::{DeletedInstrText, TextSpace};
+///
+/// let text = DeletedInstrText::from(" PAGE ");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:delInstrText")]
+pub struct DeletedInstrText<'a> {
+    /// Specifies how to handle whitespace
+    #[xml(attr = "xml:space")]
+    pub space: Option<TextSpace>,
+    #[xml(text)]
+    pub text: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for DeletedInstrText<'a> {
+    fn from(text: T) -> Self {
+        DeletedInstrText {
+            space: Some(TextSpace::Preserve),
+            text: text.into(),
+        }
+    }
+}
+
+__xml_test_suites!(
+    DeletedInstrText,
+    DeletedInstrText::default(),
+    "<w:delInstrText></w:delInstrText>",
+    DeletedInstrText::from(" PAGE "),
+    r#"<w:delInstrText xml:space="preserve"> PAGE </w:delInstrText>"#,
+);
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, document::ParagraphContent, DateTime};
+
+/// Deleted Run Content
+///
+/// Specifies the content deleted as a tracked change. Text of deleted
+/// runs is stored as `DeletedText`.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let del = Deletion::new(1, "Reviewer").push(Run::default().push(DeletedText::from("text")));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:del")]
+pub struct Deletion<'a> {
+    /// Specifies the id of the revision.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+    /// Specifies the author of the revision.
+    #[xml(attr = "w:author")]
+    pub author: Cow<'a, str>,
+    /// Specifies when the revision was made.
+    #[xml(attr = "w:date")]
+    pub date: Option<DateTime>,
+    /// Specifies the deleted content.
+    #[xml(
+        child = "w:r",
+        child = "w:hyperlink",
+        child = "w:bookmarkStart",
+        child = "w:bookmarkEnd",
+        child = "w:permStart",
+        child = "w:permEnd",
+        child = "w:sdt",
+        child = "w:commentRangeStart",
+        child = "w:commentRangeEnd"
+    )]
+    pub content: Vec<ParagraphContent<'a>>,
+}
+
+impl<'a> Deletion<'a> {
+    __setter!(date: Option<DateTime>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(id: usize, author: T) -> Self {
+        Deletion {
+            id,
+            author: author.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline(always)]
+    pub fn push<T: Into<ParagraphContent<'a>>>(mut self, content: T) -> Self {
+        self.content.push(content.into());
+        self
+    }
+}
+
+#[cfg(test)]
+use crate::document::{DeletedText, Run};
+
+__xml_test_suites!(
+    Deletion,
+    Deletion::new(0, "foo"),
+    r#"<w:del w:id="0" w:author="foo"/>"#,
+    Deletion::new(1, "bar")
+        .date("2020-04-01T00:00:00Z".parse::<DateTime>().unwrap())
+        .push(Run::default().push(DeletedText::from("text"))),
+    r#"<w:del w:id="1" w:author="bar" w:date="2020-04-01T00:00:00Z"><w:r><w:rPr/><w:delText>text</w:delText></w:r></w:del>"#,
+);
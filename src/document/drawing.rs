@@ -0,0 +1,280 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    document::RevisionView,
+    drawing::{Anchor, ChartReference, DocProperty, Graphic, Inline, Picture, Shape},
+};
+
+/// Drawing
+///
+/// Contains a picture or other graphic object, placed in a run either
+/// inline with the text or anchored. `Docx::add_image` adds the image to
+/// the package and returns an inline drawing of it.
+///
+/// ```rust
+/// use docx::document::*;
+/// use docx::drawing::*;
+///
+/// let drawing = Drawing::inline_picture("rId5", 914400, 457200)
+///     .description("A red square")
+///     .title("Logo");
+/// let para = Paragraph::default().push(Run::default().push(drawing));
+///
+/// // a logo floating at the top right corner of the page
+/// let logo = Drawing::inline_picture("rId6", 914400, 457200).anchor(
+///     Anchor::default()
+///         .position_h(HorizontalPosition::align(
+///             HorizontalRelativeFrom::Margin,
+///             HorizontalAlign::Right,
+///         ))
+///         .position_v(VerticalPosition::align(
+///             VerticalRelativeFrom::TopMargin,
+///             VerticalAlign::Center,
+///         ))
+///         .wrap(WrapTopAndBottom::default()),
+/// );
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:drawing")]
+pub struct Drawing<'a> {
+    /// Specifies the drawing placed inline with the text.
+    #[xml(child = "wp:inline")]
+    pub inline: Option<Inline<'a>>,
+    /// Specifies the drawing anchored to the paragraph.
+    #[xml(child = "wp:anchor")]
+    pub anchor: Option<Anchor<'a>>,
+}
+
+impl<'a> Drawing<'a> {
+    /// Creates an inline picture of the image with the given relationship
+    /// id, in the size in EMUs
+    ///
+    /// The id of the drawing is allocated when the document is written.
+    pub fn inline_picture<T: Into<Cow<'a, str>>>(embed: T, width: u64, height: u64) -> Self {
+        Drawing {
+            inline: Some(Inline::new(
+                (width, height),
+                DocProperty::new(0, "Picture"),
+                Picture::new(embed, width, height),
+            )),
+            anchor: None,
+        }
+    }
+
+    /// Creates an inline drawing of the shape, in the size of the shape
+    ///
+    /// The id of the drawing is allocated when the document is written.
+    pub fn inline_shape(shape: Shape<'a>) -> Self {
+        let (width, height) = shape
+            .property
+            .transform
+            .as_ref()
+            .and_then(|transform| transform.extent.as_ref())
+            .map(|extent| (extent.width, extent.height))
+            .unwrap_or_default();
+        let name = if shape.text_box.is_some() {
+            "Text Box"
+        } else {
+            "Shape"
+        };
+        Drawing {
+            inline: Some(Inline::new(
+                (width, height),
+                DocProperty::new(0, name),
+                shape,
+            )),
+            anchor: None,
+        }
+    }
+
+    /// Creates an inline chart of the chart part with the given relationship
+    /// id, in the size in EMUs
+    ///
+    /// `Docx::add_chart` adds the chart part and returns such a drawing.
+    pub fn inline_chart<T: Into<Cow<'a, str>>>(id: T, width: u64, height: u64) -> Self {
+        Drawing {
+            inline: Some(Inline::new(
+                (width, height),
+                DocProperty::new(0, "Chart"),
+                ChartReference::from(id),
+            )),
+            anchor: None,
+        }
+    }
+
+    /// Turns the drawing into an anchored one, positioned as `anchor`
+    ///
+    /// The size, the properties and the graphic object of an inline
+    /// drawing are moved into `anchor`.
+    pub fn anchor(mut self, mut anchor: Anchor<'a>) -> Self {
+        if let Some(inline) = self.inline.take() {
+            anchor.extent = inline.extent;
+            anchor.effect_extent = inline.effect_extent;
+            anchor.doc_property = inline.doc_property;
+            anchor.graphic = inline.graphic;
+        }
+        self.anchor = Some(anchor);
+        self
+    }
+
+    /// Specifies the alternative text
+    pub fn description<T: Into<Cow<'a, str>>>(mut self, description: T) -> Self {
+        let description = description.into();
+        if let Some(picture) = self.picture_mut() {
+            picture.non_visual.property.description = Some(description.clone());
+        }
+        if let Some(doc_property) = self.doc_property_mut() {
+            doc_property.description = Some(description);
+        }
+        self
+    }
+
+    /// Specifies the title
+    pub fn title<T: Into<Cow<'a, str>>>(mut self, title: T) -> Self {
+        let title = title.into();
+        if let Some(picture) = self.picture_mut() {
+            picture.non_visual.property.title = Some(title.clone());
+        }
+        if let Some(doc_property) = self.doc_property_mut() {
+            doc_property.title = Some(title);
+        }
+        self
+    }
+
+    /// Returns the id, the name and the alternative text of the drawing
+    pub fn doc_property(&self) -> Option<&DocProperty<'a>> {
+        match (&self.inline, &self.anchor) {
+            (Some(inline), _) => Some(&inline.doc_property),
+            (None, Some(anchor)) => Some(&anchor.doc_property),
+            (None, None) => None,
+        }
+    }
+
+    pub fn doc_property_mut(&mut self) -> Option<&mut DocProperty<'a>> {
+        match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => Some(&mut inline.doc_property),
+            (None, Some(anchor)) => Some(&mut anchor.doc_property),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the graphic object of the drawing
+    pub fn graphic(&self) -> Option<&Graphic<'a>> {
+        match (&self.inline, &self.anchor) {
+            (Some(inline), _) => Some(&inline.graphic),
+            (None, Some(anchor)) => Some(&anchor.graphic),
+            (None, None) => None,
+        }
+    }
+
+    pub fn graphic_mut(&mut self) -> Option<&mut Graphic<'a>> {
+        match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => Some(&mut inline.graphic),
+            (None, Some(anchor)) => Some(&mut anchor.graphic),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the picture of the drawing
+    pub fn picture(&self) -> Option<&Picture<'a>> {
+        self.graphic()
+            .and_then(|graphic| graphic.data.picture.as_ref())
+    }
+
+    pub fn picture_mut(&mut self) -> Option<&mut Picture<'a>> {
+        self.graphic_mut()
+            .and_then(|graphic| graphic.data.picture.as_mut())
+    }
+
+    /// Returns the shape of the drawing
+    pub fn shape(&self) -> Option<&Shape<'a>> {
+        self.graphic()
+            .and_then(|graphic| graphic.data.shape.as_ref())
+    }
+
+    pub fn shape_mut(&mut self) -> Option<&mut Shape<'a>> {
+        self.graphic_mut()
+            .and_then(|graphic| graphic.data.shape.as_mut())
+    }
+
+    /// Returns the chart reference of the drawing
+    /// Returns if the graphic data of the drawing is modeled
+    pub(crate) fn is_known(&self) -> bool {
+        matches!(self.graphic(), Some(graphic) if graphic.data.is_known())
+    }
+
+    pub fn chart(&self) -> Option<&ChartReference<'a>> {
+        self.graphic()
+            .and_then(|graphic| graphic.data.chart.as_ref())
+    }
+
+    /// Iterates over the text in the text box of the shape, as it is when
+    /// tracked changes are accepted or rejected
+    pub fn iter_text_in(&self, view: RevisionView) -> Box<dyn Iterator<Item = &Cow<'a, str>> + '_> {
+        match self.shape().and_then(Shape::text_box_content) {
+            Some(content) => content.iter_text_in(view),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Iterates over the text in the text box of the shape
+    pub fn iter_text_mut(&mut self) -> Box<dyn Iterator<Item = &mut Cow<'a, str>> + '_> {
+        match self.shape_mut().and_then(Shape::text_box_content_mut) {
+            Some(content) => content.iter_text_mut(),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Returns the size of the drawing in EMUs
+    pub fn extent(&self) -> Option<(u64, u64)> {
+        let extent = match (&self.inline, &self.anchor) {
+            (Some(inline), _) => &inline.extent,
+            (None, Some(anchor)) => &anchor.extent,
+            (None, None) => return None,
+        };
+        Some((extent.width, extent.height))
+    }
+}
+
+__xml_test_suites!(
+    Drawing,
+    Drawing::default(),
+    r#"<w:drawing/>"#,
+    Drawing::inline_picture("rId1", 1, 2).description("foo"),
+    r#"<w:drawing><wp:inline><wp:extent cx="1" cy="2"/><wp:docPr id="0" name="Picture" descr="foo"/><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/picture"><pic:pic><pic:nvPicPr><pic:cNvPr id="0" name="" descr="foo"/><pic:cNvPicPr/></pic:nvPicPr><pic:blipFill><a:blip r:embed="rId1"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill><pic:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="1" cy="2"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></pic:spPr></pic:pic></a:graphicData></a:graphic></wp:inline></w:drawing>"#,
+);
+
+#[test]
+fn anchored_drawing() -> strong_xml::XmlResult<()> {
+    use crate::drawing::*;
+
+    let drawing = Drawing::inline_picture("rId1", 1, 2)
+        .description("foo")
+        .anchor(Anchor::default().wrap(WrapNone::default()).behind_doc(true));
+
+    assert!(drawing.inline.is_none());
+    assert_eq!(drawing.extent(), Some((1, 2)));
+    assert_eq!(drawing.picture().unwrap().embed(), Some("rId1"));
+    assert_eq!(
+        drawing.doc_property().unwrap().description.as_deref(),
+        Some("foo")
+    );
+
+    // as written by Word
+    let xml = r#"<w:drawing><wp:anchor distT="0" distB="0" distL="114300" distR="114300" simplePos="0" relativeHeight="251659264" behindDoc="1" locked="0" layoutInCell="1" allowOverlap="1"><wp:simplePos x="0" y="0"/><wp:positionH relativeFrom="margin"><wp:align>right</wp:align></wp:positionH><wp:positionV relativeFrom="page"><wp:posOffset>457200</wp:posOffset></wp:positionV><wp:extent cx="1" cy="2"/><wp:effectExtent l="0" t="0" r="0" b="0"/><wp:wrapNone/><wp:docPr id="3" name="Picture 3"/><wp:cNvGraphicFramePr/><a:graphic><a:graphicData uri=""/></a:graphic></wp:anchor></w:drawing>"#;
+    let drawing = Drawing::from_str(xml)?;
+    let anchor = drawing.anchor.as_ref().unwrap();
+
+    assert!(anchor.behind_doc);
+    assert_eq!(anchor.relative_height, 251659264);
+    assert_eq!(anchor.position_h.align, Some(HorizontalAlign::Right));
+    assert_eq!(anchor.position_v.relative_from, VerticalRelativeFrom::Page);
+    assert_eq!(anchor.position_v.offset, Some(457200));
+    assert_eq!(anchor.wrap, Wrap::None(WrapNone {}));
+    assert_eq!(drawing.doc_property().unwrap().id, 3);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Endnote Reference
+///
+/// Refers to an endnote by its id, which is returned by `Docx::add_endnote`.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let endnote = EndnoteReference::from(1);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:endnoteReference")]
+pub struct EndnoteReference {
+    #[xml(attr = "w:id")]
+    pub id: isize,
+}
+
+impl From<isize> for EndnoteReference {
+    fn from(id: isize) -> Self {
+        EndnoteReference { id }
+    }
+}
+
+/// Endnote Reference Mark
+///
+/// Displays the number of the endnote which contains it.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:endnoteRef")]
+pub struct EndnoteRef {}
+
+__xml_test_suites!(
+    EndnoteReference,
+    EndnoteReference::from(1),
+    r#"<w:endnoteReference w:id="1"/>"#,
+);
@@ -0,0 +1,433 @@
+use std::borrow::Cow;
+
+use crate::{
+    __setter,
+    document::{
+        DeletedInstrText, FieldChar, FieldCharType, InstrText, ParagraphContent, Run, RunContent,
+        SimpleField, Text, TextSpace,
+    },
+};
+
+/// Field
+///
+/// A field as a whole, e.g. a page number or a table of contents, which is
+/// an instruction evaluated by Word along with its last result.
+///
+/// In a paragraph, a field is either a simple field (`w:fldSimple`), or a
+/// complex field spanning several runs: a `w:fldChar` beginning it, the
+/// instruction in `w:instrText`, a `w:fldChar` separating the result and a
+/// `w:fldChar` ending it. `Paragraph::fields` collects both kinds, while
+/// `Paragraph::push_field` appends a complex field and `into_simple`
+/// converts it to a simple field.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let para = Paragraph::default()
+///     .push_text("Page ")
+///     .push_field(Field::page().push(Run::default().push_text("1")))
+///     .push_text(" of ")
+///     .push(Field::num_pages().into_simple());
+///
+/// let fields = para.fields();
+/// assert_eq!(fields[0].instruction, "PAGE");
+/// assert_eq!(fields[0].iter_text().collect::<Vec<_>>(), ["1"]);
+/// assert_eq!(fields[1].field_type(), Some("NUMPAGES"));
+///
+/// let toc = Field::toc(1, 3);
+/// assert_eq!(toc.instruction, r#"TOC \o "1-3" \h \z \u"#);
+///
+/// let date = Field::date().date_format("d MMMM yyyy");
+/// assert_eq!(date.instruction, r#"DATE \@ "d MMMM yyyy""#);
+/// ```
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Field<'a> {
+    /// Specifies the instruction, e.g. `PAGE \* Arabic`.
+    pub instruction: Cow<'a, str>,
+    /// Specifies the runs of the last result.
+    pub result: Vec<Run<'a>>,
+    /// Specifies that the result is outdated and should be updated when the
+    /// document is opened.
+    pub dirty: bool,
+}
+
+impl<'a> Field<'a> {
+    __setter!(dirty: bool);
+
+    pub fn new<T: Into<Cow<'a, str>>>(instruction: T) -> Self {
+        Field {
+            instruction: instruction.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a field of the current page number
+    pub fn page() -> Self {
+        Field::new("PAGE")
+    }
+
+    /// Creates a field of the number of pages in the document
+    pub fn num_pages() -> Self {
+        Field::new("NUMPAGES")
+    }
+
+    /// Creates a field of the current date, formatted with `date_format`
+    pub fn date() -> Self {
+        Field::new("DATE")
+    }
+
+    /// Creates a field of the content of the bookmark, e.g. a
+    /// cross-reference to a heading
+    pub fn reference(bookmark: &str) -> Self {
+        Field::new(format!("REF {}", bookmark))
+    }
+
+    /// Creates a field numbering the items with the identifier, e.g.
+    /// `Figure` for captions of figures
+    pub fn seq(identifier: &str) -> Self {
+        Field::new(format!("SEQ {}", quote(identifier)))
+    }
+
+    /// Creates a field jumping to the url when clicked
+    pub fn hyperlink(url: &str) -> Self {
+        Field::new(format!("HYPERLINK {}", quote_always(url)))
+    }
+
+    /// Creates a table of contents of the headings with outline levels
+    /// `from` to `to`, with the entries linked to the headings
+    ///
+    /// The field is dirty, so that Word builds the table when the document
+    /// is opened.
+    pub fn toc(from: u8, to: u8) -> Self {
+        Field::new(format!(r#"TOC \o "{}-{}" \h \z \u"#, from, to)).dirty(true)
+    }
+
+    /// Creates a field of the merge field, with the name of the merge field
+    /// as the result until the data is merged
+    pub fn merge_field(name: &str) -> Self {
+        Field::new(format!(r#"MERGEFIELD {} \* MERGEFORMAT"#, quote(name)))
+            .push(Run::default().push_text(format!("«{}»", name)))
+    }
+
+    /// Appends a switch without argument, e.g. `h` for `\h`
+    pub fn switch(mut self, switch: &str) -> Self {
+        let instruction = self.instruction.to_mut();
+        instruction.push_str(" \\");
+        instruction.push_str(switch);
+        self
+    }
+
+    /// Appends a switch with an argument, e.g. `@` and `yyyy` for
+    /// `\@ "yyyy"`
+    pub fn switch_with(mut self, switch: &str, argument: &str) -> Self {
+        let instruction = self.instruction.to_mut();
+        instruction.push_str(" \\");
+        instruction.push_str(switch);
+        instruction.push(' ');
+        instruction.push_str(&quote_always(argument));
+        self
+    }
+
+    /// Appends a date format switch, e.g. `d MMMM yyyy`
+    pub fn date_format(self, format: &str) -> Self {
+        self.switch_with("@", format)
+    }
+
+    /// Appends a numeric format switch, e.g. `#,##0.00`
+    pub fn number_format(self, format: &str) -> Self {
+        self.switch_with("#", format)
+    }
+
+    /// Appends a general format switch, e.g. `MERGEFORMAT` or `ROMAN`
+    pub fn general_format(mut self, format: &str) -> Self {
+        let instruction = self.instruction.to_mut();
+        instruction.push_str(" \\* ");
+        instruction.push_str(format);
+        self
+    }
+
+    /// Appends a run to the result
+    #[inline(always)]
+    pub fn push(mut self, run: Run<'a>) -> Self {
+        self.result.push(run);
+        self
+    }
+
+    /// Returns the type of the field, i.e. the first word of the
+    /// instruction
+    pub fn field_type(&self) -> Option<&str> {
+        self.instruction.split_whitespace().next()
+    }
+
+    /// Iterates over the text of the result
+    pub fn iter_text(&self) -> impl Iterator<Item = &Cow<'a, str>> {
+        self.result.iter().flat_map(Run::iter_text)
+    }
+
+    /// Converts the field into the runs of a complex field
+    pub fn into_runs(self) -> Vec<Run<'a>> {
+        let mut begin = FieldChar::from(FieldCharType::Begin);
+        if self.dirty {
+            begin.dirty = Some(true);
+        }
+
+        let mut runs = vec![
+            Run::default().push(begin),
+            Run::default().push(InstrText::from(format!(" {} ", self.instruction))),
+        ];
+        if !self.result.is_empty() {
+            runs.push(Run::default().push(FieldChar::from(FieldCharType::Separate)));
+            runs.extend(self.result);
+        }
+        runs.push(Run::default().push(FieldChar::from(FieldCharType::End)));
+
+        runs
+    }
+
+    /// Converts the field into a simple field
+    pub fn into_simple(self) -> SimpleField<'a> {
+        SimpleField {
+            instruction: self.instruction,
+            dirty: if self.dirty { Some(true) } else { None },
+            content: self.result,
+        }
+    }
+}
+
+/// Quotes the argument of an instruction if it contains whitespace
+fn quote(argument: &str) -> Cow<'_, str> {
+    if argument.contains(char::is_whitespace) || argument.contains('"') {
+        quote_always(argument).into()
+    } else {
+        argument.into()
+    }
+}
+
+fn quote_always(argument: &str) -> String {
+    format!(
+        "\"{}\"",
+        argument.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Collects the simple and complex fields in the paragraph content, in the
+/// order they begin
+///
+/// The result runs keep the text and the properties of the runs. Runs in
+/// the result of nested fields are in the result of the outer fields too.
+pub(crate) fn collect_fields<'b, 'a: 'b>(content: &'b [ParagraphContent<'a>]) -> Vec<Field<'b>> {
+    let mut collector = Collector::default();
+    collector.paragraph(content);
+
+    for field in &mut collector.fields {
+        field.instruction = match std::mem::take(&mut field.instruction) {
+            Cow::Borrowed(instruction) => Cow::Borrowed(instruction.trim()),
+            Cow::Owned(instruction) => Cow::Owned(instruction.trim().to_owned()),
+        };
+    }
+
+    collector.fields
+}
+
+#[derive(Default)]
+struct Collector<'b> {
+    fields: Vec<Field<'b>>,
+    /// The indices of the unfinished fields, and whether they're in the
+    /// result
+    open: Vec<(usize, bool)>,
+}
+
+impl<'b> Collector<'b> {
+    fn paragraph<'a: 'b>(&mut self, content: &'b [ParagraphContent<'a>]) {
+        for content in content {
+            match content {
+                ParagraphContent::Run(run) => self.run(run),
+                ParagraphContent::Link(link) => self.run(&link.content),
+                ParagraphContent::Sdt(sdt) => self.paragraph(&sdt.content.content),
+                ParagraphContent::Insertion(ins) => self.paragraph(&ins.content),
+                ParagraphContent::Deletion(del) => self.paragraph(&del.content),
+                ParagraphContent::MoveFrom(from) => self.paragraph(&from.content),
+                ParagraphContent::MoveTo(to) => self.paragraph(&to.content),
+                ParagraphContent::SimpleField(field) => {
+                    self.begin(field.dirty == Some(true));
+                    self.fields.last_mut().unwrap().instruction = Cow::Borrowed(&field.instruction);
+                    self.open.last_mut().unwrap().1 = true;
+                    for run in &field.content {
+                        self.run(run);
+                    }
+                    self.open.pop();
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn run<'a: 'b>(&mut self, run: &'b Run<'a>) {
+        let mut text = Vec::new();
+
+        for content in &run.content {
+            match content {
+                RunContent::FieldChar(field_char) => {
+                    self.push_result(run, &mut text);
+                    match field_char.ty {
+                        FieldCharType::Begin => self.begin(field_char.dirty == Some(true)),
+                        FieldCharType::Separate => {
+                            if let Some(open) = self.open.last_mut() {
+                                open.1 = true;
+                            }
+                        }
+                        FieldCharType::End => {
+                            self.open.pop();
+                        }
+                    }
+                }
+                RunContent::InstrText(InstrText { text: instr, .. })
+                | RunContent::DeletedInstrText(DeletedInstrText { text: instr, .. }) => {
+                    if let Some(&(index, false)) = self.open.last() {
+                        let instruction = &mut self.fields[index].instruction;
+                        if instruction.is_empty() {
+                            *instruction = Cow::Borrowed(instr);
+                        } else {
+                            instruction.to_mut().push_str(instr);
+                        }
+                    }
+                }
+                RunContent::Text(t) => text.push(&*t.text),
+                _ => (),
+            }
+        }
+
+        self.push_result(run, &mut text);
+    }
+
+    fn begin(&mut self, dirty: bool) {
+        self.open.push((self.fields.len(), false));
+        self.fields.push(Field::default().dirty(dirty));
+    }
+
+    /// Appends a copy of the run with the text to the fields in the result
+    fn push_result<'a: 'b>(&mut self, run: &'b Run<'a>, text: &mut Vec<&'b str>) {
+        if text.is_empty() {
+            return;
+        }
+
+        for &(index, in_result) in &self.open {
+            if in_result {
+                self.fields[index].result.push(Run {
+                    property: run.property.clone(),
+                    content: text
+                        .iter()
+                        .map(|text| {
+                            RunContent::Text(Text {
+                                space: Some(TextSpace::Preserve),
+                                text: Cow::Borrowed(*text),
+                            })
+                        })
+                        .collect(),
+                });
+            }
+        }
+
+        text.clear();
+    }
+}
+
+#[test]
+fn complex_fields() -> strong_xml::XmlResult<()> {
+    use crate::document::Paragraph;
+    use strong_xml::XmlRead;
+
+    let para = Paragraph::from_str(
+        r#"<w:p><w:r><w:t xml:space="preserve">See </w:t></w:r><w:r><w:fldChar w:fldCharType="begin"/></w:r><w:r><w:instrText xml:space="preserve"> REF _Ref1 </w:instrText></w:r><w:r><w:instrText xml:space="preserve">\h </w:instrText></w:r><w:r><w:fldChar w:fldCharType="separate"/></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>Figure </w:t></w:r><w:fldSimple w:instr=" SEQ Figure "><w:r><w:t>2</w:t></w:r></w:fldSimple><w:r><w:fldChar w:fldCharType="end"/></w:r><w:r><w:t>.</w:t></w:r></w:p>"#,
+    )?;
+
+    let fields = para.fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].instruction, r#"REF _Ref1 \h"#);
+    assert_eq!(fields[0].field_type(), Some("REF"));
+    assert_eq!(fields[0].iter_text().collect::<Vec<_>>(), ["Figure ", "2"]);
+    assert!(fields[0].result[0].property.bold.is_some());
+    assert_eq!(fields[1].instruction, "SEQ Figure");
+    assert_eq!(fields[1].iter_text().collect::<Vec<_>>(), ["2"]);
+
+    assert_eq!(
+        para.iter_text().collect::<Vec<_>>(),
+        ["See ", "Figure ", "2", "."]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn field_runs() -> strong_xml::XmlResult<()> {
+    use crate::document::Paragraph;
+    use strong_xml::XmlWrite;
+
+    let para = Paragraph::default().push_field(
+        Field::page()
+            .general_format("ROMAN")
+            .dirty(true)
+            .push(Run::default().push_text("iv")),
+    );
+    assert_eq!(
+        para.to_string()?,
+        r#"<w:p><w:pPr/><w:r><w:rPr/><w:fldChar w:fldCharType="begin" w:dirty="true"/></w:r><w:r><w:rPr/><w:instrText xml:space="preserve"> PAGE \* ROMAN </w:instrText></w:r><w:r><w:rPr/><w:fldChar w:fldCharType="separate"/></w:r><w:r><w:rPr/><w:t>iv</w:t></w:r><w:r><w:rPr/><w:fldChar w:fldCharType="end"/></w:r></w:p>"#
+    );
+
+    let fields = para.fields();
+    assert_eq!(fields[0].instruction, r#"PAGE \* ROMAN"#);
+    assert!(fields[0].dirty);
+
+    assert_eq!(
+        Field::hyperlink("https://example.com/a b").instruction,
+        r#"HYPERLINK "https://example.com/a b""#
+    );
+    assert_eq!(
+        Field::merge_field("First Name").instruction,
+        r#"MERGEFIELD "First Name" \* MERGEFORMAT"#
+    );
+    assert_eq!(
+        Field::seq("Table").switch("h").instruction,
+        r#"SEQ Table \h"#
+    );
+    assert_eq!(
+        Field::reference("_Ref2")
+            .switch("h")
+            .into_simple()
+            .to_string()?,
+        r#"<w:fldSimple w:instr="REF _Ref2 \h"/>"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deleted_fields() -> strong_xml::XmlResult<()> {
+    use crate::document::{resolve_revisions, BodyContent, Paragraph, TrackChanges};
+    use strong_xml::XmlWrite;
+
+    let mut changes = TrackChanges::new("foo");
+    changes.date = None;
+
+    let mut para = Paragraph::default().push_field(Field::page());
+    let len = para.content.len();
+    para.delete_tracked(0..len, &mut changes);
+
+    let xml = para.to_string()?;
+    assert!(xml.contains(r#"<w:delInstrText xml:space="preserve"> PAGE </w:delInstrText>"#));
+    assert!(!xml.contains("<w:instrText"));
+    assert_eq!(para.fields()[0].instruction, "PAGE");
+
+    let mut content = vec![BodyContent::Paragraph(para)];
+    resolve_revisions(&mut content, false, None);
+    match &content[0] {
+        BodyContent::Paragraph(para) => {
+            assert!(para.to_string()?.contains("<w:instrText"));
+            assert_eq!(para.fields()[0].instruction, "PAGE");
+        }
+        _ => panic!("the paragraph is kept"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __string_enum, __xml_test_suites,
+    document::{FormFieldData, TextSpace},
+};
+
+/// Complex Field Character
+///
+/// Marks the beginning of a complex field, the separator between its
+/// instruction and its result, or its end. `Field` deals with the field as
+/// a whole.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let begin = FieldChar::from(FieldCharType::Begin).dirty(true);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:fldChar")]
+pub struct FieldChar<'a> {
+    #[xml(attr = "w:fldCharType")]
+    pub ty: FieldCharType,
+    /// Specifies that the result is outdated and should be updated when the
+    /// document is opened.
+    #[xml(attr = "w:dirty")]
+    pub dirty: Option<bool>,
+    /// Specifies that the result shouldn't be updated.
+    #[xml(attr = "w:fldLock")]
+    pub lock: Option<bool>,
+    /// Specifies the properties of a legacy form field.
+    #[xml(child = "w:ffData")]
+    pub form_field_data: Option<FormFieldData<'a>>,
+    /// Specifies the custom data of the field.
+    #[xml(child = "w:fldData")]
+    pub data: Option<FieldData<'a>>,
+}
+
+impl<'a> FieldChar<'a> {
+    __setter!(dirty: Option<bool>);
+    __setter!(lock: Option<bool>);
+    __setter!(form_field_data: Option<FormFieldData<'a>>);
+    __setter!(data: Option<FieldData<'a>>);
+}
+
+impl From<FieldCharType> for FieldChar<'_> {
+    fn from(ty: FieldCharType) -> Self {
+        FieldChar {
+            ty,
+            dirty: None,
+            lock: None,
+            form_field_data: None,
+            data: None,
+        }
+    }
+}
+
+/// Custom Field Data
+///
+/// The data of a field, encoded in base64, which is kept as it is.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:fldData")]
+pub struct FieldData<'a> {
+    #[xml(attr = "xml:space")]
+    pub space: Option<TextSpace>,
+    #[xml(text)]
+    pub text: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for FieldData<'a> {
+    fn from(text: T) -> Self {
+        FieldData {
+            space: None,
+            text: text.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldCharType {
+    Begin,
+    Separate,
+    End,
+}
+
+__string_enum! {
+    FieldCharType {
+        Begin = "begin",
+        Separate = "separate",
+        End = "end",
+    }
+}
+
+__xml_test_suites!(
+    FieldChar,
+    FieldChar::from(FieldCharType::Separate),
+    r#"<w:fldChar w:fldCharType="separate"/>"#,
+    FieldChar::from(FieldCharType::Begin).dirty(true),
+    r#"<w:fldChar w:fldCharType="begin" w:dirty="true"/>"#,
+    FieldChar::from(FieldCharType::Begin)
+        .lock(true)
+        .form_field_data(FormFieldData::default().name("Text1"))
+        .data("AAAA"),
+    r#"<w:fldChar w:fldCharType="begin" w:fldLock="true"><w:ffData><w:name w:val="Text1"/></w:ffData><w:fldData>AAAA</w:fldData></w:fldChar>"#,
+);
@@ -0,0 +1,40 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Footnote Reference
+///
+/// Refers to a footnote by its id, which is returned by `Docx::add_footnote`.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let footnote = FootnoteReference::from(1);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footnoteReference")]
+pub struct FootnoteReference {
+    #[xml(attr = "w:id")]
+    pub id: isize,
+}
+
+impl From<isize> for FootnoteReference {
+    fn from(id: isize) -> Self {
+        FootnoteReference { id }
+    }
+}
+
+/// Footnote Reference Mark
+///
+/// Displays the number of the footnote which contains it.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footnoteRef")]
+pub struct FootnoteRef {}
+
+__xml_test_suites!(
+    FootnoteReference,
+    FootnoteReference::from(1),
+    r#"<w:footnoteReference w:id="1"/>"#,
+);
@@ -0,0 +1,543 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Form Field Properties
+///
+/// The properties of a legacy form field, stored in the character which
+/// begins the field.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let data = FormFieldData::default()
+///     .name("Agree")
+///     .enabled(true)
+///     .check_box(CheckBox::default().size_auto(true).default_value(false));
+///
+/// let data = FormFieldData::default().name("Color").drop_down_list(
+///     DropDownList::default()
+///         .push_entry("Red")
+///         .push_entry("Green")
+///         .result(1usize),
+/// );
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:ffData")]
+pub struct FormFieldData<'a> {
+    /// Specifies the name of the form field.
+    #[xml(child = "w:name")]
+    pub name: Option<FormFieldName<'a>>,
+    /// Specifies the label of the form field.
+    #[xml(child = "w:label")]
+    pub label: Option<FormFieldLabel>,
+    /// Specifies the tab order of the form field.
+    #[xml(child = "w:tabIndex")]
+    pub tab_index: Option<FormFieldTabIndex>,
+    /// Specifies whether the form field can be edited.
+    #[xml(child = "w:enabled")]
+    pub enabled: Option<FormFieldEnabled>,
+    /// Specifies that the fields are updated when the form field is exited.
+    #[xml(child = "w:calcOnExit")]
+    pub calc_on_exit: Option<CalcOnExit>,
+    /// Specifies the macro run when the form field is entered.
+    #[xml(child = "w:entryMacro")]
+    pub entry_macro: Option<EntryMacro<'a>>,
+    /// Specifies the macro run when the form field is exited.
+    #[xml(child = "w:exitMacro")]
+    pub exit_macro: Option<ExitMacro<'a>>,
+    /// Specifies the help text of the form field.
+    #[xml(child = "w:helpText")]
+    pub help_text: Option<HelpText<'a>>,
+    /// Specifies the text shown in the status bar.
+    #[xml(child = "w:statusText")]
+    pub status_text: Option<StatusText<'a>>,
+    /// Specifies that the form field is a check box.
+    #[xml(child = "w:checkBox")]
+    pub check_box: Option<CheckBox>,
+    /// Specifies that the form field is a drop-down list.
+    #[xml(child = "w:ddList")]
+    pub drop_down_list: Option<DropDownList<'a>>,
+    /// Specifies that the form field is a text box.
+    #[xml(child = "w:textInput")]
+    pub text_input: Option<TextInput<'a>>,
+}
+
+impl<'a> FormFieldData<'a> {
+    __setter!(name: Option<FormFieldName<'a>>);
+    __setter!(label: Option<FormFieldLabel>);
+    __setter!(tab_index: Option<FormFieldTabIndex>);
+    __setter!(enabled: Option<FormFieldEnabled>);
+    __setter!(calc_on_exit: Option<CalcOnExit>);
+    __setter!(entry_macro: Option<EntryMacro<'a>>);
+    __setter!(exit_macro: Option<ExitMacro<'a>>);
+    __setter!(help_text: Option<HelpText<'a>>);
+    __setter!(status_text: Option<StatusText<'a>>);
+    __setter!(check_box: Option<CheckBox>);
+    __setter!(drop_down_list: Option<DropDownList<'a>>);
+    __setter!(text_input: Option<TextInput<'a>>);
+}
+
+/// Name of a form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:name")]
+pub struct FormFieldName<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for FormFieldName<'a> {
+    fn from(val: T) -> Self {
+        FormFieldName { value: val.into() }
+    }
+}
+
+/// Label of a form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:label")]
+pub struct FormFieldLabel {
+    #[xml(attr = "w:val")]
+    pub value: i32,
+}
+
+impl<T: Into<i32>> From<T> for FormFieldLabel {
+    fn from(val: T) -> Self {
+        FormFieldLabel { value: val.into() }
+    }
+}
+
+/// Tab Order of a form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:tabIndex")]
+pub struct FormFieldTabIndex {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl<T: Into<usize>> From<T> for FormFieldTabIndex {
+    fn from(val: T) -> Self {
+        FormFieldTabIndex { value: val.into() }
+    }
+}
+
+/// Whether a form field can be edited
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:enabled")]
+pub struct FormFieldEnabled {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for FormFieldEnabled {
+    fn from(val: T) -> Self {
+        FormFieldEnabled { value: val.into() }
+    }
+}
+
+/// Whether the fields are updated when a form field is exited
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:calcOnExit")]
+pub struct CalcOnExit {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for CalcOnExit {
+    fn from(val: T) -> Self {
+        CalcOnExit { value: val.into() }
+    }
+}
+
+/// Macro run when a form field is entered
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:entryMacro")]
+pub struct EntryMacro<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for EntryMacro<'a> {
+    fn from(val: T) -> Self {
+        EntryMacro { value: val.into() }
+    }
+}
+
+/// Macro run when a form field is exited
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:exitMacro")]
+pub struct ExitMacro<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for ExitMacro<'a> {
+    fn from(val: T) -> Self {
+        ExitMacro { value: val.into() }
+    }
+}
+
+/// Help Text of a form field
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:helpText")]
+pub struct HelpText<'a> {
+    /// Specifies whether the value is a literal text or the name of an
+    /// AutoText entry.
+    #[xml(attr = "w:type")]
+    pub ty: Option<InfoTextType>,
+    #[xml(attr = "w:val")]
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for HelpText<'a> {
+    fn from(val: T) -> Self {
+        HelpText {
+            ty: None,
+            value: Some(val.into()),
+        }
+    }
+}
+
+/// Status Bar Text of a form field
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:statusText")]
+pub struct StatusText<'a> {
+    /// Specifies whether the value is a literal text or the name of an
+    /// AutoText entry.
+    #[xml(attr = "w:type")]
+    pub ty: Option<InfoTextType>,
+    #[xml(attr = "w:val")]
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for StatusText<'a> {
+    fn from(val: T) -> Self {
+        StatusText {
+            ty: None,
+            value: Some(val.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum InfoTextType {
+    Text,
+    AutoText,
+}
+
+__string_enum! {
+    InfoTextType {
+        Text = "text",
+        AutoText = "autoText",
+    }
+}
+
+/// Check Box Form Field
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:checkBox")]
+pub struct CheckBox {
+    /// Specifies the size of the check box, in half-points.
+    #[xml(child = "w:size")]
+    pub size: Option<CheckBoxSize>,
+    /// Specifies that the check box is sized like the text.
+    #[xml(child = "w:sizeAuto")]
+    pub size_auto: Option<CheckBoxSizeAuto>,
+    /// Specifies whether the check box is checked by default.
+    #[xml(child = "w:default")]
+    pub default_value: Option<CheckBoxDefault>,
+    /// Specifies whether the check box is checked.
+    #[xml(child = "w:checked")]
+    pub checked: Option<CheckBoxChecked>,
+}
+
+impl CheckBox {
+    __setter!(size: Option<CheckBoxSize>);
+    __setter!(size_auto: Option<CheckBoxSizeAuto>);
+    __setter!(default_value: Option<CheckBoxDefault>);
+    __setter!(checked: Option<CheckBoxChecked>);
+}
+
+/// Size of a check box, in half-points
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:size")]
+pub struct CheckBoxSize {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl<T: Into<usize>> From<T> for CheckBoxSize {
+    fn from(val: T) -> Self {
+        CheckBoxSize { value: val.into() }
+    }
+}
+
+/// Whether a check box is sized like the text
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sizeAuto")]
+pub struct CheckBoxSizeAuto {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for CheckBoxSizeAuto {
+    fn from(val: T) -> Self {
+        CheckBoxSizeAuto { value: val.into() }
+    }
+}
+
+/// Whether a check box is checked by default
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:default")]
+pub struct CheckBoxDefault {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for CheckBoxDefault {
+    fn from(val: T) -> Self {
+        CheckBoxDefault { value: val.into() }
+    }
+}
+
+/// Whether a check box is checked
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:checked")]
+pub struct CheckBoxChecked {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for CheckBoxChecked {
+    fn from(val: T) -> Self {
+        CheckBoxChecked { value: val.into() }
+    }
+}
+
+/// Drop-Down List Form Field
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:ddList")]
+pub struct DropDownList<'a> {
+    /// Specifies the index of the selected entry.
+    #[xml(child = "w:result")]
+    pub result: Option<DropDownListResult>,
+    /// Specifies the index of the entry selected by default.
+    #[xml(child = "w:default")]
+    pub default_value: Option<DropDownListDefault>,
+    /// Specifies the entries of the list.
+    #[xml(child = "w:listEntry")]
+    pub entries: Vec<ListEntry<'a>>,
+}
+
+impl<'a> DropDownList<'a> {
+    __setter!(result: Option<DropDownListResult>);
+    __setter!(default_value: Option<DropDownListDefault>);
+
+    /// Appends an entry to the list
+    #[inline(always)]
+    pub fn push_entry<T: Into<ListEntry<'a>>>(mut self, entry: T) -> Self {
+        self.entries.push(entry.into());
+        self
+    }
+}
+
+/// Index of the selected entry of a drop-down list
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:result")]
+pub struct DropDownListResult {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl<T: Into<usize>> From<T> for DropDownListResult {
+    fn from(val: T) -> Self {
+        DropDownListResult { value: val.into() }
+    }
+}
+
+/// Index of the entry of a drop-down list selected by default
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:default")]
+pub struct DropDownListDefault {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl<T: Into<usize>> From<T> for DropDownListDefault {
+    fn from(val: T) -> Self {
+        DropDownListDefault { value: val.into() }
+    }
+}
+
+/// Entry of a drop-down list
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:listEntry")]
+pub struct ListEntry<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for ListEntry<'a> {
+    fn from(val: T) -> Self {
+        ListEntry { value: val.into() }
+    }
+}
+
+/// Text Box Form Field
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:textInput")]
+pub struct TextInput<'a> {
+    /// Specifies the kind of the text.
+    #[xml(child = "w:type")]
+    pub ty: Option<TextInputType>,
+    /// Specifies the default text.
+    #[xml(child = "w:default")]
+    pub default_value: Option<TextInputDefault<'a>>,
+    /// Specifies the maximum length of the text.
+    #[xml(child = "w:maxLength")]
+    pub max_length: Option<TextInputMaxLength>,
+    /// Specifies the format of the text.
+    #[xml(child = "w:format")]
+    pub format: Option<TextInputFormat<'a>>,
+}
+
+impl<'a> TextInput<'a> {
+    __setter!(ty: Option<TextInputType>);
+    __setter!(default_value: Option<TextInputDefault<'a>>);
+    __setter!(max_length: Option<TextInputMaxLength>);
+    __setter!(format: Option<TextInputFormat<'a>>);
+}
+
+/// Kind of the text of a text box form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:type")]
+pub struct TextInputType {
+    #[xml(attr = "w:val")]
+    pub value: TextInputTypeVal,
+}
+
+impl From<TextInputTypeVal> for TextInputType {
+    fn from(value: TextInputTypeVal) -> Self {
+        TextInputType { value }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum TextInputTypeVal {
+    Regular,
+    Number,
+    Date,
+    CurrentTime,
+    CurrentDate,
+    Calculated,
+}
+
+__string_enum! {
+    TextInputTypeVal {
+        Regular = "regular",
+        Number = "number",
+        Date = "date",
+        CurrentTime = "currentTime",
+        CurrentDate = "currentDate",
+        Calculated = "calculated",
+    }
+}
+
+/// Default Text of a text box form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:default")]
+pub struct TextInputDefault<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for TextInputDefault<'a> {
+    fn from(val: T) -> Self {
+        TextInputDefault { value: val.into() }
+    }
+}
+
+/// Maximum Length of the text of a text box form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:maxLength")]
+pub struct TextInputMaxLength {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl<T: Into<usize>> From<T> for TextInputMaxLength {
+    fn from(val: T) -> Self {
+        TextInputMaxLength { value: val.into() }
+    }
+}
+
+/// Format of the text of a text box form field
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:format")]
+pub struct TextInputFormat<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for TextInputFormat<'a> {
+    fn from(val: T) -> Self {
+        TextInputFormat { value: val.into() }
+    }
+}
+
+__xml_test_suites!(
+    FormFieldData,
+    FormFieldData::default(),
+    r#"<w:ffData/>"#,
+    FormFieldData::default()
+        .name("Agree")
+        .enabled(FormFieldEnabled::default())
+        .calc_on_exit(false)
+        .status_text("Check to agree")
+        .check_box(
+            CheckBox::default()
+                .size_auto(CheckBoxSizeAuto::default())
+                .default_value(false)
+        ),
+    r#"<w:ffData><w:name w:val="Agree"/><w:enabled/><w:calcOnExit w:val="false"/><w:statusText w:val="Check to agree"/><w:checkBox><w:sizeAuto/><w:default w:val="false"/></w:checkBox></w:ffData>"#,
+    FormFieldData::default().name("Color").drop_down_list(
+        DropDownList::default()
+            .result(1usize)
+            .push_entry("Red")
+            .push_entry("Green")
+    ),
+    r#"<w:ffData><w:name w:val="Color"/><w:ddList><w:result w:val="1"/><w:listEntry w:val="Red"/><w:listEntry w:val="Green"/></w:ddList></w:ffData>"#,
+    FormFieldData::default().name("Due").text_input(
+        TextInput::default()
+            .ty(TextInputTypeVal::Date)
+            .default_value("1/1/2020")
+            .max_length(10usize)
+            .format("M/d/yyyy")
+    ),
+    r#"<w:ffData><w:name w:val="Due"/><w:textInput><w:type w:val="date"/><w:default w:val="1/1/2020"/><w:maxLength w:val="10"/><w:format w:val="M/d/yyyy"/></w:textInput></w:ffData>"#,
+);
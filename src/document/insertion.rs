@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, document::ParagraphContent, DateTime};
+
+/// Inserted Run Content
+///
+/// Specifies the content inserted as a tracked change.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let ins = Insertion::new(1, "Reviewer").push(Run::default().push_text("text"));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:ins")]
+pub struct Insertion<'a> {
+    /// Specifies the id of the revision.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+    /// Specifies the author of the revision.
+    #[xml(attr = "w:author")]
+    pub author: Cow<'a, str>,
+    /// Specifies when the revision was made.
+    #[xml(attr = "w:date")]
+    pub date: Option<DateTime>,
+    /// Specifies the inserted content.
+    #[xml(
+        child = "w:r",
+        child = "w:hyperlink",
+        child = "w:bookmarkStart",
+        child = "w:bookmarkEnd",
+        child = "w:permStart",
+        child = "w:permEnd",
+        child = "w:sdt",
+        child = "w:commentRangeStart",
+        child = "w:commentRangeEnd",
+        child = "w:del"
+    )]
+    pub content: Vec<ParagraphContent<'a>>,
+}
+
+impl<'a> Insertion<'a> {
+    __setter!(date: Option<DateTime>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(id: usize, author: T) -> Self {
+        Insertion {
+            id,
+            author: author.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline(always)]
+    pub fn push<T: Into<ParagraphContent<'a>>>(mut self, content: T) -> Self {
+        self.content.push(content.into());
+        self
+    }
+}
+
+#[cfg(test)]
+use crate::document::Run;
+
+__xml_test_suites!(
+    Insertion,
+    Insertion::new(0, "foo"),
+    r#"<w:ins w:id="0" w:author="foo"/>"#,
+    Insertion::new(1, "bar")
+        .date("2020-04-01T00:00:00Z".parse::<DateTime>().unwrap())
+        .push(Run::default().push_text("text")),
+    r#"<w:ins w:id="1" w:author="bar" w:date="2020-04-01T00:00:00Z"><w:r><w:rPr/><w:t>text</w:t></w:r></w:ins>"#,
+);
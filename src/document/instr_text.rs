@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__xml_test_suites, document::TextSpace};
+
+/// Field Code
+///
+/// The instruction of a complex field, which may be split across several
+/// runs.
+///
+/// ```rust
+/// use docx::document::{InstrText, TextSpace};
+///
+/// let text = InstrText::from(" PAGE ");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:instrText")]
+pub struct InstrText<'a> {
+    /// Specifies how to handle whitespace
+    #[xml(attr = "xml:space")]
+    pub space: Option<TextSpace>,
+    #[xml(text)]
+    pub text: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for InstrText<'a> {
+    fn from(text: T) -> Self {
+        InstrText {
+            space: Some(TextSpace::Preserve),
+            text: text.into(),
+        }
+    }
+}
+
+__xml_test_suites!(
+    InstrText,
+    InstrText::default(),
+    "<w:instrText></w:instrText>",
+    InstrText::from(" PAGE "),
+    r#"<w:instrText xml:space="preserve"> PAGE </w:instrText>"#,
+);
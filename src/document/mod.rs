@@ -2,30 +2,74 @@
 //!
 //! The corresponding ZIP item is `/word/document.xml`.
 
+mod alternate_content;
 mod body;
 mod bookmark_end;
 mod bookmark_start;
 mod r#break;
+mod comment_range_end;
+mod comment_range_start;
+mod comment_reference;
+mod data_binding;
+mod deleted_instr_text;
+mod deleted_text;
+mod deletion;
+mod drawing;
+mod endnote_reference;
+mod field;
+mod field_char;
+mod footnote_reference;
+mod form_field_data;
 mod grid_column;
 mod hyperlink;
+mod insertion;
+mod instr_text;
+mod move_from;
+mod move_from_range;
+mod move_to;
+mod move_to_range;
 mod paragraph;
+mod permission_end;
+mod permission_start;
+mod pict;
+mod revision;
 mod run;
+mod sdt_block;
+mod sdt_property;
+mod sdt_run;
+mod separator;
+mod simple_field;
 mod table;
 mod table_cell;
 mod table_grid;
 mod table_row;
 mod text;
+mod text_box;
+mod track_changes;
 
 pub use self::{
-    body::*, bookmark_end::*, bookmark_start::*, grid_column::*, hyperlink::*, paragraph::*,
-    r#break::*, run::*, table::*, table::*, table_cell::*, table_grid::*, table_row::*, text::*,
+    alternate_content::*, body::*, bookmark_end::*, bookmark_start::*, comment_range_end::*,
+    comment_range_start::*, comment_reference::*, data_binding::*, deleted_instr_text::*,
+    deleted_text::*, deletion::*, drawing::*, endnote_reference::*, field::*, field_char::*,
+    footnote_reference::*, form_field_data::*, grid_column::*, hyperlink::*, insertion::*,
+    instr_text::*, move_from::*, move_from_range::*, move_to::*, move_to_range::*, paragraph::*,
+    permission_end::*, permission_start::*, pict::*, r#break::*, revision::*, run::*, sdt_block::*,
+    sdt_property::*, sdt_run::*, separator::*, simple_field::*, table::*, table_cell::*,
+    table_grid::*, table_row::*, text::*, text_box::*, track_changes::*,
 };
 
 use std::io::Write;
+use std::ops::Range;
 use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 
+use self::sdt_run::text_of;
 use crate::__xml_test_suites;
-use crate::schema::SCHEMA_MAIN;
+use crate::formatting::{ParagraphProperty, SectionProperty};
+use crate::schema::{
+    SCHEMA_DRAWING_MAIN, SCHEMA_MAIN, SCHEMA_MARKUP_COMPATIBILITY,
+    SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS, SCHEMA_PICTURE, SCHEMA_VML,
+    SCHEMA_WORDPROCESSING_DRAWING, SCHEMA_WORDPROCESSING_SHAPE, SCHEMA_WORD_2010, SCHEMA_WORD_2012,
+};
 
 /// The root element of the main document part.
 #[derive(Debug, Default, XmlRead)]
@@ -42,6 +86,395 @@ impl<'a> Document<'a> {
         self.body.push(content);
         self
     }
+
+    /// Ends the current section and starts a new one with the given properties
+    ///
+    /// The section type of the new properties specifies how the section
+    /// starts, e.g. on the next page or continuously. The current section
+    /// ends at the last paragraph, or a new empty paragraph if the body
+    /// doesn't end with a paragraph.
+    ///
+    /// ```rust
+    /// use docx::document::*;
+    /// use docx::formatting::*;
+    ///
+    /// let mut doc = Document::default();
+    /// doc.push(Paragraph::default().push_text("Cover"))
+    ///     .push_section(SectionProperty::default().page_size(PageSize::a4().landscape()))
+    ///     .push(Paragraph::default().push_text("Appendix"))
+    ///     .push_section(
+    ///         SectionProperty::default()
+    ///             .section_type(SectionTypeVal::Continuous)
+    ///             .columns(Columns::from(2usize)),
+    ///     );
+    ///
+    /// assert_eq!(doc.sections().len(), 3);
+    /// ```
+    pub fn push_section<T: Into<SectionProperty<'a>>>(&mut self, property: T) -> &mut Self {
+        let current = self.body.property.take().unwrap_or_default();
+
+        match self.body.content.last_mut() {
+            Some(BodyContent::Paragraph(para)) if para.property.section_property.is_none() => {
+                para.property.section_property = Some(current);
+            }
+            _ => {
+                self.body.push(section_break(current));
+            }
+        }
+
+        self.body.property = Some(property.into());
+        self
+    }
+
+    /// Returns the sections of the document
+    ///
+    /// Each section covers a range of the body content, which ends with the
+    /// paragraph containing its properties, except for the last section.
+    /// Paragraphs inside block-level content controls end their sections
+    /// at the controls.
+    pub fn sections(&self) -> Vec<Section<'_, 'a>> {
+        let mut sections = Vec::new();
+        let mut start = 0;
+
+        for (i, content) in self.body.content.iter().enumerate() {
+            let mut properties = Vec::new();
+            section_properties(content, &mut properties);
+            for property in properties {
+                sections.push(Section {
+                    range: start..i + 1,
+                    property: Some(property),
+                });
+                start = i + 1;
+            }
+        }
+
+        sections.push(Section {
+            range: start..self.body.content.len(),
+            property: self.body.property.as_ref(),
+        });
+
+        sections
+    }
+
+    /// Splits a section before the body content at index `at`
+    ///
+    /// The content before `at` becomes a section with the given properties,
+    /// while the content after it stays in the original section. A new empty
+    /// paragraph is inserted to hold the properties if the content before
+    /// `at` isn't a paragraph or already ends a section.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the body content.
+    pub fn split_section<T: Into<SectionProperty<'a>>>(&mut self, at: usize, property: T) {
+        assert!(
+            at <= self.body.content.len(),
+            "split index (is {}) should be <= len (is {})",
+            at,
+            self.body.content.len()
+        );
+
+        let property = property.into();
+
+        match at.checked_sub(1).map(|i| &mut self.body.content[i]) {
+            Some(BodyContent::Paragraph(para)) if para.property.section_property.is_none() => {
+                para.property.section_property = Some(property);
+            }
+            _ => self.body.content.insert(at, section_break(property).into()),
+        }
+    }
+
+    /// Merges the section at `index` with the following one
+    ///
+    /// Just like removing a section break in Word, the merged section uses
+    /// the properties of the following section. The paragraph holding the
+    /// properties is removed as well if it's empty, e.g. one inserted by
+    /// `push_section`. Returns the properties of the removed section, or
+    /// `None` if there's no following section.
+    pub fn merge_sections(&mut self, index: usize) -> Option<SectionProperty<'a>> {
+        take_section_property(&mut self.body.content, &mut { index })
+    }
+
+    /// Returns the ids of anchored comments with the text they are anchored to
+    ///
+    /// Comments are listed in the order their ranges end. Paragraphs inside
+    /// a range are separated by line feeds.
+    pub fn comment_anchors(&self) -> Vec<(usize, String)> {
+        let mut open = Vec::new();
+        let mut anchors = Vec::new();
+        collect_anchors(&self.body.content, &mut open, &mut anchors);
+        anchors
+    }
+
+    /// Anchors the comment with id `reply` to the same range as `parent`
+    ///
+    /// Word anchors replies this way: their range markers and references
+    /// follow the ones of the comment they reply to. Returns `false` if the
+    /// parent comment isn't anchored in the document.
+    pub fn anchor_reply(&mut self, parent: usize, reply: usize) -> bool {
+        let mut found = false;
+
+        for_each_paragraph_mut(&mut self.body.content, &mut |para| {
+            for i in (0..para.content.len()).rev() {
+                let content: ParagraphContent = match &para.content[i] {
+                    ParagraphContent::CommentRangeStart(start) if start.id == parent => {
+                        CommentRangeStart::from(reply).into()
+                    }
+                    ParagraphContent::CommentRangeEnd(end) if end.id == parent => {
+                        CommentRangeEnd::from(reply).into()
+                    }
+                    ParagraphContent::Run(run)
+                        if run.content.iter().any(|content| {
+                            matches!(content, RunContent::CommentReference(r) if r.id == parent)
+                        }) =>
+                    {
+                        Run::default().push(CommentReference::from(reply)).into()
+                    }
+                    _ => continue,
+                };
+                para.content.insert(i + 1, content);
+                found = true;
+            }
+        });
+
+        found
+    }
+}
+
+/// A section of the document
+#[derive(Debug)]
+pub struct Section<'b, 'a> {
+    /// Specifies the range of the body content in the section.
+    pub range: Range<usize>,
+    /// Specifies the properties of the section.
+    pub property: Option<&'b SectionProperty<'a>>,
+}
+
+// collects the section properties of the paragraph, or of the paragraphs
+// inside the content control
+fn section_properties<'b, 'a>(
+    content: &'b BodyContent<'a>,
+    properties: &mut Vec<&'b SectionProperty<'a>>,
+) {
+    match content {
+        BodyContent::Paragraph(para) => properties.extend(&para.property.section_property),
+        BodyContent::Sdt(sdt) => {
+            for content in &sdt.content.content {
+                section_properties(content, properties);
+            }
+        }
+        _ => (),
+    }
+}
+
+// takes the section properties at `index`, counted through the content,
+// and removes the paragraph holding them if it's left empty
+fn take_section_property<'a>(
+    content: &mut Vec<BodyContent<'a>>,
+    index: &mut usize,
+) -> Option<SectionProperty<'a>> {
+    for i in 0..content.len() {
+        let para = match &mut content[i] {
+            BodyContent::Paragraph(para) if para.property.section_property.is_some() => para,
+            BodyContent::Sdt(sdt) => match take_section_property(&mut sdt.content.content, index) {
+                Some(property) => return Some(property),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        if *index > 0 {
+            *index -= 1;
+            continue;
+        }
+
+        let property = para.property.section_property.take();
+        if is_empty_paragraph(para) {
+            content.remove(i);
+        }
+        return property;
+    }
+
+    None
+}
+
+fn is_empty_paragraph(para: &Paragraph) -> bool {
+    let ParagraphProperty {
+        style_id,
+        justification,
+        border,
+        numbering,
+        mark,
+        section_property,
+        change,
+    } = &para.property;
+
+    para.content.is_empty()
+        && style_id.is_none()
+        && justification.is_none()
+        && border.is_none()
+        && numbering.is_none()
+        && mark.is_none()
+        && section_property.is_none()
+        && change.is_none()
+}
+
+fn collect_anchors(
+    content: &[BodyContent],
+    open: &mut Vec<(usize, String)>,
+    anchors: &mut Vec<(usize, String)>,
+) {
+    fn paragraph(
+        para: &Paragraph,
+        open: &mut Vec<(usize, String)>,
+        anchors: &mut Vec<(usize, String)>,
+    ) {
+        for content in &para.content {
+            match content {
+                ParagraphContent::CommentRangeStart(start) => open.push((start.id, String::new())),
+                ParagraphContent::CommentRangeEnd(end) => {
+                    if let Some(i) = open.iter().position(|(id, _)| *id == end.id) {
+                        anchors.push(open.remove(i));
+                    }
+                }
+                _ => {
+                    let mut text = String::new();
+                    text_of(std::slice::from_ref(content), &mut text);
+                    for (_, anchor) in open.iter_mut() {
+                        anchor.push_str(&text);
+                    }
+                }
+            }
+        }
+        for (_, anchor) in open.iter_mut() {
+            anchor.push('\n');
+        }
+    }
+
+    for content in content {
+        match content {
+            BodyContent::Paragraph(para) => paragraph(para, open, anchors),
+            BodyContent::Table(table) => {
+                for_each_table_paragraph(table, &mut |para| paragraph(para, open, anchors))
+            }
+            BodyContent::Sdt(sdt) => collect_anchors(&sdt.content.content, open, anchors),
+            _ => (),
+        }
+    }
+}
+
+pub(crate) fn for_each_paragraph<'b, 'a, F>(content: &'b [BodyContent<'a>], f: &mut F)
+where
+    F: FnMut(&'b Paragraph<'a>),
+{
+    for content in content {
+        match content {
+            BodyContent::Paragraph(para) => f(para),
+            BodyContent::Table(table) => for_each_table_paragraph(table, f),
+            BodyContent::Sdt(sdt) => for_each_paragraph(&sdt.content.content, f),
+            _ => (),
+        }
+    }
+}
+
+/// Calls `f` with every paragraph in the cells, including nested tables
+pub(crate) fn for_each_table_paragraph<'b, 'a, F>(table: &'b Table<'a>, f: &mut F)
+where
+    F: FnMut(&'b Paragraph<'a>),
+{
+    for row in &table.rows {
+        for cell in &row.cells {
+            for content in &cell.content {
+                match content {
+                    TableCellContent::Paragraph(para) => f(para),
+                    TableCellContent::Table(table) => for_each_table_paragraph(table, f),
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn for_each_paragraph_mut<'a, F>(content: &mut [BodyContent<'a>], f: &mut F)
+where
+    F: FnMut(&mut Paragraph<'a>),
+{
+    for content in content {
+        match content {
+            BodyContent::Paragraph(para) => f(para),
+            BodyContent::Table(table) => for_each_table_paragraph_mut(table, f),
+            BodyContent::Sdt(sdt) => for_each_paragraph_mut(&mut sdt.content.content, f),
+            _ => (),
+        }
+    }
+}
+
+pub(crate) fn for_each_table_paragraph_mut<'a, F>(table: &mut Table<'a>, f: &mut F)
+where
+    F: FnMut(&mut Paragraph<'a>),
+{
+    for row in &mut table.rows {
+        for cell in &mut row.cells {
+            for content in &mut cell.content {
+                match content {
+                    TableCellContent::Paragraph(para) => f(para),
+                    TableCellContent::Table(table) => for_each_table_paragraph_mut(table, f),
+                }
+            }
+        }
+    }
+}
+
+/// Calls `f` with every run in the paragraph content, including the ones in
+/// hyperlinks, nested controls and tracked changes
+pub(crate) fn for_each_run<'b, 'a, F>(content: &'b [ParagraphContent<'a>], f: &mut F)
+where
+    F: FnMut(&'b Run<'a>),
+{
+    for content in content {
+        match content {
+            ParagraphContent::Run(run) => f(run),
+            ParagraphContent::Link(link) => f(&link.content),
+            ParagraphContent::Sdt(sdt) => for_each_run(&sdt.content.content, f),
+            ParagraphContent::Insertion(ins) => for_each_run(&ins.content, f),
+            ParagraphContent::Deletion(del) => for_each_run(&del.content, f),
+            ParagraphContent::MoveFrom(from) => for_each_run(&from.content, f),
+            ParagraphContent::MoveTo(to) => for_each_run(&to.content, f),
+            ParagraphContent::SimpleField(field) => field.content.iter().for_each(&mut *f),
+            _ => (),
+        }
+    }
+}
+
+/// Calls `f` with every run in paragraphs, hyperlinks, nested controls and
+/// tracked changes
+pub(crate) fn for_each_run_mut<'a, F>(content: &mut [BodyContent<'a>], f: &mut F)
+where
+    F: FnMut(&mut Run<'a>),
+{
+    fn paragraph<'a, F>(content: &mut [ParagraphContent<'a>], f: &mut F)
+    where
+        F: FnMut(&mut Run<'a>),
+    {
+        for content in content {
+            match content {
+                ParagraphContent::Run(run) => f(run),
+                ParagraphContent::Link(link) => f(&mut link.content),
+                ParagraphContent::Sdt(sdt) => paragraph(&mut sdt.content.content, f),
+                ParagraphContent::Insertion(ins) => paragraph(&mut ins.content, f),
+                ParagraphContent::Deletion(del) => paragraph(&mut del.content, f),
+                ParagraphContent::MoveFrom(from) => paragraph(&mut from.content, f),
+                ParagraphContent::MoveTo(to) => paragraph(&mut to.content, f),
+                ParagraphContent::SimpleField(field) => field.content.iter_mut().for_each(&mut *f),
+                _ => (),
+            }
+        }
+    }
+
+    for_each_paragraph_mut(content, &mut |para| paragraph(&mut para.content, f));
+}
+
+fn section_break(property: SectionProperty) -> Paragraph {
+    Paragraph::default().property(ParagraphProperty::default().section_property(property))
 }
 
 impl<'a> XmlWrite for Document<'a> {
@@ -53,6 +486,16 @@ impl<'a> XmlWrite for Document<'a> {
         writer.write_element_start("w:document")?;
 
         writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+        writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+        writer.write_attribute("xmlns:w14", SCHEMA_WORD_2010)?;
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:wp", SCHEMA_WORDPROCESSING_DRAWING)?;
+        writer.write_attribute("xmlns:a", SCHEMA_DRAWING_MAIN)?;
+        writer.write_attribute("xmlns:pic", SCHEMA_PICTURE)?;
+        writer.write_attribute("xmlns:wps", SCHEMA_WORDPROCESSING_SHAPE)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("xmlns:v", SCHEMA_VML)?;
+        writer.write_attribute("mc:Ignorable", "w14 w15")?;
 
         writer.write_element_end_open()?;
 
@@ -70,8 +513,76 @@ __xml_test_suites!(
     Document,
     Document::default(),
     format!(
-        r#"<w:document xmlns:w="{}"><w:body/></w:document>"#,
-        SCHEMA_MAIN
+        r#"<w:document xmlns:w="{}" xmlns:r="{}" xmlns:w14="{}" xmlns:w15="{}" xmlns:wp="{}" xmlns:a="{}" xmlns:pic="{}" xmlns:wps="{}" xmlns:mc="{}" xmlns:v="{}" mc:Ignorable="w14 w15"><w:body/></w:document>"#,
+        SCHEMA_MAIN,
+        SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS,
+        SCHEMA_WORD_2010,
+        SCHEMA_WORD_2012,
+        SCHEMA_WORDPROCESSING_DRAWING,
+        SCHEMA_DRAWING_MAIN,
+        SCHEMA_PICTURE,
+        SCHEMA_WORDPROCESSING_SHAPE,
+        SCHEMA_MARKUP_COMPATIBILITY,
+        SCHEMA_VML
     )
     .as_str(),
 );
+
+#[test]
+fn sections() {
+    use crate::formatting::{PageSize, SectionTypeVal};
+
+    let mut doc = Document::default();
+    doc.push(Paragraph::default().push_text("Cover"))
+        .push_section(SectionProperty::default().page_size(PageSize::a4().landscape()))
+        .push(Table::default())
+        .push_section(SectionProperty::default().section_type(SectionTypeVal::Continuous));
+
+    let sections = doc.sections();
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].range, 0..1);
+    assert_eq!(sections[0].property, Some(&SectionProperty::default()));
+    // an empty paragraph is inserted after the table
+    assert_eq!(sections[1].range, 1..3);
+    assert!(sections[1].property.unwrap().page_size.is_some());
+    assert_eq!(sections[2].range, 3..3);
+
+    doc.split_section(0, SectionProperty::default().page_size(PageSize::letter()));
+    assert_eq!(doc.body.content.len(), 4);
+    assert_eq!(doc.sections()[0].range, 0..1);
+    assert_eq!(doc.sections()[1].range, 1..2);
+
+    let property = doc.merge_sections(1);
+    assert_eq!(property, Some(SectionProperty::default()));
+    assert_eq!(doc.sections().len(), 3);
+    assert_eq!(doc.sections()[1].range, 1..4);
+    assert!(doc.merge_sections(2).is_none());
+
+    // the empty paragraph inserted after the table is removed
+    assert!(doc.merge_sections(1).unwrap().page_size.is_some());
+    assert_eq!(doc.body.content.len(), 3);
+    assert_eq!(doc.sections().len(), 2);
+    assert_eq!(doc.sections()[1].range, 1..3);
+
+    // section properties inside content controls end their sections too
+    doc.push(
+        SdtBlock::default()
+            .push(Paragraph::default().push_text("Terms"))
+            .push(section_break(
+                SectionProperty::default().page_size(PageSize::a4()),
+            )),
+    )
+    .push(Paragraph::default().push_text("Signatures"));
+    let sections = doc.sections();
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[1].range, 1..4);
+    assert!(sections[1].property.unwrap().page_size.is_some());
+    assert_eq!(sections[2].range, 4..5);
+
+    assert!(doc.merge_sections(1).unwrap().page_size.is_some());
+    assert_eq!(doc.sections().len(), 2);
+    match &doc.body.content[3] {
+        BodyContent::Sdt(sdt) => assert_eq!(sdt.content.content.len(), 1),
+        _ => panic!(),
+    }
+}
@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, DateTime};
+
+/// Move Source Location Container Start
+///
+/// Specifies the start of the region moved away from this location, which
+/// is named so that the source and destination can be matched.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:moveFromRangeStart")]
+pub struct MoveFromRangeStart<'a> {
+    /// Specifies the id of the range.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+    /// Specifies the name of the move.
+    #[xml(attr = "w:name")]
+    pub name: Option<Cow<'a, str>>,
+    /// Specifies the author of the revision.
+    #[xml(attr = "w:author")]
+    pub author: Option<Cow<'a, str>>,
+    /// Specifies when the revision was made.
+    #[xml(attr = "w:date")]
+    pub date: Option<DateTime>,
+}
+
+impl<'a> MoveFromRangeStart<'a> {
+    __setter!(name: Option<Cow<'a, str>>);
+    __setter!(author: Option<Cow<'a, str>>);
+    __setter!(date: Option<DateTime>);
+}
+
+/// Move Source Location Container End
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:moveFromRangeEnd")]
+pub struct MoveFromRangeEnd {
+    /// Specifies the id of the corresponding range start.
+    #[xml(attr = "w:id")]
+    pub id: usize,
+}
+
+__xml_test_suites!(
+    MoveFromRangeStart,
+    MoveFromRangeStart::default(),
+    r#"<w:moveFromRangeStart w:id="0"/>"#,
+    MoveFromRangeStart::default().name("move1").author("foo"),
+    r#"<w:moveFromRangeStart w:id="0" w:name="move1" w:author="foo"/>"#,
+);
@@ -1,10 +1,16 @@
 use derive_more::From;
 use std::borrow::Cow;
+use std::ops::Range;
 use strong_xml::{XmlRead, XmlWrite};
 
 use crate::{
     __setter, __xml_test_suites,
-    document::{BookmarkEnd, BookmarkStart, Hyperlink, Run, RunContent, Text},
+    document::{
+        field::collect_fields, BookmarkEnd, BookmarkStart, CommentRangeEnd, CommentRangeStart,
+        CommentReference, Deletion, Field, Hyperlink, Insertion, MoveFrom, MoveFromRangeEnd,
+        MoveFromRangeStart, MoveTo, MoveToRangeEnd, MoveToRangeStart, PermissionEnd,
+        PermissionStart, RevisionView, Run, RunContent, SdtRun, SimpleField, Text, TrackChanges,
+    },
     formatting::ParagraphProperty,
 };
 
@@ -28,6 +34,15 @@ use crate::{
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:p")]
 pub struct Paragraph<'a> {
+    /// Specifies the unique id of the paragraph
+    ///
+    /// Word uses it to identify paragraphs across parts, e.g. comment
+    /// extensions refer to the last paragraph of comments with it.
+    #[xml(attr = "w14:paraId")]
+    pub para_id: Option<Cow<'a, str>>,
+    /// Specifies the id of the paragraph text revision.
+    #[xml(attr = "w14:textId")]
+    pub text_id: Option<Cow<'a, str>>,
     /// Specifies the properties of a paragraph
     ///
     /// This information is applied to all the contents of the paragraph.
@@ -40,12 +55,28 @@ pub struct Paragraph<'a> {
         child = "w:r",
         child = "w:hyperlink",
         child = "w:bookmarkStart",
-        child = "w:bookmarkEnd"
+        child = "w:bookmarkEnd",
+        child = "w:permStart",
+        child = "w:permEnd",
+        child = "w:sdt",
+        child = "w:commentRangeStart",
+        child = "w:commentRangeEnd",
+        child = "w:ins",
+        child = "w:del",
+        child = "w:moveFrom",
+        child = "w:moveTo",
+        child = "w:moveFromRangeStart",
+        child = "w:moveFromRangeEnd",
+        child = "w:moveToRangeStart",
+        child = "w:moveToRangeEnd",
+        child = "w:fldSimple"
     )]
     pub content: Vec<ParagraphContent<'a>>,
 }
 
 impl<'a> Paragraph<'a> {
+    __setter!(para_id: Option<Cow<'a, str>>);
+    __setter!(text_id: Option<Cow<'a, str>>);
     __setter!(property: ParagraphProperty<'a>);
 
     #[inline(always)]
@@ -63,26 +94,186 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Appends the runs of a complex field
+    #[inline(always)]
+    pub fn push_field(mut self, field: Field<'a>) -> Self {
+        self.content
+            .extend(field.into_runs().into_iter().map(ParagraphContent::Run));
+        self
+    }
+
+    /// Returns the simple and complex fields in the paragraph, in the order
+    /// they begin
+    ///
+    /// Complex fields spanning several paragraphs, e.g. tables of contents,
+    /// are only partly included.
+    pub fn fields(&self) -> Vec<Field<'_>> {
+        collect_fields(&self.content)
+    }
+
+    /// Anchors the comment with the given id around the contents in range
+    ///
+    /// The range is marked with `w:commentRangeStart` and
+    /// `w:commentRangeEnd`, followed by a run containing the comment
+    /// reference.
+    ///
+    /// ```rust
+    /// use docx::comments::Comment;
+    /// use docx::document::*;
+    /// use docx::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let id = docx.add_comment(Comment::new("Reviewer").push_text("Typo?"));
+    ///
+    /// let mut para = Paragraph::default()
+    ///     .push_text("Lorem ")
+    ///     .push_text("ipsum")
+    ///     .push_text(" dolor");
+    /// para.anchor_comment(id, 1..2);
+    /// docx.document.push(para);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn anchor_comment(&mut self, id: usize, range: Range<usize>) -> &mut Self {
+        assert!(
+            range.start <= range.end && range.end <= self.content.len(),
+            "comment range {:?} is out of bounds",
+            range
+        );
+
+        let reference = Run::default().push(CommentReference::from(id));
+        self.content.splice(
+            range.end..range.end,
+            vec![CommentRangeEnd::from(id).into(), reference.into()],
+        );
+        self.content
+            .insert(range.start, CommentRangeStart::from(id).into());
+        self
+    }
+
     pub fn iter_text(&self) -> impl Iterator<Item = &Cow<'a, str>> {
+        self.content.iter().flat_map(ParagraphContent::iter_text)
+    }
+
+    /// Inserts the content at `index` as a tracked change
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the content.
+    pub fn insert_tracked<T: Into<ParagraphContent<'a>>>(
+        &mut self,
+        index: usize,
+        content: T,
+        changes: &mut TrackChanges<'a>,
+    ) -> &mut Self {
+        let ins = changes.insert(content);
+        self.content.insert(index, ins.into());
+        self
+    }
+
+    /// Appends the content as a tracked change
+    pub fn push_tracked<T: Into<ParagraphContent<'a>>>(
+        &mut self,
+        content: T,
+        changes: &mut TrackChanges<'a>,
+    ) -> &mut Self {
+        let ins = changes.insert(content);
+        self.content.push(ins.into());
+        self
+    }
+
+    /// Deletes the contents in range as a tracked change
+    ///
+    /// The text of runs, hyperlinks, fields, moved content and content
+    /// controls in range is marked as deleted, while the content inserted by
+    /// the same author is removed right away. Use `delete_text_tracked` to
+    /// delete part of a run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn delete_tracked(
+        &mut self,
+        range: Range<usize>,
+        changes: &mut TrackChanges<'a>,
+    ) -> &mut Self {
+        let start = range.start;
+        let deleted = changes.delete(self.content.drain(range).collect());
+        self.content.splice(start..start, deleted);
+        self
+    }
+
+    /// Deletes the text in the range of characters as a tracked change
+    ///
+    /// Characters are counted in the text as it is when tracked changes are
+    /// accepted, i.e. in `iter_text`. Runs and hyperlinks are split at the
+    /// bounds of the range, while fields and drawings overlapping it are
+    /// deleted as a whole.
+    ///
+    /// ```rust
+    /// use docx::document::*;
+    ///
+    /// let mut changes = TrackChanges::new("Counsel");
+    ///
+    /// let mut para = Paragraph::default().push_text("The Seller shall promptly deliver");
+    /// para.delete_text_tracked(17..26, &mut changes);
+    ///
+    /// let accepted: String = para.iter_text_in(RevisionView::Accepted).map(|t| &**t).collect();
+    /// let original: String = para.iter_text_in(RevisionView::Original).map(|t| &**t).collect();
+    ///
+    /// assert_eq!(accepted, "The Seller shall deliver");
+    /// assert_eq!(original, "The Seller shall promptly deliver");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn delete_text_tracked(
+        &mut self,
+        range: Range<usize>,
+        changes: &mut TrackChanges<'a>,
+    ) -> &mut Self {
+        let len: usize = self.iter_text().map(|text| text.chars().count()).sum();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "text range {:?} is out of bounds",
+            range
+        );
+
+        let content = std::mem::take(&mut self.content);
+        self.content = changes.delete_range(content, range);
+        self
+    }
+
+    /// Iterates over the text as it is when tracked changes are accepted or
+    /// rejected
+    ///
+    /// ```rust
+    /// use docx::document::*;
+    ///
+    /// let para = Paragraph::default()
+    ///     .push_text("Lorem ")
+    ///     .push(Insertion::new(0, "foo").push(Run::default().push_text("ipsum")))
+    ///     .push(Deletion::new(1, "foo").push(Run::default().push(DeletedText::from("dolor"))));
+    ///
+    /// let accepted: Vec<_> = para.iter_text_in(RevisionView::Accepted).collect();
+    /// let original: Vec<_> = para.iter_text_in(RevisionView::Original).collect();
+    ///
+    /// assert_eq!(accepted, ["Lorem ", "ipsum"]);
+    /// assert_eq!(original, ["Lorem ", "dolor"]);
+    /// ```
+    pub fn iter_text_in(&self, view: RevisionView) -> impl Iterator<Item = &Cow<'a, str>> {
         self.content
             .iter()
-            .filter_map(|content| match content {
-                ParagraphContent::Run(run) => Some(run.iter_text()),
-                ParagraphContent::Link(link) => Some(link.content.iter_text()),
-                _ => None,
-            })
-            .flatten()
+            .flat_map(move |content| content.iter_text_in(view))
     }
 
     pub fn iter_text_mut(&mut self) -> impl Iterator<Item = &mut Cow<'a, str>> {
         self.content
             .iter_mut()
-            .filter_map(|content| match content {
-                ParagraphContent::Run(run) => Some(run.iter_text_mut()),
-                ParagraphContent::Link(link) => Some(link.content.iter_text_mut()),
-                _ => None,
-            })
-            .flatten()
+            .flat_map(ParagraphContent::iter_text_mut)
     }
 }
 
@@ -98,6 +289,106 @@ pub enum ParagraphContent<'a> {
     BookmarkStart(BookmarkStart<'a>),
     #[xml(tag = "w:bookmarkEnd")]
     BookmarkEnd(BookmarkEnd<'a>),
+    #[xml(tag = "w:permStart")]
+    PermissionStart(PermissionStart<'a>),
+    #[xml(tag = "w:permEnd")]
+    PermissionEnd(PermissionEnd<'a>),
+    #[xml(tag = "w:sdt")]
+    Sdt(SdtRun<'a>),
+    #[xml(tag = "w:commentRangeStart")]
+    CommentRangeStart(CommentRangeStart),
+    #[xml(tag = "w:commentRangeEnd")]
+    CommentRangeEnd(CommentRangeEnd),
+    #[xml(tag = "w:ins")]
+    Insertion(Insertion<'a>),
+    #[xml(tag = "w:del")]
+    Deletion(Deletion<'a>),
+    #[xml(tag = "w:moveFrom")]
+    MoveFrom(MoveFrom<'a>),
+    #[xml(tag = "w:moveTo")]
+    MoveTo(MoveTo<'a>),
+    #[xml(tag = "w:moveFromRangeStart")]
+    MoveFromRangeStart(MoveFromRangeStart<'a>),
+    #[xml(tag = "w:moveFromRangeEnd")]
+    MoveFromRangeEnd(MoveFromRangeEnd),
+    #[xml(tag = "w:moveToRangeStart")]
+    MoveToRangeStart(MoveToRangeStart<'a>),
+    #[xml(tag = "w:moveToRangeEnd")]
+    MoveToRangeEnd(MoveToRangeEnd),
+    #[xml(tag = "w:fldSimple")]
+    SimpleField(SimpleField<'a>),
+}
+
+impl<'a> ParagraphContent<'a> {
+    /// Iterates over the text in runs, hyperlinks and nested controls
+    ///
+    /// Tracked changes are viewed as accepted.
+    pub fn iter_text(&self) -> Box<dyn Iterator<Item = &Cow<'a, str>> + '_> {
+        self.iter_text_in(RevisionView::Accepted)
+    }
+
+    /// Iterates over the text in runs, hyperlinks and nested controls, as
+    /// it is when tracked changes are accepted or rejected
+    pub fn iter_text_in(&self, view: RevisionView) -> Box<dyn Iterator<Item = &Cow<'a, str>> + '_> {
+        match self {
+            ParagraphContent::Run(run) => Box::new(run.iter_text_in(view)),
+            ParagraphContent::Link(link) => Box::new(link.content.iter_text_in(view)),
+            ParagraphContent::SimpleField(field) => Box::new(
+                field
+                    .content
+                    .iter()
+                    .flat_map(move |run| run.iter_text_in(view)),
+            ),
+            ParagraphContent::Sdt(sdt) => iter_content(&sdt.content.content, view),
+            ParagraphContent::Insertion(ins) if view == RevisionView::Accepted => {
+                iter_content(&ins.content, view)
+            }
+            ParagraphContent::MoveTo(to) if view == RevisionView::Accepted => {
+                iter_content(&to.content, view)
+            }
+            ParagraphContent::Deletion(del) if view == RevisionView::Original => {
+                iter_content(&del.content, view)
+            }
+            ParagraphContent::MoveFrom(from) if view == RevisionView::Original => {
+                iter_content(&from.content, view)
+            }
+            _ => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Iterates over the text in runs, hyperlinks and nested controls
+    ///
+    /// Tracked changes are viewed as accepted.
+    pub fn iter_text_mut(&mut self) -> Box<dyn Iterator<Item = &mut Cow<'a, str>> + '_> {
+        match self {
+            ParagraphContent::Run(run) => Box::new(run.iter_text_mut()),
+            ParagraphContent::Link(link) => Box::new(link.content.iter_text_mut()),
+            ParagraphContent::SimpleField(field) => {
+                Box::new(field.content.iter_mut().flat_map(Run::iter_text_mut))
+            }
+            ParagraphContent::Sdt(sdt) => iter_content_mut(&mut sdt.content.content),
+            ParagraphContent::Insertion(ins) => iter_content_mut(&mut ins.content),
+            ParagraphContent::MoveTo(to) => iter_content_mut(&mut to.content),
+            _ => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+fn iter_content<'b, 'a>(
+    content: &'b [ParagraphContent<'a>],
+    view: RevisionView,
+) -> Box<dyn Iterator<Item = &'b Cow<'a, str>> + 'b> {
+    Box::new(
+        content
+            .iter()
+            .flat_map(move |content| content.iter_text_in(view)),
+    )
+}
+
+fn iter_content_mut<'b, 'a>(
+    content: &'b mut [ParagraphContent<'a>],
+) -> Box<dyn Iterator<Item = &'b mut Cow<'a, str>> + 'b> {
+    Box::new(content.iter_mut().flat_map(ParagraphContent::iter_text_mut))
 }
 
 __xml_test_suites!(
@@ -112,4 +403,94 @@ __xml_test_suites!(
     r#"<w:p><w:pPr/><w:bookmarkStart/></w:p>"#,
     Paragraph::default().push(BookmarkEnd::default()),
     r#"<w:p><w:pPr/><w:bookmarkEnd/></w:p>"#,
+    Paragraph::default().push(PermissionStart::default()),
+    r#"<w:p><w:pPr/><w:permStart/></w:p>"#,
+    Paragraph::default().push(PermissionEnd::default()),
+    r#"<w:p><w:pPr/><w:permEnd/></w:p>"#,
+    Paragraph::default().push(SdtRun::default()),
+    r#"<w:p><w:pPr/><w:sdt><w:sdtPr/><w:sdtContent/></w:sdt></w:p>"#,
+    Paragraph::default()
+        .push(Insertion::new(0, "foo"))
+        .push(Deletion::new(1, "foo")),
+    r#"<w:p><w:pPr/><w:ins w:id="0" w:author="foo"/><w:del w:id="1" w:author="foo"/></w:p>"#,
+    Paragraph::default().para_id("1A2B3C4D").text_id("77777777"),
+    r#"<w:p w14:paraId="1A2B3C4D" w14:textId="77777777"><w:pPr/></w:p>"#,
+    Paragraph::default()
+        .push(CommentRangeStart::from(0))
+        .push_text("text")
+        .push(CommentRangeEnd::from(0)),
+    r#"<w:p><w:pPr/><w:commentRangeStart w:id="0"/><w:r><w:rPr/><w:t>text</w:t></w:r><w:commentRangeEnd w:id="0"/></w:p>"#,
 );
+
+#[test]
+fn anchor_comment() {
+    let mut para = Paragraph::default()
+        .push_text("foo")
+        .push_text("bar")
+        .push_text("baz");
+    para.anchor_comment(3, 1..2);
+
+    assert_eq!(
+        para.to_string().unwrap(),
+        r#"<w:p><w:pPr/><w:r><w:rPr/><w:t>foo</w:t></w:r><w:commentRangeStart w:id="3"/><w:r><w:rPr/><w:t>bar</w:t></w:r><w:commentRangeEnd w:id="3"/><w:r><w:rPr/><w:commentReference w:id="3"/></w:r><w:r><w:rPr/><w:t>baz</w:t></w:r></w:p>"#
+    );
+}
+
+#[test]
+fn delete_tracked() {
+    let mut changes = TrackChanges::new("foo").next_id(5);
+    changes.date = None;
+
+    let mut para = Paragraph::default()
+        .push_text("foo")
+        .push_text("bar")
+        .push(Insertion::new(0, "foo").push(Run::default().push_text("baz")))
+        .push(Insertion::new(1, "bar").push(Run::default().push_text("qux")));
+    para.delete_tracked(1..4, &mut changes);
+    para.push_tracked(Run::default().push_text("quux"), &mut changes);
+
+    assert_eq!(
+        para.to_string().unwrap(),
+        r#"<w:p><w:pPr/><w:r><w:rPr/><w:t>foo</w:t></w:r><w:del w:id="5" w:author="foo"><w:r><w:rPr/><w:delText>bar</w:delText></w:r></w:del><w:ins w:id="1" w:author="bar"><w:del w:id="6" w:author="foo"><w:r><w:rPr/><w:delText>qux</w:delText></w:r></w:del></w:ins><w:ins w:id="7" w:author="foo"><w:r><w:rPr/><w:t>quux</w:t></w:r></w:ins></w:p>"#
+    );
+}
+
+#[test]
+fn delete_text_tracked() {
+    let mut changes = TrackChanges::new("foo").next_id(5);
+    changes.date = None;
+
+    let mut para = Paragraph::default()
+        .push_text("Lorem ipsum")
+        .push(Insertion::new(0, "foo").push(Run::default().push_text(" dolor")))
+        .push(SdtRun::default().push(Run::default().push_text(" sit")))
+        .push(
+            Hyperlink::default()
+                .id("rId9")
+                .content(Run::default().push_text(" amet")),
+        );
+    para.delete_text_tracked(6..22, &mut changes);
+
+    assert_eq!(
+        para.iter_text_in(RevisionView::Accepted)
+            .map(|t| &**t)
+            .collect::<String>(),
+        "Lorem amet"
+    );
+    assert_eq!(
+        para.to_string().unwrap(),
+        r#"<w:p><w:pPr/><w:r><w:rPr/><w:t xml:space="preserve">Lorem </w:t></w:r><w:del w:id="5" w:author="foo"><w:r><w:rPr/><w:delText>ipsum</w:delText></w:r></w:del><w:sdt><w:sdtPr/><w:sdtContent><w:del w:id="6" w:author="foo"><w:r><w:rPr/><w:delText> sit</w:delText></w:r></w:del></w:sdtContent></w:sdt><w:del w:id="7" w:author="foo"><w:hyperlink r:id="rId9"><w:r><w:rPr/><w:delText xml:space="preserve"> </w:delText></w:r></w:hyperlink></w:del><w:hyperlink r:id="rId9"><w:r><w:rPr/><w:t>amet</w:t></w:r></w:hyperlink></w:p>"#
+    );
+
+    let mut para = Paragraph::default()
+        .push(SdtRun::default().push(Run::default().push_text("foo")))
+        .push(SimpleField::new("PAGE").push(Run::default().push_text("1")))
+        .push(MoveTo::new(0, "bar").push(Run::default().push_text("baz")));
+    para.delete_tracked(0..3, &mut changes);
+
+    assert_eq!(para.iter_text_in(RevisionView::Accepted).count(), 0);
+    assert_eq!(
+        para.to_string().unwrap(),
+        r#"<w:p><w:pPr/><w:sdt><w:sdtPr/><w:sdtContent><w:del w:id="8" w:author="foo"><w:r><w:rPr/><w:delText>foo</w:delText></w:r></w:del></w:sdtContent></w:sdt><w:del w:id="9" w:author="foo"><w:r><w:rPr/><w:fldChar w:fldCharType="begin"/></w:r><w:r><w:rPr/><w:delInstrText xml:space="preserve"> PAGE </w:delInstrText></w:r><w:r><w:rPr/><w:fldChar w:fldCharType="separate"/></w:r><w:r><w:rPr/><w:delText>1</w:delText></w:r><w:r><w:rPr/><w:fldChar w:fldCharType="end"/></w:r></w:del><w:moveTo w:id="0" w:author="bar"><w:del w:id="10" w:author="foo"><w:r><w:rPr/><w:delText>baz</w:delText></w:r></w:del></w:moveTo></w:p>"#
+    );
+}
@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+/// End of a range permission
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:permEnd")]
+pub struct PermissionEnd<'a> {
+    /// Specifies the identifier of the corresponding range permission.
+    #[xml(attr = "w:id")]
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> PermissionEnd<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+}
+
+__xml_test_suites!(
+    PermissionEnd,
+    PermissionEnd::default(),
+    r#"<w:permEnd/>"#,
+    PermissionEnd::default().id("0"),
+    r#"<w:permEnd w:id="0"/>"#,
+);
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Beginning of a range permission
+///
+/// Specifies the start of a range which can be edited in a protected
+/// document, by everyone or by the given editors.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:permStart")]
+pub struct PermissionStart<'a> {
+    /// Specifies a unique identifier for the range.
+    #[xml(attr = "w:id")]
+    pub id: Option<Cow<'a, str>>,
+    /// Specifies the group of editors allowed to edit the range.
+    #[xml(attr = "w:edGrp")]
+    pub editor_group: Option<EditorGroup>,
+    /// Specifies the single user allowed to edit the range.
+    #[xml(attr = "w:ed")]
+    pub editor: Option<Cow<'a, str>>,
+    /// Specifies the first table column covered by the range.
+    #[xml(attr = "w:colFirst")]
+    pub column_first: Option<usize>,
+    /// Specifies the last table column covered by the range.
+    #[xml(attr = "w:colLast")]
+    pub column_last: Option<usize>,
+}
+
+impl<'a> PermissionStart<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+    __setter!(editor_group: Option<EditorGroup>);
+    __setter!(editor: Option<Cow<'a, str>>);
+    __setter!(column_first: Option<usize>);
+    __setter!(column_last: Option<usize>);
+}
+
+/// Specifies the group of editors of a range permission
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum EditorGroup {
+    None,
+    Everyone,
+    Administrators,
+    Contributors,
+    Editors,
+    Owners,
+    Current,
+}
+
+__string_enum! {
+    EditorGroup {
+        None = "none",
+        Everyone = "everyone",
+        Administrators = "administrators",
+        Contributors = "contributors",
+        Editors = "editors",
+        Owners = "owners",
+        Current = "current",
+    }
+}
+
+__xml_test_suites!(
+    PermissionStart,
+    PermissionStart::default(),
+    r#"<w:permStart/>"#,
+    PermissionStart::default()
+        .id("0")
+        .editor_group(EditorGroup::Everyone),
+    r#"<w:permStart w:id="0" w:edGrp="everyone"/>"#,
+    PermissionStart::default()
+        .id("1")
+        .editor("jane@example.com"),
+    r#"<w:permStart w:id="1" w:ed="jane@example.com"/>"#,
+    PermissionStart::default()
+        .column_first(0usize)
+        .column_last(2usize),
+    r#"<w:permStart w:colFirst="0" w:colLast="2"/>"#,
+);
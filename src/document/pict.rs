@@ -0,0 +1,54 @@
+use derive_more::From;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    vml::{Line, Rect, RoundRect, Shape},
+};
+
+/// VML Object
+///
+/// Contains VML shapes, the legacy form of drawings. Usually it's the
+/// fallback of a DrawingML shape in `AlternateContent`. Standalone VML
+/// objects in runs aren't fully modeled, so they're skipped when reading.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pict")]
+pub struct Pict<'a> {
+    #[xml(
+        child = "v:rect",
+        child = "v:roundrect",
+        child = "v:line",
+        child = "v:shape"
+    )]
+    pub content: Vec<PictContent<'a>>,
+}
+
+impl<'a> Pict<'a> {
+    pub fn push<T: Into<PictContent<'a>>>(mut self, content: T) -> Self {
+        self.content.push(content.into());
+        self
+    }
+}
+
+/// A set of VML shapes that can be contained in a VML object
+#[derive(Debug, From, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum PictContent<'a> {
+    #[xml(tag = "v:rect")]
+    Rect(Rect<'a>),
+    #[xml(tag = "v:roundrect")]
+    RoundRect(RoundRect<'a>),
+    #[xml(tag = "v:line")]
+    Line(Line<'a>),
+    #[xml(tag = "v:shape")]
+    Shape(Shape<'a>),
+}
+
+__xml_test_suites!(
+    Pict,
+    Pict::default(),
+    r#"<w:pict/>"#,
+    Pict::default().push(Line::default().from("0,0").to("144pt,0")),
+    r#"<w:pict><v:line from="0,0" to="144pt,0"/></w:pict>"#,
+);
@@ -0,0 +1,585 @@
+use crate::document::{
+    for_each_paragraph, for_each_paragraph_mut, BodyContent, Deletion, Insertion, InstrText,
+    Paragraph, ParagraphContent, Run, RunContent, Table, TableCellContent, Text,
+};
+
+/// Version of a document with tracked changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionView {
+    /// The document as if all tracked changes are accepted
+    Accepted,
+    /// The document as if all tracked changes are rejected
+    Original,
+}
+
+/// Accepts or rejects the tracked changes made by `author`, or by anyone if
+/// `author` is `None`
+pub(crate) fn resolve_revisions(
+    content: &mut Vec<BodyContent>,
+    accept: bool,
+    author: Option<&str>,
+) {
+    let matches = |rev_author: &str| author.is_none() || author == Some(rev_author);
+
+    // move ranges are removed with the moves of the same author, but range
+    // ends don't record the author, so collect the ids of starts first
+    let mut moves = Vec::new();
+    for_each_paragraph_mut(content, &mut |para| {
+        collect_moves(&para.content, &matches, &mut moves)
+    });
+
+    for_each_paragraph_mut(content, &mut |para| {
+        resolve_paragraph(para, accept, &matches, &moves)
+    });
+
+    resolve_structure(content, accept, &matches);
+}
+
+/// Returns the largest id of tracked changes
+pub(crate) fn max_revision_id(content: &[BodyContent]) -> Option<usize> {
+    fn max_id(content: &[ParagraphContent]) -> Option<usize> {
+        content
+            .iter()
+            .filter_map(|content| match content {
+                ParagraphContent::Run(run) => run.property.change.as_ref().map(|c| c.id),
+                ParagraphContent::Link(link) => link.content.property.change.as_ref().map(|c| c.id),
+                ParagraphContent::Sdt(sdt) => max_id(&sdt.content.content),
+                ParagraphContent::Insertion(ins) => max_id(&ins.content).max(Some(ins.id)),
+                ParagraphContent::Deletion(del) => max_id(&del.content).max(Some(del.id)),
+                ParagraphContent::MoveFrom(from) => max_id(&from.content).max(Some(from.id)),
+                ParagraphContent::MoveTo(to) => max_id(&to.content).max(Some(to.id)),
+                ParagraphContent::MoveFromRangeStart(start) => Some(start.id),
+                ParagraphContent::MoveToRangeStart(start) => Some(start.id),
+                _ => None,
+            })
+            .max()
+    }
+
+    fn max_row_id(content: &[BodyContent]) -> Option<usize> {
+        content
+            .iter()
+            .filter_map(|content| match content {
+                BodyContent::Table(table) => max_table_row_id(table),
+                BodyContent::Sdt(sdt) => max_row_id(&sdt.content.content),
+                _ => None,
+            })
+            .max()
+    }
+
+    // rows of nested tables are included
+    fn max_table_row_id(table: &Table) -> Option<usize> {
+        table
+            .rows
+            .iter()
+            .filter_map(|row| {
+                let property = &row.property;
+                let ins = property.insertion.as_ref().map(|ins| ins.id);
+                let nested = row
+                    .cells
+                    .iter()
+                    .flat_map(|cell| &cell.content)
+                    .filter_map(|content| match content {
+                        TableCellContent::Table(table) => max_table_row_id(table),
+                        _ => None,
+                    })
+                    .max();
+                ins.max(property.deletion.as_ref().map(|del| del.id))
+                    .max(nested)
+            })
+            .max()
+    }
+
+    let mut max = max_row_id(content);
+    for_each_paragraph(content, &mut |para| {
+        let property = &para.property;
+        let change = property.change.as_ref().map(|c| c.id);
+        let mark = property.mark.as_ref().and_then(|mark| {
+            let ins = mark.insertion.as_ref().map(|ins| ins.id);
+            ins.max(mark.deletion.as_ref().map(|del| del.id))
+        });
+        max = max.max(change).max(mark).max(max_id(&para.content));
+    });
+    max
+}
+
+// move ranges are keyed by tag, since ids of source and destination ranges
+// can be the same
+type MoveRange = (bool, usize);
+
+fn collect_moves<F>(content: &[ParagraphContent], matches: &F, moves: &mut Vec<MoveRange>)
+where
+    F: Fn(&str) -> bool,
+{
+    for content in content {
+        match content {
+            ParagraphContent::MoveFromRangeStart(start)
+                if start.author.as_deref().map(matches) != Some(false) =>
+            {
+                moves.push((false, start.id))
+            }
+            ParagraphContent::MoveToRangeStart(start)
+                if start.author.as_deref().map(matches) != Some(false) =>
+            {
+                moves.push((true, start.id))
+            }
+            ParagraphContent::Sdt(sdt) => collect_moves(&sdt.content.content, matches, moves),
+            ParagraphContent::Insertion(ins) => collect_moves(&ins.content, matches, moves),
+            ParagraphContent::Deletion(del) => collect_moves(&del.content, matches, moves),
+            ParagraphContent::MoveFrom(from) => collect_moves(&from.content, matches, moves),
+            ParagraphContent::MoveTo(to) => collect_moves(&to.content, matches, moves),
+            _ => (),
+        }
+    }
+}
+
+fn resolve_paragraph<F>(para: &mut Paragraph, accept: bool, matches: &F, moves: &[MoveRange])
+where
+    F: Fn(&str) -> bool,
+{
+    let property = &mut para.property;
+    if matches!(&property.change, Some(change) if matches(&change.author)) {
+        let change = property.change.take().unwrap();
+        if !accept {
+            // section properties and the tracked change of the paragraph
+            // mark aren't part of the recorded properties
+            let mut original = *change.property;
+            original.mark = property.mark.take();
+            original.section_property = property.section_property.take();
+            *property = original;
+        }
+    }
+
+    let content = std::mem::take(&mut para.content);
+    para.content = resolve_content(content, accept, matches, moves);
+}
+
+fn resolve_content<'a, F>(
+    content: Vec<ParagraphContent<'a>>,
+    accept: bool,
+    matches: &F,
+    moves: &[MoveRange],
+) -> Vec<ParagraphContent<'a>>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut resolved = Vec::with_capacity(content.len());
+
+    for content in content {
+        match content {
+            ParagraphContent::Insertion(ins) if matches(&ins.author) => {
+                if accept {
+                    resolved.extend(resolve_content(ins.content, accept, matches, moves));
+                }
+            }
+            ParagraphContent::MoveTo(to) if matches(&to.author) => {
+                if accept {
+                    resolved.extend(resolve_content(to.content, accept, matches, moves));
+                }
+            }
+            ParagraphContent::Deletion(del) if matches(&del.author) => {
+                if !accept {
+                    let content = resolve_content(del.content, accept, matches, moves);
+                    resolved.extend(restore_deleted(content));
+                }
+            }
+            ParagraphContent::MoveFrom(from) if matches(&from.author) => {
+                if !accept {
+                    let content = resolve_content(from.content, accept, matches, moves);
+                    resolved.extend(restore_deleted(content));
+                }
+            }
+            ParagraphContent::MoveFromRangeStart(start) if moves.contains(&(false, start.id)) => (),
+            ParagraphContent::MoveFromRangeEnd(end) if moves.contains(&(false, end.id)) => (),
+            ParagraphContent::MoveToRangeStart(start) if moves.contains(&(true, start.id)) => (),
+            ParagraphContent::MoveToRangeEnd(end) if moves.contains(&(true, end.id)) => (),
+            ParagraphContent::Insertion(mut ins) => {
+                ins.content = resolve_content(ins.content, accept, matches, moves);
+                resolved.push(ins.into());
+            }
+            ParagraphContent::Deletion(mut del) => {
+                del.content = resolve_content(del.content, accept, matches, moves);
+                resolved.push(del.into());
+            }
+            ParagraphContent::MoveFrom(mut from) => {
+                from.content = resolve_content(from.content, accept, matches, moves);
+                resolved.push(from.into());
+            }
+            ParagraphContent::MoveTo(mut to) => {
+                to.content = resolve_content(to.content, accept, matches, moves);
+                resolved.push(to.into());
+            }
+            ParagraphContent::Sdt(mut sdt) => {
+                let content = std::mem::take(&mut sdt.content.content);
+                sdt.content.content = resolve_content(content, accept, matches, moves);
+                resolved.push(sdt.into());
+            }
+            ParagraphContent::Run(mut run) => {
+                resolve_run(&mut run, accept, matches);
+                resolved.push(run.into());
+            }
+            ParagraphContent::Link(mut link) => {
+                resolve_run(&mut link.content, accept, matches);
+                resolved.push(link.into());
+            }
+            content => resolved.push(content),
+        }
+    }
+
+    resolved
+}
+
+fn resolve_run<F>(run: &mut Run, accept: bool, matches: &F)
+where
+    F: Fn(&str) -> bool,
+{
+    if matches!(&run.property.change, Some(change) if matches(&change.author)) {
+        let change = run.property.change.take().unwrap();
+        if !accept {
+            run.property = *change.property;
+        }
+    }
+}
+
+/// Removes inserted or deleted table rows and paragraph marks
+fn resolve_structure<F>(content: &mut Vec<BodyContent>, accept: bool, matches: &F)
+where
+    F: Fn(&str) -> bool,
+{
+    let mut emptied = false;
+
+    for content in content.iter_mut() {
+        match content {
+            BodyContent::Table(table) => {
+                resolve_table(table, accept, matches);
+                emptied |= table.rows.is_empty();
+            }
+            BodyContent::Sdt(sdt) => resolve_structure(&mut sdt.content.content, accept, matches),
+            _ => (),
+        }
+    }
+
+    // tables whose rows are all removed are removed as well
+    if emptied {
+        content.retain(
+            |content| !matches!(content, BodyContent::Table(table) if table.rows.is_empty()),
+        );
+    }
+
+    merge_paragraphs(content, accept, matches, |content| match content {
+        BodyContent::Paragraph(para) => Some(para),
+        _ => None,
+    });
+}
+
+/// Removes inserted or deleted rows of the table and of nested tables, and
+/// paragraph marks in the cells
+fn resolve_table<F>(table: &mut Table, accept: bool, matches: &F)
+where
+    F: Fn(&str) -> bool,
+{
+    let rows = std::mem::take(&mut table.rows);
+    for mut row in rows {
+        let property = &mut row.property;
+        if resolve_removal(
+            &mut property.insertion,
+            &mut property.deletion,
+            accept,
+            matches,
+        ) {
+            continue;
+        }
+        for cell in &mut row.cells {
+            let mut emptied = false;
+            for content in &mut cell.content {
+                if let TableCellContent::Table(table) = content {
+                    resolve_table(table, accept, matches);
+                    emptied |= table.rows.is_empty();
+                }
+            }
+            if emptied {
+                cell.content.retain(|content| {
+                    !matches!(content, TableCellContent::Table(table) if table.rows.is_empty())
+                });
+            }
+
+            merge_paragraphs(
+                &mut cell.content,
+                accept,
+                matches,
+                |content| match content {
+                    TableCellContent::Paragraph(para) => Some(para),
+                    _ => None,
+                },
+            );
+        }
+        table.rows.push(row);
+    }
+}
+
+/// Merges paragraphs whose marks are removed with the following paragraphs
+fn merge_paragraphs<'a, T, F, P>(content: &mut Vec<T>, accept: bool, matches: &F, as_paragraph: P)
+where
+    F: Fn(&str) -> bool,
+    P: Fn(&mut T) -> Option<&mut Paragraph<'a>>,
+{
+    let mut index = 0;
+
+    while index < content.len() {
+        let (removed, empty) = match as_paragraph(&mut content[index]) {
+            Some(para) => match &mut para.property.mark {
+                Some(mark) => {
+                    let removed =
+                        resolve_removal(&mut mark.insertion, &mut mark.deletion, accept, matches);
+                    if mark.insertion.is_none() && mark.deletion.is_none() {
+                        para.property.mark = None;
+                    }
+                    (removed, para.content.is_empty())
+                }
+                None => (false, false),
+            },
+            None => (false, false),
+        };
+
+        let merged = index + 1 < content.len() && as_paragraph(&mut content[index + 1]).is_some();
+
+        // paragraphs which can't be merged are kept, unless they're empty
+        // and not the only content
+        if removed && (merged || (empty && content.len() > 1)) {
+            let mut removed = content.remove(index);
+            if merged {
+                if let (Some(removed), Some(next)) = (
+                    as_paragraph(&mut removed),
+                    as_paragraph(&mut content[index]),
+                ) {
+                    let removed = std::mem::take(&mut removed.content);
+                    next.content.splice(0..0, removed);
+                }
+            }
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// Clears the tracked changes made by the matched author, returns if the
+/// element is removed
+fn resolve_removal<F>(
+    insertion: &mut Option<Insertion>,
+    deletion: &mut Option<Deletion>,
+    accept: bool,
+    matches: &F,
+) -> bool
+where
+    F: Fn(&str) -> bool,
+{
+    let mut removed = false;
+
+    if matches!(insertion, Some(ins) if matches(&ins.author)) {
+        *insertion = None;
+        removed |= !accept;
+    }
+
+    if matches!(deletion, Some(del) if matches(&del.author)) {
+        *deletion = None;
+        removed |= accept;
+    }
+
+    removed
+}
+
+/// Turns deleted text back into text
+fn restore_deleted(mut content: Vec<ParagraphContent>) -> Vec<ParagraphContent> {
+    fn restore_run(run: &mut Run) {
+        for content in &mut run.content {
+            match content {
+                RunContent::DeletedText(deleted) => {
+                    *content = RunContent::Text(Text {
+                        space: deleted.space.take(),
+                        text: std::mem::take(&mut deleted.text),
+                    });
+                }
+                RunContent::DeletedInstrText(deleted) => {
+                    *content = RunContent::InstrText(InstrText {
+                        space: deleted.space.take(),
+                        text: std::mem::take(&mut deleted.text),
+                    });
+                }
+                _ => (),
+            }
+        }
+    }
+
+    for content in &mut content {
+        match content {
+            ParagraphContent::Run(run) => restore_run(run),
+            ParagraphContent::Link(link) => restore_run(&mut link.content),
+            ParagraphContent::Sdt(sdt) => {
+                let inner = std::mem::take(&mut sdt.content.content);
+                sdt.content.content = restore_deleted(inner);
+            }
+            _ => (),
+        }
+    }
+
+    content
+}
+
+/// Collects the text of each paragraph in `content`, including nested ones
+#[cfg(test)]
+pub(crate) fn paragraph_texts(content: &[BodyContent], view: RevisionView) -> Vec<String> {
+    let mut texts = Vec::new();
+    for_each_paragraph(content, &mut |para| {
+        texts.push(para.iter_text_in(view).map(|t| &**t).collect())
+    });
+    texts
+}
+
+#[test]
+fn tracked_changes() {
+    use crate::document::{
+        DeletedText, MoveFrom, MoveFromRangeEnd, MoveFromRangeStart, MoveTo, MoveToRangeEnd,
+        MoveToRangeStart,
+    };
+    use crate::formatting::{CharacterProperty, CharacterPropertyChange};
+    use crate::Docx;
+
+    fn docx() -> Docx<'static> {
+        let bold = CharacterProperty::default()
+            .bold(true)
+            .change(CharacterPropertyChange::new(
+                3,
+                "bar",
+                CharacterProperty::default(),
+            ));
+
+        let mut docx = Docx::default();
+        docx.document.push(
+            Paragraph::default()
+                .push_text("Lorem ")
+                .push(Insertion::new(0, "foo").push(Run::default().push_text("ipsum ")))
+                .push(
+                    Deletion::new(1, "bar").push(Run::default().push(DeletedText::from("dolor "))),
+                )
+                .push(MoveFromRangeStart::default().name("move").author("foo"))
+                .push(MoveFrom::new(2, "foo").push(Run::default().push(DeletedText::from("sit "))))
+                .push(MoveFromRangeEnd::default())
+                .push(Run::default().property(bold).push_text("amet "))
+                .push(MoveToRangeStart::default().name("move").author("foo"))
+                .push(MoveTo::new(4, "foo").push(Run::default().push_text("sit")))
+                .push(MoveToRangeEnd::default()),
+        );
+        docx
+    }
+
+    fn paragraph<'b>(docx: &'b Docx<'static>) -> &'b Paragraph<'static> {
+        match &docx.document.body.content[0] {
+            BodyContent::Paragraph(para) => para,
+            _ => panic!("expected a paragraph"),
+        }
+    }
+
+    fn text(docx: &Docx<'static>, view: RevisionView) -> String {
+        paragraph_texts(&docx.document.body.content, view).concat()
+    }
+
+    fn bold(docx: &Docx<'static>) -> bool {
+        paragraph(docx).content.iter().any(|content| match content {
+            ParagraphContent::Run(run) => run.property.bold.is_some(),
+            _ => false,
+        })
+    }
+
+    let original = docx();
+    assert_eq!(
+        text(&original, RevisionView::Accepted),
+        "Lorem ipsum amet sit"
+    );
+    assert_eq!(
+        text(&original, RevisionView::Original),
+        "Lorem dolor sit amet "
+    );
+
+    let mut accepted = docx();
+    accepted.accept_all_revisions();
+    assert_eq!(
+        text(&accepted, RevisionView::Original),
+        "Lorem ipsum amet sit"
+    );
+    assert_eq!(paragraph(&accepted).content.len(), 4);
+    assert!(bold(&accepted));
+
+    let mut rejected = docx();
+    rejected.reject_all_revisions();
+    assert_eq!(
+        text(&rejected, RevisionView::Accepted),
+        "Lorem dolor sit amet "
+    );
+    assert_eq!(paragraph(&rejected).content.len(), 4);
+    assert!(!bold(&rejected));
+
+    let mut by_author = docx();
+    by_author.accept_revisions_by("foo");
+    assert_eq!(
+        text(&by_author, RevisionView::Original),
+        "Lorem ipsum dolor amet sit"
+    );
+    assert_eq!(paragraph(&by_author).content.len(), 5);
+    by_author.reject_revisions_by("bar");
+    assert_eq!(
+        text(&by_author, RevisionView::Accepted),
+        "Lorem ipsum dolor amet sit"
+    );
+    assert!(!bold(&by_author));
+}
+
+#[test]
+fn tracked_structure() {
+    use crate::document::TableRow;
+    use crate::formatting::{ParagraphMarkProperty, ParagraphProperty, TableRowProperty};
+    use crate::Docx;
+
+    fn docx() -> Docx<'static> {
+        let mark = ParagraphMarkProperty::default().insertion(Insertion::new(0, "foo"));
+        let row = TableRowProperty::default().deletion(Deletion::new(1, "bar"));
+
+        let mut docx = Docx::default();
+        docx.document
+            .push(
+                Paragraph::default()
+                    .property(ParagraphProperty::default().mark(mark))
+                    .push_text("Lorem "),
+            )
+            .push(Paragraph::default().push_text("ipsum"))
+            .push(
+                Table::default()
+                    .push_row(TableRow::default().push_cell(Paragraph::default().push_text("a")))
+                    .push_row(
+                        TableRow::default()
+                            .property(row)
+                            .push_cell(Paragraph::default().push_text("b")),
+                    ),
+            );
+        docx
+    }
+
+    fn texts(docx: &Docx<'static>) -> Vec<String> {
+        paragraph_texts(&docx.document.body.content, RevisionView::Original)
+    }
+
+    let mut accepted = docx();
+    accepted.accept_all_revisions();
+    assert_eq!(texts(&accepted), ["Lorem ", "ipsum", "a"]);
+    match &accepted.document.body.content[0] {
+        BodyContent::Paragraph(para) => assert!(para.property.mark.is_none()),
+        _ => panic!("expected a paragraph"),
+    }
+
+    let mut rejected = docx();
+    rejected.reject_all_revisions();
+    assert_eq!(texts(&rejected), ["Lorem ipsum", "a", "b"]);
+
+    let mut by_author = docx();
+    by_author.reject_revisions_by("foo");
+    by_author.accept_revisions_by("bar");
+    assert_eq!(texts(&by_author), ["Lorem ipsum", "a"]);
+}
@@ -1,10 +1,15 @@
 use derive_more::From;
 use std::borrow::Cow;
-use strong_xml::{XmlRead, XmlWrite};
+use strong_xml::{XmlRead, XmlReader, XmlResult, XmlWrite};
 
 use crate::{
     __setter, __xml_test_suites,
-    document::{r#break::Break, text::Text},
+    document::{
+        r#break::Break, text::Text, AlternateContent, AnnotationRef, CommentReference,
+        ContinuationSeparator, DeletedInstrText, DeletedText, Drawing, EndnoteRef,
+        EndnoteReference, FieldChar, FootnoteRef, FootnoteReference, InstrText, Pict, RevisionView,
+        Separator, TrackChanges,
+    },
     formatting::CharacterProperty,
 };
 
@@ -23,7 +28,7 @@ use crate::{
 ///     .push_text((" text ", TextSpace::Preserve))
 ///     .push_break(BreakType::Column);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[derive(Debug, Default, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:r")]
 pub struct Run<'a> {
@@ -32,7 +37,25 @@ pub struct Run<'a> {
     /// Just as paragraph, a run's properties is applied to all the contents of the run.
     #[xml(default, child = "w:rPr")]
     pub property: CharacterProperty<'a>,
-    #[xml(child = "w:t", child = "w:br")]
+    #[xml(
+        child = "w:t",
+        child = "w:br",
+        child = "w:delText",
+        child = "w:footnoteReference",
+        child = "w:endnoteReference",
+        child = "w:footnoteRef",
+        child = "w:endnoteRef",
+        child = "w:separator",
+        child = "w:continuationSeparator",
+        child = "w:commentReference",
+        child = "w:annotationRef",
+        child = "w:drawing",
+        child = "mc:AlternateContent",
+        child = "w:pict",
+        child = "w:fldChar",
+        child = "w:instrText",
+        child = "w:delInstrText"
+    )]
     /// Specifies the content of a run
     pub content: Vec<RunContent<'a>>,
 }
@@ -58,29 +81,193 @@ impl<'a> Run<'a> {
         self
     }
 
+    /// Replaces the properties of the run as a tracked change
+    pub fn set_property_tracked(
+        &mut self,
+        property: CharacterProperty<'a>,
+        changes: &mut TrackChanges<'a>,
+    ) -> &mut Self {
+        changes.set_property(self, property);
+        self
+    }
+
+    /// Appends a reference to the footnote with the given id
+    ///
+    /// Footnotes are stored in their own part, so adding one takes two
+    /// steps: `Docx::add_footnote` adds the note and allocates its id, which
+    /// is then passed in here.
+    ///
+    /// ```rust
+    /// use docx::document::*;
+    /// use docx::formatting::*;
+    /// use docx::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let id = docx.add_footnote(vec![Paragraph::default().push_text("See the appendix.")]);
+    ///
+    /// let run = Run::default()
+    ///     .property(CharacterProperty::default().vertical_align(VerticalAlignVal::Superscript))
+    ///     .push_footnote(id);
+    /// docx.document.push(Paragraph::default().push_text("Lorem ipsum").push(run));
+    /// ```
+    #[inline(always)]
+    pub fn push_footnote(mut self, id: isize) -> Self {
+        self.content
+            .push(RunContent::FootnoteReference(FootnoteReference { id }));
+        self
+    }
+
+    /// Appends a reference to the endnote with the given id
+    ///
+    /// Like footnotes, the id is allocated by `Docx::add_endnote`.
+    #[inline(always)]
+    pub fn push_endnote(mut self, id: isize) -> Self {
+        self.content
+            .push(RunContent::EndnoteReference(EndnoteReference { id }));
+        self
+    }
+
+    /// Iterates over the text, including the text in text boxes of shapes
+    ///
+    /// Tracked changes are viewed as accepted.
     pub fn iter_text(&self) -> impl Iterator<Item = &Cow<'a, str>> {
-        self.content.iter().filter_map(|content| match content {
-            RunContent::Text(Text { text, .. }) => Some(text),
-            RunContent::Break(_) => None,
-        })
+        self.iter_text_in(RevisionView::Accepted)
     }
 
+    /// Iterates over the text as it is when tracked changes are accepted or
+    /// rejected
+    ///
+    /// Deleted text is only in the original view. The text in text boxes of
+    /// shapes is included, while their fallbacks are skipped.
+    pub fn iter_text_in(&self, view: RevisionView) -> impl Iterator<Item = &Cow<'a, str>> {
+        self.content.iter().flat_map(
+            move |content| -> Box<dyn Iterator<Item = &Cow<'a, str>> + '_> {
+                match content {
+                    RunContent::Text(Text { text, .. }) => Box::new(std::iter::once(text)),
+                    RunContent::DeletedText(DeletedText { text, .. })
+                        if view == RevisionView::Original =>
+                    {
+                        Box::new(std::iter::once(text))
+                    }
+                    RunContent::Drawing(drawing) => drawing.iter_text_in(view),
+                    RunContent::AlternateContent(content) => match content.drawing() {
+                        Some(drawing) => drawing.iter_text_in(view),
+                        None => Box::new(std::iter::empty()),
+                    },
+                    _ => Box::new(std::iter::empty()),
+                }
+            },
+        )
+    }
+
+    /// Iterates over the text, including the text in text boxes of shapes
+    ///
+    /// The fallbacks of shapes are left as they are.
     pub fn iter_text_mut(&mut self) -> impl Iterator<Item = &mut Cow<'a, str>> {
-        self.content.iter_mut().filter_map(|content| match content {
-            RunContent::Text(Text { text, .. }) => Some(text),
-            RunContent::Break(_) => None,
-        })
+        self.content.iter_mut().flat_map(
+            |content| -> Box<dyn Iterator<Item = &mut Cow<'a, str>> + '_> {
+                match content {
+                    RunContent::Text(Text { text, .. }) => Box::new(std::iter::once(text)),
+                    RunContent::Drawing(drawing) => drawing.iter_text_mut(),
+                    RunContent::AlternateContent(content) => match content.drawing_mut() {
+                        Some(drawing) => drawing.iter_text_mut(),
+                        None => Box::new(std::iter::empty()),
+                    },
+                    _ => Box::new(std::iter::empty()),
+                }
+            },
+        )
     }
 }
 
+impl<'i: 'a, 'a> XmlRead<'i> for Run<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let RawRun {
+            property,
+            mut content,
+        } = RawRun::from_reader(reader)?;
+
+        // drawings whose graphic data isn't modeled, e.g. SmartArt or group
+        // shapes, would be written back without their content, so they're
+        // skipped instead
+        content.retain(|content| match content {
+            RunContent::Drawing(drawing) => drawing.is_known(),
+            RunContent::AlternateContent(content) => content.is_known(),
+            _ => true,
+        });
+
+        Ok(Run { property, content })
+    }
+}
+
+// standalone VML objects, e.g. watermarks and legacy pictures, aren't fully
+// modeled, so they're skipped, while the ones in fallbacks of shapes are read
+#[derive(XmlRead)]
+#[xml(tag = "w:r")]
+struct RawRun<'a> {
+    #[xml(default, child = "w:rPr")]
+    property: CharacterProperty<'a>,
+    #[xml(
+        child = "w:t",
+        child = "w:br",
+        child = "w:delText",
+        child = "w:footnoteReference",
+        child = "w:endnoteReference",
+        child = "w:footnoteRef",
+        child = "w:endnoteRef",
+        child = "w:separator",
+        child = "w:continuationSeparator",
+        child = "w:commentReference",
+        child = "w:annotationRef",
+        child = "w:drawing",
+        child = "mc:AlternateContent",
+        child = "w:fldChar",
+        child = "w:instrText",
+        child = "w:delInstrText"
+    )]
+    content: Vec<RunContent<'a>>,
+}
+
 /// A set of elements that can be contained as the content of a run.
 #[derive(Debug, From, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
+// drawings are kept unboxed, so they're matched and built like other content
+#[allow(clippy::large_enum_variant)]
 pub enum RunContent<'a> {
     #[xml(tag = "w:t")]
     Text(Text<'a>),
     #[xml(tag = "w:br")]
     Break(Break),
+    #[xml(tag = "w:delText")]
+    DeletedText(DeletedText<'a>),
+    #[xml(tag = "w:footnoteReference")]
+    FootnoteReference(FootnoteReference),
+    #[xml(tag = "w:endnoteReference")]
+    EndnoteReference(EndnoteReference),
+    #[xml(tag = "w:footnoteRef")]
+    FootnoteRef(FootnoteRef),
+    #[xml(tag = "w:endnoteRef")]
+    EndnoteRef(EndnoteRef),
+    #[xml(tag = "w:separator")]
+    Separator(Separator),
+    #[xml(tag = "w:continuationSeparator")]
+    ContinuationSeparator(ContinuationSeparator),
+    #[xml(tag = "w:commentReference")]
+    CommentReference(CommentReference),
+    #[xml(tag = "w:annotationRef")]
+    AnnotationRef(AnnotationRef),
+    #[xml(tag = "w:drawing")]
+    Drawing(Drawing<'a>),
+    #[xml(tag = "mc:AlternateContent")]
+    AlternateContent(AlternateContent<'a>),
+    #[xml(tag = "w:pict")]
+    Pict(Pict<'a>),
+    #[xml(tag = "w:fldChar")]
+    FieldChar(FieldChar<'a>),
+    #[xml(tag = "w:instrText")]
+    InstrText(InstrText<'a>),
+    #[xml(tag = "w:delInstrText")]
+    DeletedInstrText(DeletedInstrText<'a>),
 }
 
 __xml_test_suites!(
@@ -91,4 +278,56 @@ __xml_test_suites!(
     r#"<w:r><w:rPr/><w:br/></w:r>"#,
     Run::default().push_text("text"),
     r#"<w:r><w:rPr/><w:t>text</w:t></w:r>"#,
+    Run::default().push_footnote(2).push_endnote(3),
+    r#"<w:r><w:rPr/><w:footnoteReference w:id="2"/><w:endnoteReference w:id="3"/></w:r>"#,
+    Run::default().push(Separator::default()),
+    r#"<w:r><w:rPr/><w:separator/></w:r>"#,
+    Run::default().push(CommentReference::from(0)),
+    r#"<w:r><w:rPr/><w:commentReference w:id="0"/></w:r>"#,
 );
+
+#[test]
+fn text_box_text() -> strong_xml::XmlResult<()> {
+    // as written by Word, with the text box in both the shape and the fallback
+    let xml = r##"<w:r><w:rPr/><w:t>See </w:t><mc:AlternateContent><mc:Choice Requires="wps"><w:drawing><wp:anchor distT="0" distB="0" distL="114300" distR="114300" simplePos="0" relativeHeight="251659264" behindDoc="0" locked="0" layoutInCell="1" allowOverlap="1"><wp:simplePos x="0" y="0"/><wp:positionH relativeFrom="column"><wp:posOffset>0</wp:posOffset></wp:positionH><wp:positionV relativeFrom="paragraph"><wp:posOffset>0</wp:posOffset></wp:positionV><wp:extent cx="1828800" cy="457200"/><wp:wrapSquare wrapText="bothSides"/><wp:docPr id="1" name="Text Box 1"/><a:graphic><a:graphicData uri="http://schemas.microsoft.com/office/word/2010/wordprocessingShape"><wps:wsp><wps:cNvSpPr txBox="1"/><wps:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="1828800" cy="457200"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:solidFill><a:schemeClr val="lt1"/></a:solidFill><a:ln w="6350"><a:solidFill><a:prstClr val="black"/></a:solidFill></a:ln></wps:spPr><wps:style/><wps:txbx><w:txbxContent><w:p><w:r><w:t>the box</w:t></w:r></w:p><w:tbl><w:tr><w:tc><w:p><w:r><w:t>, the cell</w:t></w:r></w:p></w:tc></w:tr></w:tbl></w:txbxContent></wps:txbx><wps:bodyPr rot="0" vert="horz" wrap="square" lIns="91440" tIns="45720" rIns="91440" bIns="45720" anchor="t" anchorCtr="0"><a:noAutofit/></wps:bodyPr></wps:wsp></a:graphicData></a:graphic></wp:anchor></w:drawing></mc:Choice><mc:Fallback><w:pict><v:shape id="Text Box 1" type="#_x0000_t202" style="position:absolute;width:2in;height:36pt" fillcolor="white [3201]" strokeweight=".5pt"><v:textbox><w:txbxContent><w:p><w:r><w:t>the box</w:t></w:r></w:p></w:txbxContent></v:textbox></v:shape></w:pict></mc:Fallback></mc:AlternateContent></w:r>"##;
+
+    let mut run = Run::from_str(xml)?;
+    assert_eq!(
+        run.iter_text().collect::<Vec<_>>(),
+        ["See ", "the box", ", the cell"]
+    );
+
+    let content = match &run.content[1] {
+        RunContent::AlternateContent(content) => content,
+        _ => panic!("shapes are read as alternate content"),
+    };
+    let shape = content.drawing().unwrap().shape().unwrap();
+    assert_eq!(shape.geometry(), Some("rect"));
+    assert_eq!(
+        shape.body_property.anchor,
+        Some(crate::drawing::TextAnchor::Top)
+    );
+    assert!(content.fallback.as_ref().unwrap().pict.is_some());
+
+    for text in run.iter_text_mut() {
+        *text = text.to_uppercase().into();
+    }
+    assert_eq!(
+        run.iter_text().collect::<Vec<_>>(),
+        ["SEE ", "THE BOX", ", THE CELL"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn skipped_drawings() -> strong_xml::XmlResult<()> {
+    // a group shape, a SmartArt diagram and a watermark
+    let xml = r##"<w:r><w:t>foo</w:t><mc:AlternateContent><mc:Choice Requires="wpg"><w:drawing><wp:inline><wp:extent cx="914400" cy="914400"/><wp:docPr id="1" name="Group 1"/><a:graphic><a:graphicData uri="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup"><wpg:wgp><wpg:cNvGrpSpPr/><wpg:grpSpPr/></wpg:wgp></a:graphicData></a:graphic></wp:inline></w:drawing></mc:Choice><mc:Fallback><w:pict><v:group style="width:72pt;height:72pt"/></w:pict></mc:Fallback></mc:AlternateContent><w:drawing><wp:inline><wp:extent cx="914400" cy="914400"/><wp:docPr id="2" name="Diagram 2"/><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/diagram"><dgm:relIds r:dm="rId4" r:lo="rId5" r:qs="rId6" r:cs="rId7"/></a:graphicData></a:graphic></wp:inline></w:drawing><w:pict><v:shapetype id="_x0000_t136" coordsize="21600,21600"/><v:shape id="PowerPlusWaterMarkObject" type="#_x0000_t136" style="width:412pt;height:165pt"><v:textpath string="DRAFT"/></v:shape></w:pict><w:t>bar</w:t></w:r>"##;
+
+    let run = Run::from_str(xml)?;
+    assert_eq!(run.content.len(), 2);
+    assert_eq!(run.iter_text().collect::<Vec<_>>(), ["foo", "bar"]);
+
+    Ok(())
+}
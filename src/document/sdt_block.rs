@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    document::{
+        sdt_run::{push_text, take_run_property, text_of},
+        BodyContent, Paragraph, SdtProperty, Table,
+    },
+};
+
+/// Block-Level Structured Document Tag
+///
+/// A content control around paragraphs and tables.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let mut sdt = SdtBlock::default()
+///     .property(SdtProperty::default().tag("summary"))
+///     .push(Paragraph::default().push_text("Lorem Ipsum"));
+///
+/// assert_eq!(sdt.text(), "Lorem Ipsum");
+///
+/// sdt.set_text("dolor sit amet");
+/// assert_eq!(sdt.text(), "dolor sit amet");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdt")]
+pub struct SdtBlock<'a> {
+    /// Specifies the properties of the control.
+    #[xml(default, child = "w:sdtPr")]
+    pub property: SdtProperty<'a>,
+    /// Specifies the content of the control.
+    #[xml(default, child = "w:sdtContent")]
+    pub content: SdtContentBlock<'a>,
+}
+
+impl<'a> SdtBlock<'a> {
+    __setter!(property: SdtProperty<'a>);
+
+    #[inline(always)]
+    pub fn push<T: Into<BodyContent<'a>>>(mut self, content: T) -> Self {
+        self.content.content.push(content.into());
+        self
+    }
+
+    /// Returns the text of the paragraphs in the control, separated by line feeds
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for (i, para) in self
+            .content
+            .content
+            .iter()
+            .filter_map(|content| match content {
+                BodyContent::Paragraph(para) => Some(para),
+                _ => None,
+            })
+            .enumerate()
+        {
+            if i > 0 {
+                text.push('\n');
+            }
+            text_of(&para.content, &mut text);
+        }
+        text
+    }
+
+    /// Replaces the content of the control with a single paragraph of text
+    ///
+    /// The paragraph keeps the properties of the first paragraph and of its
+    /// first run. Line feeds in the text become line breaks.
+    pub fn set_text<T: Into<Cow<'a, str>>>(&mut self, text: T) {
+        let mut para = self
+            .content
+            .content
+            .drain(..)
+            .find_map(|content| match content {
+                BodyContent::Paragraph(para) => Some(para),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let property = take_run_property(&mut para.content);
+        para.content.clear();
+        push_text(&mut para.content, property, text.into());
+
+        self.content.content.push(para.into());
+    }
+}
+
+/// Block-Level Structured Document Tag Content
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdtContent")]
+pub struct SdtContentBlock<'a> {
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
+    pub content: Vec<BodyContent<'a>>,
+}
+
+impl<'a> From<Paragraph<'a>> for SdtContentBlock<'a> {
+    fn from(para: Paragraph<'a>) -> Self {
+        SdtContentBlock {
+            content: vec![para.into()],
+        }
+    }
+}
+
+impl<'a> From<Table<'a>> for SdtContentBlock<'a> {
+    fn from(table: Table<'a>) -> Self {
+        SdtContentBlock {
+            content: vec![table.into()],
+        }
+    }
+}
+
+__xml_test_suites!(
+    SdtBlock,
+    SdtBlock::default(),
+    r#"<w:sdt><w:sdtPr/><w:sdtContent/></w:sdt>"#,
+    SdtBlock::default().push(Paragraph::default()),
+    r#"<w:sdt><w:sdtPr/><w:sdtContent><w:p><w:pPr/></w:p></w:sdtContent></w:sdt>"#,
+    SdtBlock::default().push(SdtBlock::default()),
+    r#"<w:sdt><w:sdtPr/><w:sdtContent><w:sdt><w:sdtPr/><w:sdtContent/></w:sdt></w:sdtContent></w:sdt>"#,
+);
+
+#[test]
+fn set_text() {
+    use crate::document::Run;
+    use crate::formatting::CharacterProperty;
+
+    let mut sdt = SdtBlock::default()
+        .push(
+            Paragraph::default().push(
+                Run::default()
+                    .property(CharacterProperty::default().bold(true))
+                    .push_text("foo"),
+            ),
+        )
+        .push(Paragraph::default().push_text("bar"));
+    assert_eq!(sdt.text(), "foo\nbar");
+
+    sdt.set_text("baz\nqux");
+    assert_eq!(sdt.text(), "baz\nqux");
+    assert_eq!(sdt.content.content.len(), 1);
+    assert_eq!(
+        sdt.to_string().unwrap(),
+        r#"<w:sdt><w:sdtPr/><w:sdtContent><w:p><w:pPr/><w:r><w:rPr><w:b w:val="true"/></w:rPr><w:t>baz</w:t><w:br/><w:t>qux</w:t></w:r></w:p></w:sdtContent></w:sdt>"#
+    );
+}
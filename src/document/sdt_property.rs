@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, document::DataBinding};
+
+/// Structured Document Tag Properties
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let prop = SdtProperty::default()
+///     .alias("Client Name")
+///     .tag("client")
+///     .id(1)
+///     .data_binding(DataBinding::new("/contract[1]/client[1]", "{0}"))
+///     .text(SdtText::default());
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdtPr")]
+pub struct SdtProperty<'a> {
+    /// Specifies the friendly name of the control.
+    #[xml(child = "w:alias")]
+    pub alias: Option<SdtAlias<'a>>,
+    /// Specifies a programmatic tag of the control.
+    #[xml(child = "w:tag")]
+    pub tag: Option<SdtTag<'a>>,
+    /// Specifies a unique identifier of the control.
+    #[xml(child = "w:id")]
+    pub id: Option<SdtId>,
+    /// Specifies the XML mapping of the control.
+    #[xml(child = "w:dataBinding")]
+    pub data_binding: Option<DataBinding<'a>>,
+    /// Specifies that the control is a plain text control.
+    #[xml(child = "w:text")]
+    pub text: Option<SdtText>,
+}
+
+impl<'a> SdtProperty<'a> {
+    __setter!(alias: Option<SdtAlias<'a>>);
+    __setter!(tag: Option<SdtTag<'a>>);
+    __setter!(id: Option<SdtId>);
+    __setter!(data_binding: Option<DataBinding<'a>>);
+    __setter!(text: Option<SdtText>);
+}
+
+/// Friendly Name of a structured document tag
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:alias")]
+pub struct SdtAlias<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for SdtAlias<'a> {
+    fn from(val: T) -> Self {
+        SdtAlias { value: val.into() }
+    }
+}
+
+/// Programmatic Tag of a structured document tag
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:tag")]
+pub struct SdtTag<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for SdtTag<'a> {
+    fn from(val: T) -> Self {
+        SdtTag { value: val.into() }
+    }
+}
+
+/// Unique Identifier of a structured document tag
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:id")]
+pub struct SdtId {
+    #[xml(attr = "w:val")]
+    pub value: i32,
+}
+
+impl<T: Into<i32>> From<T> for SdtId {
+    fn from(val: T) -> Self {
+        SdtId { value: val.into() }
+    }
+}
+
+/// Plain Text Structured Document Tag
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:text")]
+pub struct SdtText {
+    /// Specifies whether soft line breaks are allowed.
+    #[xml(attr = "w:multiLine")]
+    pub multi_line: Option<bool>,
+}
+
+impl SdtText {
+    __setter!(multi_line: Option<bool>);
+}
+
+__xml_test_suites!(
+    SdtProperty,
+    SdtProperty::default(),
+    r#"<w:sdtPr/>"#,
+    SdtProperty::default().alias("Name").tag("name").id(-42),
+    r#"<w:sdtPr><w:alias w:val="Name"/><w:tag w:val="name"/><w:id w:val="-42"/></w:sdtPr>"#,
+    SdtProperty::default()
+        .data_binding(DataBinding::new("/root[1]", "{0}"))
+        .text(SdtText::default().multi_line(true)),
+    r#"<w:sdtPr><w:dataBinding w:xpath="/root[1]" w:storeItemID="{0}"/><w:text w:multiLine="true"/></w:sdtPr>"#,
+);
@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    document::{Break, ParagraphContent, Run, RunContent, SdtProperty, Text, TextSpace},
+    formatting::CharacterProperty,
+};
+
+/// Inline-Level Structured Document Tag
+///
+/// A content control around runs inside a paragraph.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let mut sdt = SdtRun::default()
+///     .property(SdtProperty::default().tag("client").text(SdtText::default()))
+///     .push(Run::default().push_text("Client Name"));
+///
+/// sdt.set_text("ACME Corporation");
+/// assert_eq!(sdt.text(), "ACME Corporation");
+///
+/// let para = Paragraph::default().push_text("Client: ").push(sdt);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdt")]
+pub struct SdtRun<'a> {
+    /// Specifies the properties of the control.
+    #[xml(default, child = "w:sdtPr")]
+    pub property: SdtProperty<'a>,
+    /// Specifies the content of the control.
+    #[xml(default, child = "w:sdtContent")]
+    pub content: SdtContentRun<'a>,
+}
+
+impl<'a> SdtRun<'a> {
+    __setter!(property: SdtProperty<'a>);
+
+    #[inline(always)]
+    pub fn push<T: Into<ParagraphContent<'a>>>(mut self, content: T) -> Self {
+        self.content.content.push(content.into());
+        self
+    }
+
+    /// Returns the text in the control
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        text_of(&self.content.content, &mut text);
+        text
+    }
+
+    /// Replaces the content of the control with a single run of text
+    ///
+    /// The run keeps the properties of the first run in the control. Line
+    /// feeds in the text become line breaks.
+    pub fn set_text<T: Into<Cow<'a, str>>>(&mut self, text: T) {
+        let property = take_run_property(&mut self.content.content);
+        self.content.content.clear();
+        push_text(&mut self.content.content, property, text.into());
+    }
+}
+
+/// Inline-Level Structured Document Tag Content
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdtContent")]
+pub struct SdtContentRun<'a> {
+    #[xml(
+        child = "w:r",
+        child = "w:hyperlink",
+        child = "w:bookmarkStart",
+        child = "w:bookmarkEnd",
+        child = "w:permStart",
+        child = "w:permEnd",
+        child = "w:sdt"
+    )]
+    pub content: Vec<ParagraphContent<'a>>,
+}
+
+/// Appends the text of runs, including the ones in hyperlinks and nested
+/// controls, with line breaks as line feeds.
+pub(crate) fn text_of(content: &[ParagraphContent], text: &mut String) {
+    fn run_text(run: &Run, text: &mut String) {
+        for content in &run.content {
+            match content {
+                RunContent::Text(t) => text.push_str(&t.text),
+                RunContent::Break(_) => text.push('\n'),
+                _ => (),
+            }
+        }
+    }
+
+    for content in content {
+        match content {
+            ParagraphContent::Run(run) => run_text(run, text),
+            ParagraphContent::Link(link) => run_text(&link.content, text),
+            ParagraphContent::SimpleField(field) => {
+                field.content.iter().for_each(|run| run_text(run, text))
+            }
+            ParagraphContent::Sdt(sdt) => text_of(&sdt.content.content, text),
+            ParagraphContent::Insertion(ins) => text_of(&ins.content, text),
+            ParagraphContent::MoveTo(to) => text_of(&to.content, text),
+            _ => (),
+        }
+    }
+}
+
+/// Takes the properties of the first run
+pub(crate) fn take_run_property<'a>(content: &mut [ParagraphContent<'a>]) -> CharacterProperty<'a> {
+    content
+        .iter_mut()
+        .find_map(|content| match content {
+            ParagraphContent::Run(run) => Some(std::mem::take(&mut run.property)),
+            ParagraphContent::Link(link) => Some(std::mem::take(&mut link.content.property)),
+            ParagraphContent::Sdt(sdt) => Some(take_run_property(&mut sdt.content.content)),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Appends a run of text, with line feeds as line breaks
+pub(crate) fn push_text<'a>(
+    content: &mut Vec<ParagraphContent<'a>>,
+    property: CharacterProperty<'a>,
+    text: Cow<'a, str>,
+) {
+    fn to_text(line: Cow<str>) -> Text {
+        let preserve = line.starts_with(char::is_whitespace) || line.ends_with(char::is_whitespace);
+        Text {
+            text: line,
+            space: if preserve {
+                Some(TextSpace::Preserve)
+            } else {
+                None
+            },
+        }
+    }
+
+    let mut run = Run {
+        property,
+        content: Vec::new(),
+    };
+
+    let lines: Vec<Cow<'a, str>> = match text {
+        Cow::Borrowed(text) => text.split('\n').map(Cow::Borrowed).collect(),
+        Cow::Owned(text) => text
+            .split('\n')
+            .map(|line| line.to_owned().into())
+            .collect(),
+    };
+
+    for (i, line) in lines.into_iter().enumerate() {
+        if i > 0 {
+            run.content.push(RunContent::Break(Break::default()));
+        }
+        if !line.is_empty() {
+            run.content.push(RunContent::Text(to_text(line)));
+        }
+    }
+
+    content.push(run.into());
+}
+
+__xml_test_suites!(
+    SdtRun,
+    SdtRun::default(),
+    r#"<w:sdt><w:sdtPr/><w:sdtContent/></w:sdt>"#,
+    SdtRun::default().push(Run::default()),
+    r#"<w:sdt><w:sdtPr/><w:sdtContent><w:r><w:rPr/></w:r></w:sdtContent></w:sdt>"#,
+);
+
+#[test]
+fn set_text() {
+    let mut sdt = SdtRun::default()
+        .push(Run::default().push_text("foo"))
+        .push(Run::default().push_text("bar"));
+    assert_eq!(sdt.text(), "foobar");
+
+    sdt.set_text(" baz");
+    assert_eq!(sdt.text(), " baz");
+    assert_eq!(
+        sdt.to_string().unwrap(),
+        r#"<w:sdt><w:sdtPr/><w:sdtContent><w:r><w:rPr/><w:t xml:space="preserve"> baz</w:t></w:r></w:sdtContent></w:sdt>"#
+    );
+}
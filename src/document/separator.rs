@@ -0,0 +1,18 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+/// Footnote/Endnote Separator Mark
+///
+/// Displays the line between the main text and the notes.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:separator")]
+pub struct Separator {}
+
+/// Continuation Separator Mark
+///
+/// Displays the line between the main text and the notes continued from the
+/// previous page.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:continuationSeparator")]
+pub struct ContinuationSeparator {}
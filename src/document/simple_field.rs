@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    document::{Field, Run},
+};
+
+/// Simple Field
+///
+/// A field whose instruction is stored in an attribute, with the runs of
+/// its result as children. `Field::into_simple` creates one.
+///
+/// ```rust
+/// use docx::document::*;
+///
+/// let field = SimpleField::new("NUMPAGES").push(Run::default().push_text("1"));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:fldSimple")]
+pub struct SimpleField<'a> {
+    /// Specifies the instruction, e.g. `PAGE \* MERGEFORMAT`.
+    #[xml(attr = "w:instr")]
+    pub instruction: Cow<'a, str>,
+    /// Specifies that the result is outdated and should be updated when the
+    /// document is opened.
+    #[xml(attr = "w:dirty")]
+    pub dirty: Option<bool>,
+    /// Specifies the runs of the result.
+    #[xml(child = "w:r")]
+    pub content: Vec<Run<'a>>,
+}
+
+impl<'a> SimpleField<'a> {
+    __setter!(dirty: Option<bool>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(instruction: T) -> Self {
+        SimpleField {
+            instruction: instruction.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline(always)]
+    pub fn push(mut self, run: Run<'a>) -> Self {
+        self.content.push(run);
+        self
+    }
+}
+
+impl<'a> From<Field<'a>> for SimpleField<'a> {
+    fn from(field: Field<'a>) -> Self {
+        field.into_simple()
+    }
+}
+
+__xml_test_suites!(
+    SimpleField,
+    SimpleField::new("PAGE"),
+    r#"<w:fldSimple w:instr="PAGE"/>"#,
+    SimpleField::new("NUMPAGES")
+        .dirty(true)
+        .push(Run::default().push_text("1")),
+    r#"<w:fldSimple w:instr="NUMPAGES" w:dirty="true"><w:r><w:rPr/><w:t>1</w:t></w:r></w:fldSimple>"#,
+);
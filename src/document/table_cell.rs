@@ -1,7 +1,11 @@
 use derive_more::From;
 use strong_xml::{XmlRead, XmlWrite};
 
-use crate::{__setter, __xml_test_suites, document::Paragraph, formatting::TableCellProperty};
+use crate::{
+    __setter, __xml_test_suites,
+    document::{Paragraph, Table},
+    formatting::TableCellProperty,
+};
 
 /// Table Cell
 ///
@@ -20,7 +24,7 @@ use crate::{__setter, __xml_test_suites, document::Paragraph, formatting::TableC
 pub struct TableCell<'a> {
     #[xml(default, child = "w:tcPr")]
     pub property: TableCellProperty,
-    #[xml(child = "w:p")]
+    #[xml(child = "w:p", child = "w:tbl")]
     pub content: Vec<TableCellContent<'a>>,
 }
 
@@ -46,15 +50,18 @@ impl<'a, T: Into<TableCellContent<'a>>> From<T> for TableCell<'a> {
 
 #[derive(Debug, From, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
+#[allow(clippy::large_enum_variant)]
 pub enum TableCellContent<'a> {
     #[xml(tag = "w:p")]
     Paragraph(Paragraph<'a>),
-    // #[xml(tag = "w:tbl")]
-    // Table(Table<'a>),
+    #[xml(tag = "w:tbl")]
+    Table(Table<'a>),
 }
 
 __xml_test_suites!(
     TableCell,
     TableCell::pargraph(Paragraph::default()),
     "<w:tc><w:tcPr/><w:p><w:pPr/></w:p></w:tc>",
+    TableCell::from(Table::default()),
+    "<w:tc><w:tcPr/><w:tbl><w:tblPr/></w:tbl></w:tc>",
 );
@@ -21,13 +21,13 @@ use crate::{__setter, __xml_test_suites, document::TableCell, formatting::TableR
 #[xml(tag = "w:tr")]
 pub struct TableRow<'a> {
     #[xml(default, child = "w:trPr")]
-    pub property: TableRowProperty,
+    pub property: TableRowProperty<'a>,
     #[xml(child = "w:tc")]
     pub cells: Vec<TableCell<'a>>,
 }
 
 impl<'a> TableRow<'a> {
-    __setter!(property: TableRowProperty);
+    __setter!(property: TableRowProperty<'a>);
 
     pub fn push_cell<T: Into<TableCell<'a>>>(mut self, cell: T) -> Self {
         self.cells.push(cell.into());
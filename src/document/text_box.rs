@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    document::{BodyContent, RevisionView, Table, TableCellContent},
+};
+
+/// Text Box Content
+///
+/// Contains the paragraphs and tables of a text box, either in a DrawingML
+/// shape or in its VML fallback.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:txbxContent")]
+pub struct TextBoxContent<'a> {
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
+    pub content: Vec<BodyContent<'a>>,
+}
+
+impl<'a> TextBoxContent<'a> {
+    pub fn push<T: Into<BodyContent<'a>>>(&mut self, content: T) -> &mut Self {
+        self.content.push(content.into());
+        self
+    }
+
+    /// Iterates over the text in paragraphs and tables, as it is when
+    /// tracked changes are accepted or rejected
+    pub fn iter_text_in(&self, view: RevisionView) -> Box<dyn Iterator<Item = &Cow<'a, str>> + '_> {
+        iter_body_text(&self.content, view)
+    }
+
+    /// Iterates over the text in paragraphs and tables
+    ///
+    /// Tracked changes are viewed as accepted.
+    pub fn iter_text_mut(&mut self) -> Box<dyn Iterator<Item = &mut Cow<'a, str>> + '_> {
+        iter_body_text_mut(&mut self.content)
+    }
+}
+
+fn iter_body_text<'b, 'a>(
+    content: &'b [BodyContent<'a>],
+    view: RevisionView,
+) -> Box<dyn Iterator<Item = &'b Cow<'a, str>> + 'b> {
+    Box::new(content.iter().flat_map(
+        move |content| -> Box<dyn Iterator<Item = &'b Cow<'a, str>> + 'b> {
+            match content {
+                BodyContent::Paragraph(para) => Box::new(para.iter_text_in(view)),
+                BodyContent::Table(table) => iter_table_text(table, view),
+                BodyContent::Sdt(sdt) => iter_body_text(&sdt.content.content, view),
+                _ => Box::new(std::iter::empty()),
+            }
+        },
+    ))
+}
+
+fn iter_table_text<'b, 'a>(
+    table: &'b Table<'a>,
+    view: RevisionView,
+) -> Box<dyn Iterator<Item = &'b Cow<'a, str>> + 'b> {
+    Box::new(
+        table
+            .rows
+            .iter()
+            .flat_map(|row| row.cells.iter())
+            .flat_map(|cell| cell.content.iter())
+            .flat_map(
+                move |content| -> Box<dyn Iterator<Item = &'b Cow<'a, str>> + 'b> {
+                    match content {
+                        TableCellContent::Paragraph(para) => Box::new(para.iter_text_in(view)),
+                        TableCellContent::Table(table) => iter_table_text(table, view),
+                    }
+                },
+            ),
+    )
+}
+
+fn iter_body_text_mut<'b, 'a>(
+    content: &'b mut [BodyContent<'a>],
+) -> Box<dyn Iterator<Item = &'b mut Cow<'a, str>> + 'b> {
+    Box::new(content.iter_mut().flat_map(
+        |content| -> Box<dyn Iterator<Item = &'b mut Cow<'a, str>> + 'b> {
+            match content {
+                BodyContent::Paragraph(para) => Box::new(para.iter_text_mut()),
+                BodyContent::Table(table) => iter_table_text_mut(table),
+                BodyContent::Sdt(sdt) => iter_body_text_mut(&mut sdt.content.content),
+                _ => Box::new(std::iter::empty()),
+            }
+        },
+    ))
+}
+
+fn iter_table_text_mut<'b, 'a>(
+    table: &'b mut Table<'a>,
+) -> Box<dyn Iterator<Item = &'b mut Cow<'a, str>> + 'b> {
+    Box::new(
+        table
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.cells.iter_mut())
+            .flat_map(|cell| cell.content.iter_mut())
+            .flat_map(
+                |content| -> Box<dyn Iterator<Item = &'b mut Cow<'a, str>> + 'b> {
+                    match content {
+                        TableCellContent::Paragraph(para) => Box::new(para.iter_text_mut()),
+                        TableCellContent::Table(table) => iter_table_text_mut(table),
+                    }
+                },
+            ),
+    )
+}
+
+#[cfg(test)]
+use crate::document::Paragraph;
+
+__xml_test_suites!(
+    TextBoxContent,
+    TextBoxContent::default(),
+    r#"<w:txbxContent/>"#,
+    TextBoxContent {
+        content: vec![Paragraph::default().into()],
+    },
+    r#"<w:txbxContent><w:p><w:pPr/></w:p></w:txbxContent>"#,
+);
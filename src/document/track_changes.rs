@@ -0,0 +1,564 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::{
+    __setter,
+    document::{
+        DeletedInstrText, DeletedText, Deletion, Field, Hyperlink, Insertion, Paragraph,
+        ParagraphContent, RevisionView, Run, RunContent, TableCellContent, TableRow, Text,
+        TextSpace,
+    },
+    formatting::{CharacterProperty, CharacterPropertyChange},
+    DateTime,
+};
+
+/// Tracked editing mode
+///
+/// Edits made with it are recorded as tracked changes by the given author,
+/// so they show up as redlines in Word. Use `Docx::track_changes` to
+/// create one whose revision ids don't collide with the existing ones.
+///
+/// ```rust
+/// use docx::document::*;
+/// use docx::formatting::*;
+/// use docx::Docx;
+///
+/// let mut docx = Docx::default();
+/// let mut changes = docx.track_changes("Counsel");
+///
+/// let mut para = Paragraph::default()
+///     .push_text("The Seller shall ")
+///     .push_text("promptly ")
+///     .push_text("deliver");
+/// para.delete_tracked(1..2, &mut changes);
+/// para.push_tracked(Run::default().push_text(" within 5 days"), &mut changes);
+///
+/// if let ParagraphContent::Run(run) = &mut para.content[0] {
+///     run.set_property_tracked(CharacterProperty::default().bold(true), &mut changes);
+/// }
+///
+/// docx.document.push(para);
+/// ```
+#[derive(Debug)]
+pub struct TrackChanges<'a> {
+    /// Specifies the author of the changes.
+    pub author: Cow<'a, str>,
+    /// Specifies when the changes are made.
+    pub date: Option<DateTime>,
+    next_id: usize,
+}
+
+impl<'a> TrackChanges<'a> {
+    __setter!(date: Option<DateTime>);
+
+    /// Creates a tracked editing mode, dated now
+    ///
+    /// Revision ids start from zero, `Docx::track_changes` should be
+    /// preferred for editing existing documents.
+    pub fn new<T: Into<Cow<'a, str>>>(author: T) -> Self {
+        TrackChanges {
+            author: author.into(),
+            date: Some(DateTime::now()),
+            next_id: 0,
+        }
+    }
+
+    /// Specifies the id of the next revision
+    pub fn next_id(mut self, id: usize) -> Self {
+        self.next_id = id;
+        self
+    }
+
+    /// Allocates a revision id
+    pub fn allocate_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Wraps the content in an insertion
+    pub fn insert<T: Into<ParagraphContent<'a>>>(&mut self, content: T) -> Insertion<'a> {
+        self.insertion(vec![content.into()])
+    }
+
+    fn insertion(&mut self, content: Vec<ParagraphContent<'a>>) -> Insertion<'a> {
+        Insertion {
+            id: self.allocate_id(),
+            author: self.author.clone(),
+            date: self.date,
+            content,
+        }
+    }
+
+    fn deletion(&mut self, content: Vec<ParagraphContent<'a>>) -> Deletion<'a> {
+        Deletion {
+            id: self.allocate_id(),
+            author: self.author.clone(),
+            date: self.date,
+            content,
+        }
+    }
+
+    /// Marks the paragraph as inserted, including its mark
+    pub(crate) fn insert_paragraph(&mut self, para: &mut Paragraph<'a>) {
+        self.insert_content(para);
+        let ins = self.insertion(Vec::new());
+        para.property
+            .mark
+            .get_or_insert_with(Default::default)
+            .insertion = Some(ins);
+    }
+
+    /// Marks the paragraph as deleted, including its mark
+    pub(crate) fn delete_paragraph(&mut self, para: &mut Paragraph<'a>) {
+        self.delete_content(para);
+        let del = self.deletion(Vec::new());
+        para.property
+            .mark
+            .get_or_insert_with(Default::default)
+            .deletion = Some(del);
+    }
+
+    /// Marks the table row as inserted, including the content of its cells
+    /// and the rows of nested tables
+    pub(crate) fn insert_row(&mut self, row: &mut TableRow<'a>) {
+        for cell in &mut row.cells {
+            for content in &mut cell.content {
+                match content {
+                    TableCellContent::Paragraph(para) => self.insert_content(para),
+                    TableCellContent::Table(table) => {
+                        for row in &mut table.rows {
+                            self.insert_row(row);
+                        }
+                    }
+                }
+            }
+        }
+        row.property.insertion = Some(self.insertion(Vec::new()));
+    }
+
+    /// Marks the table row as deleted, including the content of its cells
+    /// and the rows of nested tables
+    pub(crate) fn delete_row(&mut self, row: &mut TableRow<'a>) {
+        for cell in &mut row.cells {
+            for content in &mut cell.content {
+                match content {
+                    TableCellContent::Paragraph(para) => self.delete_content(para),
+                    TableCellContent::Table(table) => {
+                        for row in &mut table.rows {
+                            self.delete_row(row);
+                        }
+                    }
+                }
+            }
+        }
+        row.property.deletion = Some(self.deletion(Vec::new()));
+    }
+
+    fn insert_content(&mut self, para: &mut Paragraph<'a>) {
+        if !para.content.is_empty() {
+            let content = std::mem::take(&mut para.content);
+            para.content = vec![self.insertion(content).into()];
+        }
+    }
+
+    fn delete_content(&mut self, para: &mut Paragraph<'a>) {
+        let content = std::mem::take(&mut para.content);
+        para.content = self.delete(content);
+    }
+
+    /// Marks the content as deleted
+    ///
+    /// Content inserted by the same author is removed, while content
+    /// inserted by others, moved content and content controls are kept with
+    /// the deletion nested inside. Simple fields are deleted in their complex
+    /// form, since they can't contain deletions. Content which isn't text,
+    /// e.g. bookmarks, is left as it is.
+    pub(crate) fn delete(
+        &mut self,
+        content: Vec<ParagraphContent<'a>>,
+    ) -> Vec<ParagraphContent<'a>> {
+        let mut result = Vec::with_capacity(content.len());
+        let mut deleted = Vec::new();
+
+        for content in content {
+            match content {
+                ParagraphContent::Run(mut run) => {
+                    delete_text(&mut run);
+                    deleted.push(run.into());
+                }
+                ParagraphContent::Link(mut link) => {
+                    delete_text(&mut link.content);
+                    deleted.push(link.into());
+                }
+                ParagraphContent::Insertion(ins) if ins.author == self.author => (),
+                ParagraphContent::Insertion(mut ins) => {
+                    self.flush(&mut deleted, &mut result);
+                    let content = std::mem::take(&mut ins.content);
+                    ins.content = self.delete(content);
+                    result.push(ins.into());
+                }
+                ParagraphContent::MoveTo(mut to) => {
+                    self.flush(&mut deleted, &mut result);
+                    let content = std::mem::take(&mut to.content);
+                    to.content = self.delete(content);
+                    result.push(to.into());
+                }
+                ParagraphContent::Sdt(mut sdt) => {
+                    self.flush(&mut deleted, &mut result);
+                    let content = std::mem::take(&mut sdt.content.content);
+                    sdt.content.content = self.delete(content);
+                    result.push(sdt.into());
+                }
+                ParagraphContent::SimpleField(field) => {
+                    let field = Field {
+                        instruction: field.instruction,
+                        result: field.content,
+                        dirty: field.dirty == Some(true),
+                    };
+                    for mut run in field.into_runs() {
+                        delete_text(&mut run);
+                        deleted.push(run.into());
+                    }
+                }
+                content => {
+                    self.flush(&mut deleted, &mut result);
+                    result.push(content);
+                }
+            }
+        }
+
+        self.flush(&mut deleted, &mut result);
+
+        result
+    }
+
+    /// Marks the text in the range of characters as deleted
+    ///
+    /// Runs and hyperlinks are split at the bounds of the range, and the
+    /// range is applied to the content of insertions, moved content and
+    /// content controls. Other content overlapping the range, e.g. simple
+    /// fields and drawings, is deleted as a whole. Content without text,
+    /// e.g. breaks and bookmarks, goes along with the text before it.
+    pub(crate) fn delete_range(
+        &mut self,
+        content: Vec<ParagraphContent<'a>>,
+        range: Range<usize>,
+    ) -> Vec<ParagraphContent<'a>> {
+        self.delete_range_in(content, range, false)
+    }
+
+    // content inserted by the same author is removed instead of deleted
+    fn delete_range_in(
+        &mut self,
+        content: Vec<ParagraphContent<'a>>,
+        range: Range<usize>,
+        remove: bool,
+    ) -> Vec<ParagraphContent<'a>> {
+        let mut result = Vec::with_capacity(content.len());
+        let mut deleted = Vec::new();
+        let mut offset = 0;
+
+        for content in content {
+            let start = offset;
+            let len: usize = content.iter_text().map(|text| text.chars().count()).sum();
+            offset += len;
+
+            if len == 0 {
+                if range.start < start && start <= range.end {
+                    deleted.push(content);
+                } else {
+                    self.flush_range(&mut deleted, &mut result, remove);
+                    result.push(content);
+                }
+                continue;
+            }
+
+            if offset <= range.start || start >= range.end {
+                self.flush_range(&mut deleted, &mut result, remove);
+                result.push(content);
+                continue;
+            }
+
+            if range.start <= start && offset <= range.end {
+                deleted.push(content);
+                continue;
+            }
+
+            // the range starts or ends inside the content
+            let local = range.start.saturating_sub(start)..range.end.min(offset) - start;
+
+            match content {
+                ParagraphContent::Run(run) => {
+                    let (before, inside, after) = split_range(run, local, range.start < start);
+                    self.flush_range(&mut deleted, &mut result, remove);
+                    result.extend(before.map(Into::into));
+                    deleted.extend(inside.map(Into::into));
+                    if let Some(after) = after {
+                        self.flush_range(&mut deleted, &mut result, remove);
+                        result.push(after.into());
+                    }
+                }
+                ParagraphContent::Link(Hyperlink {
+                    id,
+                    anchor,
+                    content,
+                }) => {
+                    let (before, inside, after) = split_range(content, local, range.start < start);
+                    let link = |content| {
+                        ParagraphContent::from(Hyperlink {
+                            id: id.clone(),
+                            anchor: anchor.clone(),
+                            content,
+                        })
+                    };
+                    self.flush_range(&mut deleted, &mut result, remove);
+                    result.extend(before.map(link));
+                    deleted.extend(inside.map(link));
+                    if let Some(after) = after {
+                        self.flush_range(&mut deleted, &mut result, remove);
+                        result.push(link(after));
+                    }
+                }
+                ParagraphContent::Insertion(mut ins) => {
+                    self.flush_range(&mut deleted, &mut result, remove);
+                    let remove = remove || ins.author == self.author;
+                    let content = std::mem::take(&mut ins.content);
+                    ins.content = self.delete_range_in(content, local, remove);
+                    result.push(ins.into());
+                }
+                ParagraphContent::MoveTo(mut to) => {
+                    self.flush_range(&mut deleted, &mut result, remove);
+                    let content = std::mem::take(&mut to.content);
+                    to.content = self.delete_range_in(content, local, remove);
+                    result.push(to.into());
+                }
+                ParagraphContent::Sdt(mut sdt) => {
+                    self.flush_range(&mut deleted, &mut result, remove);
+                    let content = std::mem::take(&mut sdt.content.content);
+                    sdt.content.content = self.delete_range_in(content, local, remove);
+                    result.push(sdt.into());
+                }
+                content => deleted.push(content),
+            }
+        }
+
+        self.flush_range(&mut deleted, &mut result, remove);
+
+        result
+    }
+
+    fn flush_range(
+        &mut self,
+        deleted: &mut Vec<ParagraphContent<'a>>,
+        result: &mut Vec<ParagraphContent<'a>>,
+        remove: bool,
+    ) {
+        let content = std::mem::take(deleted);
+        if !remove {
+            result.extend(self.delete(content));
+        }
+    }
+
+    // consecutive deleted content is put into one deletion
+    fn flush(
+        &mut self,
+        deleted: &mut Vec<ParagraphContent<'a>>,
+        result: &mut Vec<ParagraphContent<'a>>,
+    ) {
+        if !deleted.is_empty() {
+            let content = std::mem::take(deleted);
+            result.push(self.deletion(content).into());
+        }
+    }
+
+    /// Replaces the run properties, records the previous ones
+    ///
+    /// If the properties have been changed already, the originally
+    /// recorded ones are kept.
+    pub(crate) fn set_property(&mut self, run: &mut Run<'a>, mut property: CharacterProperty<'a>) {
+        let mut previous = std::mem::take(&mut run.property);
+
+        property.change = Some(match previous.change.take() {
+            Some(change) => change,
+            None => CharacterPropertyChange {
+                id: self.allocate_id(),
+                author: self.author.clone(),
+                date: self.date,
+                property: Box::new(previous),
+            },
+        });
+
+        run.property = property;
+    }
+}
+
+fn delete_text(run: &mut Run) {
+    for content in &mut run.content {
+        match content {
+            RunContent::Text(text) => {
+                *content = RunContent::DeletedText(DeletedText {
+                    space: text.space.take(),
+                    text: std::mem::take(&mut text.text),
+                });
+            }
+            RunContent::InstrText(instr) => {
+                *content = RunContent::DeletedInstrText(DeletedInstrText {
+                    space: instr.space.take(),
+                    text: std::mem::take(&mut instr.text),
+                });
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Splits the run into the parts before, inside and after the range of
+/// characters
+///
+/// Content without text at the start of the run is inside the range only if
+/// the range starts before the run.
+fn split_range<'a>(
+    run: Run<'a>,
+    range: Range<usize>,
+    starts_before: bool,
+) -> (Option<Run<'a>>, Option<Run<'a>>, Option<Run<'a>>) {
+    let (before, rest) = if starts_before {
+        (None, Some(run))
+    } else {
+        split_run(run, range.start, false)
+    };
+
+    match rest {
+        Some(rest) => {
+            let (inside, after) = split_run(rest, range.end - range.start, true);
+            (before, inside, after)
+        }
+        None => (before, None, None),
+    }
+}
+
+/// Splits the run at the character offset, content without text goes along
+/// with the text before it
+///
+/// Content whose text is split by the offset, e.g. drawings, is put on the
+/// left if `left` is true. Empty runs are returned as `None`.
+fn split_run<'a>(run: Run<'a>, at: usize, left: bool) -> (Option<Run<'a>>, Option<Run<'a>>) {
+    fn text<'a>(text: &str, preserve: bool) -> RunContent<'a> {
+        let text = text.to_owned();
+        if preserve || text.starts_with(char::is_whitespace) || text.ends_with(char::is_whitespace)
+        {
+            RunContent::Text(Text::from((text, TextSpace::Preserve)))
+        } else {
+            RunContent::Text(Text::from(text))
+        }
+    }
+
+    let Run { property, content } = run;
+
+    let mut before = Vec::new();
+    let mut after = Vec::new();
+    let mut offset = 0;
+
+    for content in content {
+        let start = offset;
+        offset += match &content {
+            RunContent::Text(text) => text.text.chars().count(),
+            RunContent::Drawing(drawing) => drawing
+                .iter_text_in(RevisionView::Accepted)
+                .map(|text| text.chars().count())
+                .sum(),
+            RunContent::AlternateContent(content) => content.drawing().map_or(0, |drawing| {
+                drawing
+                    .iter_text_in(RevisionView::Accepted)
+                    .map(|text| text.chars().count())
+                    .sum()
+            }),
+            _ => 0,
+        };
+
+        if offset <= at {
+            before.push(content);
+        } else if start >= at {
+            after.push(content);
+        } else if let RunContent::Text(Text { space, text: value }) = &content {
+            let index = value
+                .char_indices()
+                .nth(at - start)
+                .map_or(value.len(), |(index, _)| index);
+            before.push(text(&value[..index], space.is_some()));
+            after.push(text(&value[index..], space.is_some()));
+        } else if left {
+            before.push(content);
+        } else {
+            after.push(content);
+        }
+    }
+
+    let run = |content: Vec<RunContent<'a>>| {
+        if content.is_empty() {
+            None
+        } else {
+            Some(Run {
+                property: property.clone(),
+                content,
+            })
+        }
+    };
+
+    (run(before), run(after))
+}
+
+#[test]
+fn tracked_editing() {
+    use crate::document::{revision::paragraph_texts, BodyContent, RevisionView};
+    use crate::Docx;
+
+    fn docx() -> Docx<'static> {
+        let mut docx = Docx::default();
+        docx.document.push(
+            Paragraph::default()
+                .push_text("Lorem ")
+                .push(Insertion::new(3, "foo").push(Run::default().push_text("ipsum ")))
+                .push_text("dolor"),
+        );
+
+        let mut changes = docx.track_changes("bar");
+        if let BodyContent::Paragraph(para) = &mut docx.document.body.content[0] {
+            para.delete_tracked(0..1, &mut changes);
+            para.push_tracked(Run::default().push_text(" sit"), &mut changes);
+            if let ParagraphContent::Run(run) = &mut para.content[2] {
+                run.set_property_tracked(CharacterProperty::default().bold(true), &mut changes);
+            }
+        }
+        assert_eq!(changes.allocate_id(), 7);
+
+        docx
+    }
+
+    fn text(docx: &Docx<'static>, view: RevisionView) -> String {
+        paragraph_texts(&docx.document.body.content, view).concat()
+    }
+
+    let edited = docx();
+    assert_eq!(text(&edited, RevisionView::Accepted), "ipsum dolor sit");
+    assert_eq!(text(&edited, RevisionView::Original), "Lorem dolor");
+
+    let mut accepted = docx();
+    accepted.accept_revisions_by("bar");
+    assert_eq!(text(&accepted, RevisionView::Original), "dolor sit");
+
+    let mut rejected = docx();
+    rejected.reject_revisions_by("bar");
+    assert_eq!(text(&rejected, RevisionView::Accepted), "Lorem ipsum dolor");
+    match &rejected.document.body.content[0] {
+        BodyContent::Paragraph(para) => {
+            assert_eq!(para.content.len(), 3);
+            assert!(para.content.iter().all(|content| match content {
+                ParagraphContent::Run(run) => run.property.bold.is_none(),
+                _ => true,
+            }));
+        }
+        _ => panic!("expected a paragraph"),
+    }
+}
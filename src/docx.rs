@@ -1,20 +1,55 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fs::File;
-use std::io::{Read, Seek, Write};
+#[cfg(any(test, feature = "async", feature = "encryption"))]
+use std::io::Cursor;
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::Path;
 use strong_xml::{XmlRead, XmlWrite, XmlWriter};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use zip::{result::ZipError, write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
 
 use crate::{
     app::App,
-    content_type::ContentTypes,
+    chart::{workbook, Chart, ChartPart},
+    comments::{new_hex_id, Comment, CommentThread, Comments},
+    comments_extended::{CommentExtended, CommentsExtended},
+    comments_ids::{CommentId, CommentsIds},
+    compare::compare_content,
+    content_type::{
+        ContentTypes, CONTENT_TYPE_CHART, CONTENT_TYPE_COMMENTS, CONTENT_TYPE_COMMENTS_EXTENDED,
+        CONTENT_TYPE_COMMENTS_IDS, CONTENT_TYPE_CUSTOM, CONTENT_TYPE_CUSTOM_XML_PROPERTIES,
+        CONTENT_TYPE_ENDNOTES, CONTENT_TYPE_FOOTER, CONTENT_TYPE_FOOTNOTES, CONTENT_TYPE_HEADER,
+        CONTENT_TYPE_PEOPLE, CONTENT_TYPE_SETTINGS, CONTENT_TYPE_XLSX,
+    },
     core::Core,
-    document::Document,
-    error::DocxResult,
+    custom::Custom,
+    custom_xml::{CustomXml, DataStoreItem},
+    datetime::DateTime,
+    document::{
+        for_each_paragraph, for_each_run, for_each_run_mut, max_revision_id, resolve_revisions,
+        BodyContent, Document, Drawing, Paragraph, ParagraphContent, RunContent, SdtProperty,
+        Table, TableCellContent, TrackChanges,
+    },
+    endnotes::Endnotes,
+    error::{DocxError, DocxResult},
     font_table::FontTable,
-    rels::Relationships,
+    footer::Footer,
+    footnotes::Footnotes,
+    header::Header,
+    media::{is_svg, ImageFormat, ImageInfo, Media, MediaReference, MediaSource},
+    people::People,
+    rels::{Relationship, Relationships},
     schema::{
-        SCHEMA_CORE, SCHEMA_FONT_TABLE, SCHEMA_OFFICE_DOCUMENT, SCHEMA_REL_EXTENDED, SCHEMA_STYLES,
+        SCHEMA_COMMENTS, SCHEMA_COMMENTS_EXTENDED, SCHEMA_COMMENTS_IDS, SCHEMA_CORE,
+        SCHEMA_ENDNOTES, SCHEMA_FONT_TABLE, SCHEMA_FOOTER, SCHEMA_FOOTNOTES, SCHEMA_HEADER,
+        SCHEMA_IMAGE, SCHEMA_OFFICE_DOCUMENT, SCHEMA_PEOPLE, SCHEMA_REL_CHART, SCHEMA_REL_CUSTOM,
+        SCHEMA_REL_CUSTOM_XML, SCHEMA_REL_CUSTOM_XML_PROPS, SCHEMA_REL_EXTENDED,
+        SCHEMA_REL_PACKAGE, SCHEMA_SETTINGS, SCHEMA_STYLES,
     },
+    settings::Settings,
+    stream::StreamWriter,
     styles::Styles,
 };
 
@@ -23,210 +58,3071 @@ use crate::{
 pub struct Docx<'a> {
     /// Specifies package-level properties part
     pub app: Option<App<'a>>,
+    /// Specifies the chart parts
+    pub charts: Vec<ChartPart<'a>>,
+    /// Specifies the comments part
+    pub comments: Option<Comments<'a>>,
+    /// Specifies the comments extended part, which stores replies and
+    /// resolved state
+    pub comments_extended: Option<CommentsExtended<'a>>,
+    /// Specifies the comments ids part
+    pub comments_ids: Option<CommentsIds<'a>>,
+    /// Specifies the part-level relationships of the comments part
+    pub comments_rels: Option<Relationships<'a>>,
     /// Specifies core properties part
     pub core: Option<Core<'a>>,
+    /// Specifies custom properties part
+    pub custom: Option<Custom<'a>>,
+    /// Specifies custom XML data parts
+    pub custom_xml: Vec<CustomXml<'a>>,
     /// Specifies the content type of relationship parts and the main document part.
     pub content_types: ContentTypes<'a>,
     /// Specifies the main document part.
     pub document: Document<'a>,
+    /// Specifies the embedded packages, e.g. the workbooks of charts
+    pub embeddings: Vec<Media<'a>>,
+    /// Specifies the endnotes part
+    pub endnotes: Option<Endnotes<'a>>,
+    /// Specifies the part-level relationships of the endnotes part
+    pub endnotes_rels: Option<Relationships<'a>>,
     /// Specifies the font table part
     pub font_table: Option<FontTable<'a>>,
+    /// Specifies the footer parts, paired with the ids of their relationships
+    pub footers: Vec<(Cow<'a, str>, Footer<'a>)>,
+    /// Specifies the part-level relationships of footer parts, paired with
+    /// the ids of the relationships to the footers
+    pub footer_rels: Vec<(Cow<'a, str>, Relationships<'a>)>,
+    /// Specifies the footnotes part
+    pub footnotes: Option<Footnotes<'a>>,
+    /// Specifies the part-level relationships of the footnotes part
+    pub footnotes_rels: Option<Relationships<'a>>,
+    /// Specifies the header parts, paired with the ids of their relationships
+    pub headers: Vec<(Cow<'a, str>, Header<'a>)>,
+    /// Specifies the part-level relationships of header parts, paired with
+    /// the ids of the relationships to the headers
+    pub header_rels: Vec<(Cow<'a, str>, Relationships<'a>)>,
+    /// Specifies the media parts, e.g. images
+    pub media: Vec<Media<'a>>,
+    /// Specifies the people part
+    pub people: Option<People<'a>>,
+    /// Specifies the document settings part
+    pub settings: Option<Settings<'a>>,
     /// Specifies the style definitions part
     pub styles: Styles<'a>,
     /// Specifies the package-level relationship to the main document part
     pub rels: Relationships<'a>,
     /// Specifies the part-level relationship to the main document part
     pub document_rels: Option<Relationships<'a>>,
+    /// Sets the modification date of the core properties to the current
+    /// time when writing
+    pub set_modified: bool,
+    /// Recomputes the statistics of the application properties from the
+    /// main document part when writing
+    pub update_statistics: bool,
 }
 
 impl<'a> Docx<'a> {
-    pub fn write<W: Write + Seek>(&mut self, writer: W) -> DocxResult<W> {
-        let mut writer = XmlWriter::new(ZipWriter::new(writer));
+    /// Adds a header part, returns the id of its relationship
+    ///
+    /// The header is used once a section refers to the id.
+    ///
+    /// ```rust
+    /// use docx::document::Paragraph;
+    /// use docx::formatting::{HeaderFooterType, SectionProperty};
+    /// use docx::header::Header;
+    /// use docx::Docx;
+    ///
+    /// let mut header = Header::default();
+    /// header.push(Paragraph::default().push_text("Draft"));
+    ///
+    /// let mut docx = Docx::default();
+    /// let id = docx.add_header(header);
+    /// docx.document.body.property =
+    ///     Some(SectionProperty::default().push_header(HeaderFooterType::Default, id));
+    /// ```
+    pub fn add_header(&mut self, header: Header<'a>) -> String {
+        let id = add_part_rel(&mut self.document_rels, SCHEMA_HEADER, "header");
+        self.headers.push((id.clone().into(), header));
+        id
+    }
 
-        let opt = FileOptions::default()
-            .compression_method(CompressionMethod::Deflated)
-            .unix_permissions(0o755);
+    /// Adds a footer part, returns the id of its relationship
+    ///
+    /// The footer is used once a section refers to the id.
+    pub fn add_footer(&mut self, footer: Footer<'a>) -> String {
+        let id = add_part_rel(&mut self.document_rels, SCHEMA_FOOTER, "footer");
+        self.footers.push((id.clone().into(), footer));
+        id
+    }
 
-        // ==== Add Relationships ====
+    /// Adds an image, returns an inline picture of it
+    ///
+    /// PNG, JPEG and GIF images are supported. The picture is sized from
+    /// the pixel size and the resolution of the image, and the image is
+    /// stored once if it's added multiple times. The picture refers to the
+    /// image by a relationship of the main document part, so it should be
+    /// placed in the body, see `add_header_image` and `add_footer_image`
+    /// for headers and footers.
+    ///
+    /// ```rust
+    /// use docx::document::{Paragraph, Run};
+    /// use docx::Docx;
+    ///
+    /// // a 1x1 GIF image
+    /// let gif = b"GIF89a\x01\x00\x01\x00\x00\x00\x00".to_vec();
+    ///
+    /// let mut docx = Docx::default();
+    /// let drawing = docx.add_image(gif).unwrap().description("A dot");
+    /// docx.document
+    ///     .push(Paragraph::default().push(Run::default().push(drawing)));
+    ///
+    /// assert_eq!(docx.media[0].name, "word/media/image1.gif");
+    /// ```
+    pub fn add_image<T: Into<Cow<'a, [u8]>>>(&mut self, data: T) -> DocxResult<Drawing<'a>> {
+        let data = data.into();
+        let info = ImageInfo::from_bytes(&data)?;
 
-        if self.app.is_some() {
-            self.rels.add_rel(SCHEMA_REL_EXTENDED, "docProps/app.xml");
+        let name = self.add_media(data, info.format);
+        let id = self
+            .document_rels
+            .get_or_insert(Relationships::default())
+            .add_rel(SCHEMA_IMAGE, part_target(&name))
+            .to_owned();
+
+        let (width, height) = info.extent();
+        Ok(Drawing::inline_picture(id, width, height))
+    }
+
+    /// Adds an image to the header with the given relationship id, returns
+    /// an inline picture of it
+    ///
+    /// Like `add_image`, but the picture refers to the image by a
+    /// relationship of the header part, so it should be placed in the
+    /// header.
+    ///
+    /// ```rust
+    /// use docx::document::{Paragraph, Run};
+    /// use docx::header::Header;
+    /// use docx::Docx;
+    ///
+    /// // a 1x1 GIF image
+    /// let gif = b"GIF89a\x01\x00\x01\x00\x00\x00\x00".to_vec();
+    ///
+    /// let mut docx = Docx::default();
+    /// let id = docx.add_header(Header::default());
+    /// let drawing = docx.add_header_image(&id, gif).unwrap();
+    /// docx.headers[0]
+    ///     .1
+    ///     .push(Paragraph::default().push(Run::default().push(drawing)));
+    /// ```
+    pub fn add_header_image<T: Into<Cow<'a, [u8]>>>(
+        &mut self,
+        header: &str,
+        data: T,
+    ) -> DocxResult<Drawing<'a>> {
+        if self.headers.iter().all(|(id, _)| id != header) {
+            return Err(DocxError::Image(format!("header {} doesn't exist", header)));
         }
 
-        if self.core.is_some() {
-            self.rels.add_rel(SCHEMA_CORE, "docProps/core.xml");
+        let data = data.into();
+        let info = ImageInfo::from_bytes(&data)?;
+
+        let name = self.add_media(data, info.format);
+        let id = part_rels_mut(&mut self.header_rels, header)
+            .add_rel(SCHEMA_IMAGE, part_target(&name))
+            .to_owned();
+
+        let (width, height) = info.extent();
+        Ok(Drawing::inline_picture(id, width, height))
+    }
+
+    /// Adds an image to the footer with the given relationship id, returns
+    /// an inline picture of it
+    ///
+    /// Like `add_image`, but the picture refers to the image by a
+    /// relationship of the footer part, so it should be placed in the
+    /// footer.
+    pub fn add_footer_image<T: Into<Cow<'a, [u8]>>>(
+        &mut self,
+        footer: &str,
+        data: T,
+    ) -> DocxResult<Drawing<'a>> {
+        if self.footers.iter().all(|(id, _)| id != footer) {
+            return Err(DocxError::Image(format!("footer {} doesn't exist", footer)));
         }
 
-        self.rels
-            .add_rel(SCHEMA_OFFICE_DOCUMENT, "word/document.xml");
+        let data = data.into();
+        let info = ImageInfo::from_bytes(&data)?;
 
-        self.document_rels
-            .get_or_insert(Relationships::default())
-            .add_rel(SCHEMA_STYLES, "styles.xml");
+        let name = self.add_media(data, info.format);
+        let id = part_rels_mut(&mut self.footer_rels, footer)
+            .add_rel(SCHEMA_IMAGE, part_target(&name))
+            .to_owned();
 
-        if self.font_table.is_some() {
-            self.document_rels
-                .get_or_insert(Relationships::default())
-                .add_rel(SCHEMA_FONT_TABLE, "fontTable.xml");
+        let (width, height) = info.extent();
+        Ok(Drawing::inline_picture(id, width, height))
+    }
+
+    /// Adds an SVG image with a fallback image, returns an inline picture
+    /// of it
+    ///
+    /// Word 2016 and later display the SVG image, while earlier versions
+    /// display the fallback, which should be a PNG image. The picture is
+    /// sized from the fallback.
+    ///
+    /// ```rust
+    /// use docx::document::{Paragraph, Run};
+    /// use docx::Docx;
+    ///
+    /// let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"/>"#;
+    /// // a 1x1 GIF image
+    /// let fallback = b"GIF89a\x01\x00\x01\x00\x00\x00\x00";
+    ///
+    /// let mut docx = Docx::default();
+    /// let drawing = docx.add_svg_image(&svg[..], &fallback[..]).unwrap();
+    ///
+    /// assert!(drawing.picture().unwrap().svg_embed().is_some());
+    /// assert_eq!(docx.media[0].name, "word/media/image1.gif");
+    /// assert_eq!(docx.media[1].name, "word/media/image2.svg");
+    /// ```
+    pub fn add_svg_image<S, F>(&mut self, svg: S, fallback: F) -> DocxResult<Drawing<'a>>
+    where
+        S: Into<Cow<'a, [u8]>>,
+        F: Into<Cow<'a, [u8]>>,
+    {
+        let svg = svg.into();
+        if !is_svg(&svg) {
+            return Err(DocxError::Image("malformed svg image".into()));
         }
 
-        // ==== Write Zip Item ====
+        let mut drawing = self.add_image(fallback)?;
 
-        macro_rules! write_xml {
-            (Some($xml:expr) => $name:tt) => {
-                if let Some(ref xml) = $xml {
-                    write_xml!(xml => $name);
-                }
-            };
-            (Some($xml:expr) => $name:tt $($rest:tt)*) => {
-                write_xml!(Some($xml) => $name);
-                write_xml!($($rest)*);
-            };
-            ($xml:expr => $name:tt) => {
-                writer.inner.start_file($name, opt)?;
-                $xml.to_writer(&mut writer)?;
-            };
-            ($xml:expr => $name:tt $($rest:tt)*) => {
-                write_xml!($xml => $name);
-                write_xml!($($rest)*);
-            };
+        let name = self.add_media(svg, ImageFormat::Svg);
+        let id = self
+            .document_rels
+            .get_or_insert(Relationships::default())
+            .add_rel(SCHEMA_IMAGE, part_target(&name))
+            .to_owned();
+        if let Some(picture) = drawing.picture_mut() {
+            let blip = std::mem::take(&mut picture.blip_fill.blip);
+            picture.blip_fill.blip = blip.svg(id);
         }
 
-        write_xml!(
-            self.content_types        => "[Content_Types].xml"
-            Some(self.app)            => "docProps/app.xml"
-            Some(self.core)           => "docProps/core.xml"
-            self.rels                 => "_rels/.rels"
-            self.document             => "word/document.xml"
-            self.styles               => "word/styles.xml"
-            Some(self.font_table)     => "word/fontTable.xml"
-            Some(self.document_rels)  => "word/_rels/document.xml.rels"
-        );
+        Ok(drawing)
+    }
 
-        Ok(writer.inner.finish()?)
+    /// Adds a media part unless an identical one exists, returns its name
+    fn add_media(&mut self, data: Cow<'a, [u8]>, format: ImageFormat) -> String {
+        match self.media.iter().find(|media| media.data == data) {
+            Some(media) => media.name.to_string(),
+            None => {
+                let n = (1..)
+                    .find(|n| {
+                        let prefix = format!("word/media/image{}.", n);
+                        self.media
+                            .iter()
+                            .all(|media| !media.name.starts_with(&prefix))
+                    })
+                    .unwrap();
+                let name = format!("word/media/image{}.{}", n, format.extension());
+                self.media.push(Media {
+                    name: name.clone().into(),
+                    data,
+                });
+                name
+            }
+        }
     }
 
-    pub fn write_file<P: AsRef<Path>>(&mut self, path: P) -> DocxResult<File> {
-        let file = File::create(path)?;
-        self.write(file)
+    /// Adds a chart part along with a workbook of its data, returns an
+    /// inline drawing of it in the size in EMUs
+    ///
+    /// The workbook is embedded so that the data can be edited in Word. Like
+    /// pictures, the drawing should be placed in the body.
+    ///
+    /// Returns an error if the chart has no data or has non-finite values.
+    ///
+    /// ```rust
+    /// use docx::chart::{Chart, ChartKind, Series};
+    /// use docx::document::{Paragraph, Run};
+    /// use docx::Docx;
+    ///
+    /// let chart = Chart::new(ChartKind::Line)
+    ///     .categories(vec!["Jan", "Feb", "Mar"])
+    ///     .push(Series::new("Visitors", vec![120.0, 180.0, 150.0]));
+    ///
+    /// let mut docx = Docx::default();
+    /// let drawing = docx.add_chart(chart, 5486400, 3200400).unwrap();
+    /// docx.document
+    ///     .push(Paragraph::default().push(Run::default().push(drawing)));
+    ///
+    /// assert_eq!(docx.charts[0].name, "word/charts/chart1.xml");
+    /// assert_eq!(
+    ///     docx.embeddings[0].name,
+    ///     "word/embeddings/Microsoft_Excel_Worksheet1.xlsx"
+    /// );
+    /// ```
+    pub fn add_chart(
+        &mut self,
+        mut chart: Chart<'_>,
+        width: u64,
+        height: u64,
+    ) -> DocxResult<Drawing<'a>> {
+        chart.check()?;
+
+        let n = (1..)
+            .find(|n| {
+                let name = format!("word/charts/chart{}.xml", n);
+                self.charts.iter().all(|chart| chart.name != name)
+            })
+            .unwrap();
+        let m = (1..)
+            .find(|m| {
+                let name = format!("word/embeddings/Microsoft_Excel_Worksheet{}.xlsx", m);
+                self.embeddings.iter().all(|media| media.name != name)
+            })
+            .unwrap();
+
+        self.embeddings.push(Media {
+            name: format!("word/embeddings/Microsoft_Excel_Worksheet{}.xlsx", m).into(),
+            data: workbook(&chart)?.into(),
+        });
+
+        let mut rels = Relationships::default();
+        let id = rels.add_rel(
+            SCHEMA_REL_PACKAGE,
+            format!("../embeddings/Microsoft_Excel_Worksheet{}.xlsx", m),
+        );
+        chart.external_data = Some(id.to_owned().into());
+
+        self.charts.push(ChartPart {
+            name: format!("word/charts/chart{}.xml", n).into(),
+            data: chart.to_string()?.into(),
+            rels: Some(rels),
+        });
+
+        let id = self
+            .document_rels
+            .get_or_insert(Relationships::default())
+            .add_rel(SCHEMA_REL_CHART, format!("charts/chart{}.xml", n));
+
+        Ok(Drawing::inline_chart(id.to_owned(), width, height))
     }
-}
 
-/// An extracted docx file
-pub struct DocxFile {
-    app: Option<String>,
-    content_types: String,
-    core: Option<String>,
-    document: String,
-    document_rels: Option<String>,
-    font_table: Option<String>,
-    rels: String,
-    styles: Option<String>,
-}
+    /// Returns the pictures in the body, headers and footers, with the
+    /// media parts they refer to
+    ///
+    /// Pictures are listed in document order, starting with the body.
+    /// Pictures of linked or missing images are skipped.
+    ///
+    /// ```rust
+    /// use docx::document::{Paragraph, Run};
+    /// use docx::media::MediaSource;
+    /// use docx::Docx;
+    ///
+    /// // a 1x1 GIF image
+    /// let gif = b"GIF89a\x01\x00\x01\x00\x00\x00\x00";
+    ///
+    /// let mut docx = Docx::default();
+    /// let drawing = docx.add_image(&gif[..]).unwrap().description("A dot");
+    /// docx.document
+    ///     .push(Paragraph::default().push(Run::default().push(drawing)));
+    ///
+    /// let references = docx.media_references();
+    ///
+    /// assert_eq!(references[0].source, MediaSource::Document);
+    /// assert_eq!(references[0].media.name, "word/media/image1.gif");
+    /// assert_eq!(references[0].description(), Some("A dot"));
+    /// assert_eq!(references[0].image_info().unwrap().width, 1);
+    /// ```
+    pub fn media_references(&self) -> Vec<MediaReference<'_, 'a>> {
+        let mut references = Vec::new();
 
-impl DocxFile {
-    /// Extracts from reader
-    pub fn from_reader<T: Read + Seek>(reader: T) -> DocxResult<Self> {
-        let mut zip = ZipArchive::new(reader)?;
+        self.collect_media_references(
+            MediaSource::Document,
+            &self.document.body.content,
+            self.document_rels.as_ref(),
+            &mut references,
+        );
 
-        macro_rules! read {
-            ($xml:tt, $name:expr) => {{
-                let mut file = zip.by_name($name)?;
-                let mut buffer = String::new();
-                file.read_to_string(&mut buffer)?;
-                buffer
-            }};
+        for (id, header) in &self.headers {
+            let rels = find_part_rels(&self.header_rels, id);
+            let source = MediaSource::Header(id);
+            self.collect_media_references(source, &header.content, rels, &mut references);
         }
 
-        macro_rules! option_read {
-            ($xml:tt, $name:expr) => {
-                match zip.by_name($name) {
-                    Err(ZipError::FileNotFound) => None,
-                    Err(e) => return Err(e.into()),
-                    Ok(mut file) => {
-                        let mut buffer = String::new();
-                        file.read_to_string(&mut buffer)?;
-                        Some(buffer)
+        for (id, footer) in &self.footers {
+            let rels = find_part_rels(&self.footer_rels, id);
+            let source = MediaSource::Footer(id);
+            self.collect_media_references(source, &footer.content, rels, &mut references);
+        }
+
+        references
+    }
+
+    fn collect_media_references<'b>(
+        &'b self,
+        source: MediaSource<'b>,
+        content: &'b [BodyContent<'a>],
+        rels: Option<&'b Relationships<'a>>,
+        references: &mut Vec<MediaReference<'b, 'a>>,
+    ) {
+        let rels = match rels {
+            Some(rels) => rels,
+            None => return,
+        };
+
+        let find = |id: &str| -> Option<&'b Media<'a>> {
+            let rel = rels.relationships.iter().find(|rel| rel.id == id)?;
+            let name = part_name(&rel.target);
+            self.media.iter().find(|media| media.name == name)
+        };
+
+        for_each_paragraph(content, &mut |paragraph| {
+            for_each_run(&paragraph.content, &mut |run| {
+                for content in &run.content {
+                    let drawing = match content {
+                        RunContent::Drawing(drawing) => drawing,
+                        _ => continue,
+                    };
+                    let picture = match drawing.picture() {
+                        Some(picture) => picture,
+                        None => continue,
+                    };
+                    if let Some(media) = picture.embed().and_then(find) {
+                        references.push(MediaReference {
+                            source,
+                            paragraph,
+                            drawing,
+                            media,
+                            svg: picture.svg_embed().and_then(find),
+                        });
                     }
-                };
+                }
+            });
+        });
+    }
+
+    /// Replaces the image of the media part with the given name
+    ///
+    /// Pictures of the image keep their size and position. If the format
+    /// of the image changes, the part is renamed with the new extension.
+    /// Returns `false` if there's no such part.
+    ///
+    /// ```rust
+    /// use docx::Docx;
+    ///
+    /// // 1x1 GIF images
+    /// let black = b"GIF89a\x01\x00\x01\x00\x00\x00\x00";
+    /// let white = b"GIF89a\x01\x00\x01\x00\x00\xff\x00";
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_image(&black[..]).unwrap();
+    ///
+    /// assert!(docx.replace_media("word/media/image1.gif", &white[..]).unwrap());
+    /// assert_eq!(&*docx.media[0].data, &white[..]);
+    /// ```
+    pub fn replace_media<T: Into<Cow<'a, [u8]>>>(
+        &mut self,
+        name: &str,
+        data: T,
+    ) -> DocxResult<bool> {
+        let data = data.into();
+        let format = match ImageInfo::from_bytes(&data) {
+            Ok(info) => info.format,
+            Err(_) if is_svg(&data) => ImageFormat::Svg,
+            Err(err) => return Err(err),
+        };
+
+        let index = match self.media.iter().position(|media| media.name == name) {
+            Some(index) => index,
+            None => return Ok(false),
+        };
+
+        let extension = self.media[index].extension();
+        if extension.as_deref().and_then(ImageFormat::from_extension) != Some(format) {
+            let stem = match name.rsplit_once('.') {
+                Some((stem, ext)) if !ext.contains('/') => stem,
+                _ => name,
             };
+            let new_name = format!("{}.{}", stem, format.extension());
+            if self.media.iter().any(|media| media.name == new_name) {
+                return Err(DocxError::Image(format!(
+                    "media part {} already exists",
+                    new_name
+                )));
+            }
+
+            let rels = self
+                .document_rels
+                .iter_mut()
+                .chain(self.header_rels.iter_mut().map(|(_, rels)| rels))
+                .chain(self.footer_rels.iter_mut().map(|(_, rels)| rels))
+                .chain(self.footnotes_rels.iter_mut())
+                .chain(self.endnotes_rels.iter_mut())
+                .chain(self.comments_rels.iter_mut());
+            for rels in rels {
+                for rel in &mut rels.relationships {
+                    if part_name(&rel.target) == name {
+                        rel.target = part_target(&new_name).into();
+                    }
+                }
+            }
+
+            self.media[index].name = new_name.into();
         }
 
-        let app = option_read!(App, "docProps/app.xml");
-        let content_types = read!(ContentTypes, "[Content_Types].xml");
-        let core = option_read!(Core, "docProps/core.xml");
-        let document_rels = option_read!(Relationships, "word/_rels/document.xml.rels");
-        let document = read!(Document, "word/document.xml");
-        let font_table = option_read!(FontTable, "word/fontTable.xml");
-        let rels = read!(Relationships, "_rels/.rels");
-        let styles = option_read!(Styles, "word/styles.xml");
+        self.media[index].data = data;
 
-        Ok(DocxFile {
-            app,
-            content_types,
-            core,
-            document_rels,
-            document,
-            font_table,
-            rels,
-            styles,
-        })
+        Ok(true)
     }
 
-    /// Extracts from file
-    #[inline]
-    pub fn from_file<P: AsRef<Path>>(path: P) -> DocxResult<Self> {
-        Self::from_reader(File::open(path)?)
+    /// Adds a comment, returns its id
+    ///
+    /// Use `Paragraph::anchor_comment` to anchor the comment to the text.
+    pub fn add_comment(&mut self, comment: Comment<'a>) -> usize {
+        self.comments
+            .get_or_insert_with(Comments::default)
+            .push(comment)
     }
 
-    /// Parses content into `Docx` struct
-    pub fn parse<'a>(&'a self) -> DocxResult<Docx<'a>> {
-        let app = if let Some(content) = &self.app {
-            Some(App::from_str(content)?)
-        } else {
-            None
+    /// Returns the comments with the text they are anchored to
+    ///
+    /// Comments that aren't anchored in the main document are omitted.
+    pub fn anchored_comments(&self) -> Vec<(&Comment<'a>, String)> {
+        let comments = match &self.comments {
+            Some(comments) => comments,
+            None => return Vec::new(),
         };
 
-        let document = Document::from_str(&self.document)?;
+        self.document
+            .comment_anchors()
+            .into_iter()
+            .filter_map(|(id, text)| Some((comments.get(id)?, text)))
+            .collect()
+    }
 
-        let content_types = ContentTypes::from_str(&self.content_types)?;
+    /// Returns the comment threads, i.e. comments with their replies
+    ///
+    /// Replies are read from the comments extended part.
+    pub fn comment_threads(&self) -> Vec<CommentThread<'_, 'a>> {
+        let comments = match &self.comments {
+            Some(comments) => &comments.comments,
+            None => return Vec::new(),
+        };
 
-        let core = if let Some(content) = &self.core {
-            Some(Core::from_str(content)?)
-        } else {
-            None
+        let parent_of = |comment: &Comment| {
+            self.comment_extended(comment)
+                .and_then(|ext| ext.parent.as_deref())
         };
 
-        let document_rels = if let Some(content) = &self.document_rels {
-            Some(Relationships::from_str(content)?)
-        } else {
-            None
+        comments
+            .iter()
+            .filter(|comment| parent_of(comment).is_none())
+            .map(|comment| CommentThread {
+                comment,
+                replies: comments
+                    .iter()
+                    .filter(|reply| match (parent_of(reply), comment.para_id()) {
+                        (Some(parent), Some(id)) => parent.eq_ignore_ascii_case(id),
+                        _ => false,
+                    })
+                    .collect(),
+                done: self
+                    .comment_extended(comment)
+                    .and_then(|ext| ext.done)
+                    .unwrap_or(false),
+            })
+            .collect()
+    }
+
+    /// Adds a reply to the comment with id `parent`, returns its id
+    ///
+    /// The reply is anchored to the same range as the thread. Returns
+    /// `None` if there's no such comment.
+    ///
+    /// ```rust
+    /// use docx::comments::Comment;
+    /// use docx::document::*;
+    /// use docx::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let id = docx.add_comment(Comment::new("Reviewer").push_text("Typo?"));
+    ///
+    /// let mut para = Paragraph::default().push_text("Lorem ipsum");
+    /// para.anchor_comment(id, 0..1);
+    /// docx.document.push(para);
+    ///
+    /// docx.reply_to_comment(id, Comment::new("Author").push_text("Fixed."));
+    /// docx.resolve_comment(id, true);
+    ///
+    /// let threads = docx.comment_threads();
+    /// assert_eq!(threads[0].replies.len(), 1);
+    /// assert!(threads[0].done);
+    /// ```
+    pub fn reply_to_comment(&mut self, parent: usize, mut reply: Comment<'a>) -> Option<usize> {
+        let mut used = self.hex_ids();
+        let comments = self.comments.as_mut()?;
+        let parent_para_id = comments
+            .comments
+            .iter_mut()
+            .find(|comment| comment.id == parent)?
+            .ensure_para_id(&mut used);
+        let para_id = reply.ensure_para_id(&mut used);
+        let author = reply.author.clone();
+        let id = comments.push(reply);
+
+        // threads are flat, replies to a reply belong to its thread
+        let root = self.register_comment(&parent_para_id, &mut used);
+        let root = root.parent.clone().unwrap_or(root.para_id.clone());
+        self.register_comment(&para_id, &mut used).parent = Some(root);
+
+        self.document.anchor_reply(parent, id);
+        self.people.get_or_insert_with(People::default).add(author);
+
+        Some(id)
+    }
+
+    /// Marks the comment thread as resolved or not
+    ///
+    /// Returns `false` if there's no such comment.
+    pub fn resolve_comment(&mut self, id: usize, done: bool) -> bool {
+        let mut used = self.hex_ids();
+        let para_id = match self
+            .comments
+            .as_mut()
+            .and_then(|comments| comments.comments.iter_mut().find(|c| c.id == id))
+        {
+            Some(comment) => comment.ensure_para_id(&mut used),
+            None => return false,
         };
 
-        let font_table = if let Some(content) = &self.font_table {
-            Some(FontTable::from_str(content)?)
-        } else {
-            None
+        self.register_comment(&para_id, &mut used).done = Some(done);
+
+        true
+    }
+
+    /// Returns the paragraph ids and the durable ids of comments in use,
+    /// which new ids are derived from
+    fn hex_ids(&self) -> HashSet<u32> {
+        let mut used = HashSet::new();
+
+        let mut insert = |id: &str| {
+            if let Ok(id) = u32::from_str_radix(id, 16) {
+                used.insert(id);
+            }
+        };
+        let mut visit = |content: &[BodyContent]| {
+            for_each_paragraph(content, &mut |para| {
+                if let Some(id) = &para.para_id {
+                    insert(id);
+                }
+            })
         };
 
-        let rels = Relationships::from_str(&self.rels)?;
+        visit(&self.document.body.content);
+        for (_, header) in &self.headers {
+            visit(&header.content);
+        }
+        for (_, footer) in &self.footers {
+            visit(&footer.content);
+        }
+        for note in self.footnotes.iter().flat_map(|notes| &notes.notes) {
+            visit(&note.content);
+        }
+        for note in self.endnotes.iter().flat_map(|notes| &notes.notes) {
+            visit(&note.content);
+        }
+        for comment in self.comments.iter().flat_map(|comments| &comments.comments) {
+            visit(&comment.content);
+        }
 
-        let styles = self
-            .styles
-            .as_ref()
-            .map(|content| Styles::from_str(&content))
-            .transpose()?
-            .unwrap_or_default();
+        for id in self.comments_ids.iter().flat_map(|ids| &ids.ids) {
+            if let Ok(id) = u32::from_str_radix(&id.durable_id, 16) {
+                used.insert(id);
+            }
+        }
 
-        Ok(Docx {
-            app,
-            content_types,
-            core,
-            document,
-            document_rels,
-            font_table,
-            rels,
-            styles,
-        })
+        used
+    }
+
+    fn comment_extended(&self, comment: &Comment) -> Option<&CommentExtended<'a>> {
+        self.comments_extended.as_ref()?.get(comment.para_id()?)
+    }
+
+    /// Returns the extended properties of the comment, adds a durable id
+    /// for it if the comments ids part exists
+    fn register_comment(
+        &mut self,
+        para_id: &str,
+        used: &mut HashSet<u32>,
+    ) -> &mut CommentExtended<'a> {
+        if let Some(ids) = &mut self.comments_ids {
+            if ids.get(para_id).is_none() {
+                ids.ids.push(CommentId {
+                    para_id: para_id.to_owned().into(),
+                    durable_id: new_hex_id(used).into(),
+                });
+            }
+        }
+
+        self.comments_extended
+            .get_or_insert_with(CommentsExtended::default)
+            .get_or_insert(para_id)
+    }
+
+    /// Accepts all tracked changes in the document
+    ///
+    /// Insertions are kept and deletions are removed, along with the
+    /// recorded formatting changes.
+    pub fn accept_all_revisions(&mut self) {
+        self.resolve_revisions(true, None);
+    }
+
+    /// Rejects all tracked changes in the document
+    ///
+    /// Insertions are removed, deletions are restored and formatting
+    /// changes are reverted.
+    pub fn reject_all_revisions(&mut self) {
+        self.resolve_revisions(false, None);
+    }
+
+    /// Accepts the tracked changes made by the given author
+    pub fn accept_revisions_by(&mut self, author: &str) {
+        self.resolve_revisions(true, Some(author));
+    }
+
+    /// Rejects the tracked changes made by the given author
+    pub fn reject_revisions_by(&mut self, author: &str) {
+        self.resolve_revisions(false, Some(author));
+    }
+
+    /// Compares two versions of a document
+    ///
+    /// Returns the revised document, whose body records the differences
+    /// from the original one as tracked changes by the author of `changes`.
+    /// Existing tracked changes of both documents are accepted first, and
+    /// parts other than the body are taken from the revised document. So
+    /// deleted content keeps its text only: its hyperlinks become plain
+    /// runs, and its images, notes and comments are dropped.
+    pub fn compare(
+        mut original: Docx<'a>,
+        mut revised: Docx<'a>,
+        changes: &mut TrackChanges<'a>,
+    ) -> Docx<'a> {
+        original.accept_all_revisions();
+        revised.accept_all_revisions();
+
+        let content = std::mem::take(&mut revised.document.body.content);
+        revised.document.body.content =
+            compare_content(original.document.body.content, content, changes);
+
+        revised
+    }
+
+    /// Returns a tracked editing mode for the given author
+    ///
+    /// Its revision ids follow the ones of existing tracked changes.
+    pub fn track_changes<T: Into<Cow<'a, str>>>(&self, author: T) -> TrackChanges<'a> {
+        let mut max = max_revision_id(&self.document.body.content);
+
+        for (_, header) in &self.headers {
+            max = max.max(max_revision_id(&header.content));
+        }
+
+        for (_, footer) in &self.footers {
+            max = max.max(max_revision_id(&footer.content));
+        }
+
+        if let Some(footnotes) = &self.footnotes {
+            for note in &footnotes.notes {
+                max = max.max(max_revision_id(&note.content));
+            }
+        }
+
+        if let Some(endnotes) = &self.endnotes {
+            for note in &endnotes.notes {
+                max = max.max(max_revision_id(&note.content));
+            }
+        }
+
+        if let Some(comments) = &self.comments {
+            for comment in &comments.comments {
+                max = max.max(max_revision_id(&comment.content));
+            }
+        }
+
+        TrackChanges::new(author).next_id(max.map_or(0, |id| id + 1))
+    }
+
+    /// Returns the content of the body, headers, footers, notes and comments
+    fn content_mut(&mut self) -> Vec<&mut Vec<BodyContent<'a>>> {
+        let mut content = vec![&mut self.document.body.content];
+
+        content.extend(
+            self.headers
+                .iter_mut()
+                .map(|(_, header)| &mut header.content),
+        );
+        content.extend(
+            self.footers
+                .iter_mut()
+                .map(|(_, footer)| &mut footer.content),
+        );
+
+        if let Some(footnotes) = &mut self.footnotes {
+            content.extend(footnotes.notes.iter_mut().map(|note| &mut note.content));
+        }
+
+        if let Some(endnotes) = &mut self.endnotes {
+            content.extend(endnotes.notes.iter_mut().map(|note| &mut note.content));
+        }
+
+        if let Some(comments) = &mut self.comments {
+            content.extend(
+                comments
+                    .comments
+                    .iter_mut()
+                    .map(|comment| &mut comment.content),
+            );
+        }
+
+        content
     }
+
+    /// Gives new ids to drawings whose id is zero or used by a previous
+    /// drawing, since Word requires them to be unique
+    fn allocate_drawing_ids(&mut self) {
+        let mut content = self.content_mut();
+
+        let mut max = 0;
+        for content in &mut content {
+            for_each_run_mut(content, &mut |run| {
+                for drawing in run.content.iter_mut().filter_map(drawing_mut) {
+                    if let Some(property) = drawing.doc_property() {
+                        max = max.max(property.id);
+                    }
+                }
+            });
+        }
+
+        let mut used = Vec::new();
+        for content in &mut content {
+            for_each_run_mut(content, &mut |run| {
+                for drawing in run.content.iter_mut().filter_map(drawing_mut) {
+                    if let Some(property) = drawing.doc_property_mut() {
+                        if property.id == 0 || used.contains(&property.id) {
+                            max += 1;
+                            property.id = max;
+                        }
+                        used.push(property.id);
+                    }
+                }
+            });
+        }
+    }
+
+    fn resolve_revisions(&mut self, accept: bool, author: Option<&str>) {
+        resolve_revisions(&mut self.document.body.content, accept, author);
+
+        for (_, header) in &mut self.headers {
+            resolve_revisions(&mut header.content, accept, author);
+        }
+
+        for (_, footer) in &mut self.footers {
+            resolve_revisions(&mut footer.content, accept, author);
+        }
+
+        if let Some(footnotes) = &mut self.footnotes {
+            for note in &mut footnotes.notes {
+                resolve_revisions(&mut note.content, accept, author);
+            }
+        }
+
+        if let Some(endnotes) = &mut self.endnotes {
+            for note in &mut endnotes.notes {
+                resolve_revisions(&mut note.content, accept, author);
+            }
+        }
+
+        if let Some(comments) = &mut self.comments {
+            for comment in &mut comments.comments {
+                resolve_revisions(&mut comment.content, accept, author);
+            }
+        }
+    }
+
+    /// Adds a footnote with the given paragraphs, returns its id
+    ///
+    /// The footnotes part is created with its separator notes if needed.
+    /// The id is passed to `Run::push_footnote` to reference the note.
+    pub fn add_footnote<I: IntoIterator<Item = Paragraph<'a>>>(&mut self, content: I) -> isize {
+        self.footnotes
+            .get_or_insert_with(Footnotes::default)
+            .push(content)
+    }
+
+    /// Adds an endnote with the given paragraphs, returns its id
+    ///
+    /// The endnotes part is created with its separator notes if needed.
+    /// The id is passed to `Run::push_endnote` to reference the note.
+    pub fn add_endnote<I: IntoIterator<Item = Paragraph<'a>>>(&mut self, content: I) -> isize {
+        self.endnotes
+            .get_or_insert_with(Endnotes::default)
+            .push(content)
+    }
+
+    /// Returns the header with the given relationship id
+    pub fn header(&self, id: &str) -> Option<&Header<'a>> {
+        self.headers
+            .iter()
+            .find(|(rel_id, _)| rel_id == id)
+            .map(|(_, header)| header)
+    }
+
+    /// Returns the footer with the given relationship id
+    pub fn footer(&self, id: &str) -> Option<&Footer<'a>> {
+        self.footers
+            .iter()
+            .find(|(rel_id, _)| rel_id == id)
+            .map(|(_, footer)| footer)
+    }
+
+    /// Updates the text of content controls from the custom XML parts
+    /// which they're bound to
+    ///
+    /// Controls bound to missing parts or nodes are left untouched.
+    pub fn update_data_bindings(&mut self) -> DocxResult<()> {
+        let custom_xml = &self.custom_xml;
+
+        for_each_bound_control(&mut self.document.body.content, &mut |property, _| {
+            let binding = match &property.data_binding {
+                Some(binding) => binding,
+                None => return Ok(None),
+            };
+
+            match find_custom_xml(custom_xml, &binding.store_item_id) {
+                Some(xml) => xml.get(&binding.xpath, binding.prefix_mappings.as_deref()),
+                None => Ok(None),
+            }
+        })
+    }
+
+    /// Updates the custom XML parts from the text of content controls
+    /// which are bound to them
+    ///
+    /// Controls bound to missing parts or nodes are ignored.
+    pub fn update_custom_xml(&mut self) -> DocxResult<()> {
+        let custom_xml = &mut self.custom_xml;
+
+        for_each_bound_control(&mut self.document.body.content, &mut |property, text| {
+            if let Some(binding) = &property.data_binding {
+                if let Some(xml) = find_custom_xml_mut(custom_xml, &binding.store_item_id) {
+                    xml.set(&binding.xpath, binding.prefix_mappings.as_deref(), &text)?;
+                }
+            }
+
+            Ok(None)
+        })
+    }
+
+    pub fn write<W: Write + Seek>(&mut self, writer: W) -> DocxResult<W> {
+        let mut writer = XmlWriter::new(ZipWriter::new(writer));
+
+        self.write_package(&mut writer)?;
+
+        Ok(writer.inner.finish()?)
+    }
+
+    /// Writes the package into a non-seekable writer
+    ///
+    /// Each part is compressed and written as soon as it's generated, with
+    /// its size and checksum stored in a trailing data descriptor. This
+    /// allows streaming documents into stdout or a chunked HTTP response
+    /// without buffering the whole document in memory.
+    pub fn write_stream<W: Write>(&mut self, writer: W) -> DocxResult<W> {
+        let mut writer = XmlWriter::new(StreamWriter::new(writer));
+
+        self.write_package(&mut writer)?;
+
+        Ok(writer.inner.finish()?)
+    }
+
+    fn write_package<W: PackageWriter>(&mut self, mut writer: &mut XmlWriter<W>) -> DocxResult<()> {
+        if self.set_modified {
+            self.core.get_or_insert_with(Core::default).modified = Some(DateTime::now());
+        }
+
+        if self.update_statistics {
+            self.app
+                .get_or_insert(App::default())
+                .update_statistics(&self.document);
+        }
+
+        self.allocate_drawing_ids();
+
+        // ==== Add Relationships ====
+
+        if self.app.is_some() {
+            self.rels.add_rel(SCHEMA_REL_EXTENDED, "docProps/app.xml");
+        }
+
+        if self.core.is_some() {
+            self.rels.add_rel(SCHEMA_CORE, "docProps/core.xml");
+        }
+
+        if self.custom.is_some() {
+            self.rels.add_rel(SCHEMA_REL_CUSTOM, "docProps/custom.xml");
+        }
+
+        self.rels
+            .add_rel(SCHEMA_OFFICE_DOCUMENT, "word/document.xml");
+
+        self.document_rels
+            .get_or_insert(Relationships::default())
+            .add_rel(SCHEMA_STYLES, "styles.xml");
+
+        if self.font_table.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_FONT_TABLE, "fontTable.xml");
+        }
+
+        if self.settings.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_SETTINGS, "settings.xml");
+        }
+
+        if self.comments.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_COMMENTS, "comments.xml");
+        }
+
+        if self.comments_extended.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_COMMENTS_EXTENDED, "commentsExtended.xml");
+        }
+
+        if self.comments_ids.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_COMMENTS_IDS, "commentsIds.xml");
+        }
+
+        if self.people.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_PEOPLE, "people.xml");
+        }
+
+        if self.footnotes.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_FOOTNOTES, "footnotes.xml");
+        }
+
+        if self.endnotes.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_ENDNOTES, "endnotes.xml");
+        }
+
+        // custom XML parts are renumbered when writing
+        let document_rels = self.document_rels.get_or_insert(Relationships::default());
+        document_rels
+            .relationships
+            .retain(|rel| rel.ty != SCHEMA_REL_CUSTOM_XML);
+        for i in 1..=self.custom_xml.len() {
+            document_rels.add_rel(SCHEMA_REL_CUSTOM_XML, format!("../customXml/item{}.xml", i));
+        }
+
+        // header and footer parts are renumbered when writing, but keep
+        // their relationship ids which sections refer to
+        let document_rels = self.document_rels.get_or_insert(Relationships::default());
+        sync_part_rels(
+            document_rels,
+            SCHEMA_HEADER,
+            "header",
+            self.headers.iter().map(|(id, _)| id),
+        );
+        sync_part_rels(
+            document_rels,
+            SCHEMA_FOOTER,
+            "footer",
+            self.footers.iter().map(|(id, _)| id),
+        );
+
+        // ==== Add Content Types ====
+
+        if self.custom.is_some() {
+            self.content_types
+                .add_override("/docProps/custom.xml", CONTENT_TYPE_CUSTOM);
+        }
+
+        if self.settings.is_some() {
+            self.content_types
+                .add_override("/word/settings.xml", CONTENT_TYPE_SETTINGS);
+        }
+
+        if self.comments.is_some() {
+            self.content_types
+                .add_override("/word/comments.xml", CONTENT_TYPE_COMMENTS);
+        }
+
+        if self.comments_extended.is_some() {
+            self.content_types
+                .add_override("/word/commentsExtended.xml", CONTENT_TYPE_COMMENTS_EXTENDED);
+        }
+
+        if self.comments_ids.is_some() {
+            self.content_types
+                .add_override("/word/commentsIds.xml", CONTENT_TYPE_COMMENTS_IDS);
+        }
+
+        if self.people.is_some() {
+            self.content_types
+                .add_override("/word/people.xml", CONTENT_TYPE_PEOPLE);
+        }
+
+        if self.footnotes.is_some() {
+            self.content_types
+                .add_override("/word/footnotes.xml", CONTENT_TYPE_FOOTNOTES);
+        }
+
+        if self.endnotes.is_some() {
+            self.content_types
+                .add_override("/word/endnotes.xml", CONTENT_TYPE_ENDNOTES);
+        }
+
+        self.content_types.overrides.retain(|ele| {
+            !ele.part.starts_with("/customXml/")
+                && !ele.part.starts_with("/word/header")
+                && !ele.part.starts_with("/word/footer")
+        });
+        for i in 1..=self.headers.len() {
+            self.content_types
+                .add_override(format!("/word/header{}.xml", i), CONTENT_TYPE_HEADER);
+        }
+        for i in 1..=self.footers.len() {
+            self.content_types
+                .add_override(format!("/word/footer{}.xml", i), CONTENT_TYPE_FOOTER);
+        }
+        for (i, xml) in self.custom_xml.iter().enumerate() {
+            if xml.properties.is_some() {
+                self.content_types.add_override(
+                    format!("/customXml/itemProps{}.xml", i + 1),
+                    CONTENT_TYPE_CUSTOM_XML_PROPERTIES,
+                );
+            }
+        }
+
+        for media in &self.media {
+            if let (Some(ext), Some(ty)) = (media.extension(), media.content_type()) {
+                self.content_types.add_default(ext, ty);
+            }
+        }
+
+        for chart in &self.charts {
+            self.content_types
+                .add_override(format!("/{}", chart.name), CONTENT_TYPE_CHART);
+        }
+
+        for media in &self.embeddings {
+            if media.extension().as_deref() == Some("xlsx") {
+                self.content_types.add_default("xlsx", CONTENT_TYPE_XLSX);
+            }
+        }
+
+        // ==== Write Zip Item ====
+
+        macro_rules! write_xml {
+            (Some($xml:expr) => $name:tt) => {
+                if let Some(ref xml) = $xml {
+                    write_xml!(xml => $name);
+                }
+            };
+            (Some($xml:expr) => $name:tt $($rest:tt)*) => {
+                write_xml!(Some($xml) => $name);
+                write_xml!($($rest)*);
+            };
+            ($xml:expr => $name:tt) => {
+                writer.inner.start_part($name)?;
+                $xml.to_writer(&mut writer)?;
+            };
+            ($xml:expr => $name:tt $($rest:tt)*) => {
+                write_xml!($xml => $name);
+                write_xml!($($rest)*);
+            };
+        }
+
+        write_xml!(
+            self.content_types        => "[Content_Types].xml"
+            Some(self.app)            => "docProps/app.xml"
+            Some(self.core)           => "docProps/core.xml"
+            Some(self.custom)         => "docProps/custom.xml"
+            self.rels                 => "_rels/.rels"
+            self.document             => "word/document.xml"
+            self.styles               => "word/styles.xml"
+            Some(self.font_table)     => "word/fontTable.xml"
+            Some(self.settings)       => "word/settings.xml"
+            Some(self.comments)       => "word/comments.xml"
+            Some(self.comments_rels)  => "word/_rels/comments.xml.rels"
+            Some(self.comments_extended) => "word/commentsExtended.xml"
+            Some(self.comments_ids)   => "word/commentsIds.xml"
+            Some(self.people)         => "word/people.xml"
+            Some(self.footnotes)      => "word/footnotes.xml"
+            Some(self.footnotes_rels) => "word/_rels/footnotes.xml.rels"
+            Some(self.endnotes)       => "word/endnotes.xml"
+            Some(self.endnotes_rels)  => "word/_rels/endnotes.xml.rels"
+            Some(self.document_rels)  => "word/_rels/document.xml.rels"
+        );
+
+        for (i, (id, header)) in self.headers.iter().enumerate() {
+            writer
+                .inner
+                .start_part(&format!("word/header{}.xml", i + 1))?;
+            header.to_writer(writer)?;
+
+            if let Some(rels) = find_part_rels(&self.header_rels, id) {
+                writer
+                    .inner
+                    .start_part(&format!("word/_rels/header{}.xml.rels", i + 1))?;
+                rels.to_writer(writer)?;
+            }
+        }
+
+        for (i, (id, footer)) in self.footers.iter().enumerate() {
+            writer
+                .inner
+                .start_part(&format!("word/footer{}.xml", i + 1))?;
+            footer.to_writer(writer)?;
+
+            if let Some(rels) = find_part_rels(&self.footer_rels, id) {
+                writer
+                    .inner
+                    .start_part(&format!("word/_rels/footer{}.xml.rels", i + 1))?;
+                rels.to_writer(writer)?;
+            }
+        }
+
+        for (i, xml) in self.custom_xml.iter().enumerate() {
+            writer
+                .inner
+                .start_part(&format!("customXml/item{}.xml", i + 1))?;
+            writer
+                .inner
+                .write_all(utf8_declaration(&xml.data).as_bytes())?;
+
+            if let Some(properties) = &xml.properties {
+                let mut rels = Relationships::default();
+                rels.add_rel(
+                    SCHEMA_REL_CUSTOM_XML_PROPS,
+                    format!("itemProps{}.xml", i + 1),
+                );
+
+                writer
+                    .inner
+                    .start_part(&format!("customXml/itemProps{}.xml", i + 1))?;
+                properties.to_writer(writer)?;
+
+                writer
+                    .inner
+                    .start_part(&format!("customXml/_rels/item{}.xml.rels", i + 1))?;
+                rels.to_writer(writer)?;
+            }
+        }
+
+        for media in self.media.iter().chain(&self.embeddings) {
+            writer.inner.start_part(&media.name)?;
+            writer.inner.write_all(&media.data)?;
+        }
+
+        for chart in &self.charts {
+            writer.inner.start_part(&chart.name)?;
+            writer.inner.write_all(chart.data.as_bytes())?;
+
+            if let Some(rels) = &chart.rels {
+                writer.inner.start_part(&rels_name(&chart.name))?;
+                rels.to_writer(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn write_file<P: AsRef<Path>>(&mut self, path: P) -> DocxResult<File> {
+        let file = File::create(path)?;
+        self.write(file)
+    }
+
+    /// Writes the package encrypted with the given password
+    ///
+    /// The package is encrypted using the agile encryption with AES-256 and
+    /// SHA-512, just like what Office does.
+    #[cfg(feature = "encryption")]
+    pub fn write_encrypted<W: Write>(&mut self, mut writer: W, password: &str) -> DocxResult<W> {
+        let package = self.write(Cursor::new(Vec::new()))?.into_inner();
+
+        let encrypted =
+            crate::encryption::encrypt(&package, password, crate::encryption::SPIN_COUNT)?;
+        writer.write_all(&encrypted)?;
+
+        Ok(writer)
+    }
+
+    /// Writes the package encrypted with the given password into a file
+    #[cfg(feature = "encryption")]
+    pub fn write_encrypted_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        password: &str,
+    ) -> DocxResult<File> {
+        let file = File::create(path)?;
+        self.write_encrypted(file, password)
+    }
+
+    /// Writes the package into an async writer
+    ///
+    /// The package is generated in memory first, then written into `writer`
+    /// without blocking the executor.
+    #[cfg(feature = "async")]
+    pub async fn write_async<W: AsyncWrite + Unpin>(&mut self, mut writer: W) -> DocxResult<W> {
+        let buffer = self.write_stream(Vec::new())?;
+
+        writer.write_all(&buffer).await?;
+        writer.flush().await?;
+
+        Ok(writer)
+    }
+}
+
+/// A ZIP writer which accepts the parts of a package
+trait PackageWriter: Write {
+    fn start_part(&mut self, name: &str) -> DocxResult<()>;
+}
+
+impl<W: Write + Seek> PackageWriter for ZipWriter<W> {
+    fn start_part(&mut self, name: &str) -> DocxResult<()> {
+        let opt = FileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .unix_permissions(0o755);
+
+        Ok(self.start_file(name, opt)?)
+    }
+}
+
+impl<W: Write> PackageWriter for StreamWriter<W> {
+    fn start_part(&mut self, name: &str) -> DocxResult<()> {
+        Ok(self.start_file(name)?)
+    }
+}
+
+/// An extracted docx file
+pub struct DocxFile {
+    app: Option<String>,
+    charts: Vec<(String, String, Option<String>)>,
+    comments: Option<String>,
+    comments_extended: Option<String>,
+    comments_ids: Option<String>,
+    comments_rels: Option<String>,
+    content_types: String,
+    core: Option<String>,
+    custom: Option<String>,
+    custom_xml: Vec<(String, Option<String>)>,
+    document: String,
+    document_rels: Option<String>,
+    embeddings: Vec<(String, Vec<u8>)>,
+    endnotes: Option<String>,
+    endnotes_rels: Option<String>,
+    font_table: Option<String>,
+    footers: Vec<(String, String, Option<String>)>,
+    footnotes: Option<String>,
+    footnotes_rels: Option<String>,
+    headers: Vec<(String, String, Option<String>)>,
+    media: Vec<(String, Vec<u8>)>,
+    people: Option<String>,
+    rels: String,
+    settings: Option<String>,
+    styles: Option<String>,
+}
+
+impl DocxFile {
+    /// Extracts from reader
+    ///
+    /// Returns `DocxError::Encrypted` if the reader contains a
+    /// password-encrypted package.
+    pub fn from_reader<T: Read + Seek>(mut reader: T) -> DocxResult<Self> {
+        if is_encrypted_package(&mut reader)? {
+            return Err(DocxError::Encrypted);
+        }
+
+        let mut zip = ZipArchive::new(reader)?;
+
+        macro_rules! read {
+            ($xml:tt, $name:expr) => {{
+                let mut file = zip.by_name($name)?;
+                let mut buffer = String::new();
+                file.read_to_string(&mut buffer)?;
+                buffer
+            }};
+        }
+
+        macro_rules! option_read {
+            ($xml:tt, $name:expr) => {
+                match zip.by_name($name) {
+                    Err(ZipError::FileNotFound) => None,
+                    Err(e) => return Err(e.into()),
+                    Ok(mut file) => {
+                        let mut buffer = String::new();
+                        file.read_to_string(&mut buffer)?;
+                        Some(buffer)
+                    }
+                };
+            };
+        }
+
+        let app = option_read!(App, "docProps/app.xml");
+        let comments = option_read!(Comments, "word/comments.xml");
+        let comments_extended = option_read!(CommentsExtended, "word/commentsExtended.xml");
+        let comments_ids = option_read!(CommentsIds, "word/commentsIds.xml");
+        let comments_rels = option_read!(Relationships, "word/_rels/comments.xml.rels");
+        let content_types = read!(ContentTypes, "[Content_Types].xml");
+        let core = option_read!(Core, "docProps/core.xml");
+        let custom = option_read!(Custom, "docProps/custom.xml");
+        let document_rels = option_read!(Relationships, "word/_rels/document.xml.rels");
+        let document = read!(Document, "word/document.xml");
+        let endnotes = option_read!(Endnotes, "word/endnotes.xml");
+        let endnotes_rels = option_read!(Relationships, "word/_rels/endnotes.xml.rels");
+        let font_table = option_read!(FontTable, "word/fontTable.xml");
+        let footnotes = option_read!(Footnotes, "word/footnotes.xml");
+        let footnotes_rels = option_read!(Relationships, "word/_rels/footnotes.xml.rels");
+        let people = option_read!(People, "word/people.xml");
+        let rels = read!(Relationships, "_rels/.rels");
+        let settings = option_read!(Settings, "word/settings.xml");
+        let styles = option_read!(Styles, "word/styles.xml");
+
+        let mut headers = Vec::new();
+        let mut footers = Vec::new();
+        if let Some(content) = &document_rels {
+            for rel in Relationships::from_str(content)?.relationships {
+                let parts = match &*rel.ty {
+                    SCHEMA_HEADER => &mut headers,
+                    SCHEMA_FOOTER => &mut footers,
+                    _ => continue,
+                };
+                let name = match rel.target.strip_prefix('/') {
+                    Some(target) => target.to_owned(),
+                    None => format!("word/{}", rel.target),
+                };
+                if let Some(content) = read_text(&mut zip, &name)? {
+                    let rels = read_text(&mut zip, &rels_name(&name))?;
+                    parts.push((rel.id.into_owned(), content, rels));
+                }
+            }
+        }
+
+        let mut items: Vec<(usize, &str)> = zip
+            .file_names()
+            .filter_map(|name| {
+                let n = name
+                    .strip_prefix("customXml/item")?
+                    .strip_suffix(".xml")?
+                    .parse()
+                    .ok()?;
+                Some((n, name))
+            })
+            .collect();
+        items.sort_unstable();
+        let items: Vec<usize> = items.into_iter().map(|(n, _)| n).collect();
+
+        let mut custom_xml = Vec::with_capacity(items.len());
+        for n in items {
+            let data =
+                read_text(&mut zip, &format!("customXml/item{}.xml", n))?.unwrap_or_default();
+            let rels = read_text(&mut zip, &format!("customXml/_rels/item{}.xml.rels", n))?;
+            let target = match rels {
+                Some(rels) => Relationships::from_str(&rels)?
+                    .relationships
+                    .iter()
+                    .find(|rel| rel.ty == SCHEMA_REL_CUSTOM_XML_PROPS)
+                    .map(|rel| rel.target.trim_start_matches('/').replace("customXml/", "")),
+                None => None,
+            };
+            let properties = match target {
+                Some(target) => read_text(&mut zip, &format!("customXml/{}", target))?,
+                None => None,
+            };
+            custom_xml.push((data, properties));
+        }
+
+        // images are usually stored in `word/media/`, but they can be stored
+        // anywhere a relationship of the parts refers to
+        let mut names: Vec<String> = zip
+            .file_names()
+            .filter(|name| name.starts_with("word/media/"))
+            .map(str::to_owned)
+            .collect();
+        let part_rels = document_rels
+            .iter()
+            .chain(headers.iter().filter_map(|(_, _, rels)| rels.as_ref()))
+            .chain(footers.iter().filter_map(|(_, _, rels)| rels.as_ref()))
+            .chain(&footnotes_rels)
+            .chain(&endnotes_rels)
+            .chain(&comments_rels);
+        for rels in part_rels {
+            for rel in Relationships::from_str(rels)?.relationships {
+                if rel.ty != SCHEMA_IMAGE {
+                    continue;
+                }
+                let name = part_name(&rel.target);
+                if !names.contains(&name) && zip.file_names().any(|file| file == name) {
+                    names.push(name);
+                }
+            }
+        }
+        names.sort_unstable();
+
+        let mut media = Vec::with_capacity(names.len());
+        for name in names {
+            let mut data = Vec::new();
+            zip.by_name(&name)?.read_to_end(&mut data)?;
+            media.push((name, data));
+        }
+
+        let mut names: Vec<String> = zip
+            .file_names()
+            .filter(|name| name.starts_with("word/embeddings/"))
+            .map(str::to_owned)
+            .collect();
+        names.sort_unstable();
+
+        let mut embeddings = Vec::with_capacity(names.len());
+        for name in names {
+            let mut data = Vec::new();
+            zip.by_name(&name)?.read_to_end(&mut data)?;
+            embeddings.push((name, data));
+        }
+
+        let mut names: Vec<String> = zip
+            .file_names()
+            .filter(|name| name.starts_with("word/charts/") && name.ends_with(".xml"))
+            .filter(|name| !name.starts_with("word/charts/_rels/"))
+            .map(str::to_owned)
+            .collect();
+        names.sort_unstable();
+
+        let mut charts = Vec::with_capacity(names.len());
+        for name in names {
+            let data = read_text(&mut zip, &name)?.unwrap_or_default();
+            let rels = read_text(&mut zip, &rels_name(&name))?;
+            charts.push((name, data, rels));
+        }
+
+        Ok(DocxFile {
+            app,
+            charts,
+            comments,
+            comments_extended,
+            comments_ids,
+            comments_rels,
+            content_types,
+            core,
+            custom,
+            custom_xml,
+            document_rels,
+            document,
+            embeddings,
+            endnotes,
+            endnotes_rels,
+            font_table,
+            footers,
+            footnotes,
+            footnotes_rels,
+            headers,
+            media,
+            people,
+            rels,
+            settings,
+            styles,
+        })
+    }
+
+    /// Extracts from file
+    #[inline]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> DocxResult<Self> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    /// Extracts from an encrypted package with the given password
+    #[cfg(feature = "encryption")]
+    pub fn from_encrypted_reader<T: Read + Seek>(reader: T, password: &str) -> DocxResult<Self> {
+        let package = crate::encryption::decrypt(reader, password)?;
+        Self::from_reader(Cursor::new(package))
+    }
+
+    /// Extracts from an encrypted file with the given password
+    #[cfg(feature = "encryption")]
+    #[inline]
+    pub fn from_encrypted_file<P: AsRef<Path>>(path: P, password: &str) -> DocxResult<Self> {
+        Self::from_encrypted_reader(File::open(path)?, password)
+    }
+
+    /// Extracts from async reader
+    ///
+    /// Since the central directory of a ZIP archive is located at its end,
+    /// the whole content is read into memory before extracting.
+    #[cfg(feature = "async")]
+    pub async fn from_async_reader<T: AsyncRead + Unpin>(mut reader: T) -> DocxResult<Self> {
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).await?;
+        Self::from_reader(Cursor::new(buffer))
+    }
+
+    /// Parses content into `Docx` struct
+    pub fn parse<'a>(&'a self) -> DocxResult<Docx<'a>> {
+        let app = if let Some(content) = &self.app {
+            Some(App::from_str(content)?)
+        } else {
+            None
+        };
+
+        let comments = if let Some(content) = &self.comments {
+            Some(Comments::from_str(content)?)
+        } else {
+            None
+        };
+
+        let comments_extended = if let Some(content) = &self.comments_extended {
+            Some(CommentsExtended::from_str(content)?)
+        } else {
+            None
+        };
+
+        let comments_ids = if let Some(content) = &self.comments_ids {
+            Some(CommentsIds::from_str(content)?)
+        } else {
+            None
+        };
+
+        let comments_rels = if let Some(content) = &self.comments_rels {
+            Some(Relationships::from_str(content)?)
+        } else {
+            None
+        };
+
+        let document = Document::from_str(&self.document)?;
+
+        let content_types = ContentTypes::from_str(&self.content_types)?;
+
+        let core = if let Some(content) = &self.core {
+            Some(Core::from_str(content)?)
+        } else {
+            None
+        };
+
+        let custom = if let Some(content) = &self.custom {
+            Some(Custom::from_str(content)?)
+        } else {
+            None
+        };
+
+        let custom_xml = self
+            .custom_xml
+            .iter()
+            .map(|(data, properties)| {
+                Ok(CustomXml {
+                    data: Cow::Borrowed(data),
+                    properties: properties
+                        .as_ref()
+                        .map(|content| DataStoreItem::from_str(content))
+                        .transpose()?,
+                })
+            })
+            .collect::<DocxResult<_>>()?;
+
+        let headers = self
+            .headers
+            .iter()
+            .map(|(id, content, _)| Ok((Cow::Borrowed(&**id), Header::from_str(content)?)))
+            .collect::<DocxResult<_>>()?;
+
+        let header_rels = parse_part_rels(&self.headers)?;
+
+        let footers = self
+            .footers
+            .iter()
+            .map(|(id, content, _)| Ok((Cow::Borrowed(&**id), Footer::from_str(content)?)))
+            .collect::<DocxResult<_>>()?;
+
+        let footer_rels = parse_part_rels(&self.footers)?;
+
+        let media = self
+            .media
+            .iter()
+            .map(|(name, data)| Media {
+                name: Cow::Borrowed(name),
+                data: Cow::Borrowed(data),
+            })
+            .collect();
+
+        let embeddings = self
+            .embeddings
+            .iter()
+            .map(|(name, data)| Media {
+                name: Cow::Borrowed(name),
+                data: Cow::Borrowed(data),
+            })
+            .collect();
+
+        let charts = self
+            .charts
+            .iter()
+            .map(|(name, data, rels)| {
+                Ok(ChartPart {
+                    name: Cow::Borrowed(name),
+                    data: Cow::Borrowed(data),
+                    rels: rels
+                        .as_ref()
+                        .map(|content| Relationships::from_str(content))
+                        .transpose()?,
+                })
+            })
+            .collect::<DocxResult<_>>()?;
+
+        let document_rels = if let Some(content) = &self.document_rels {
+            Some(Relationships::from_str(content)?)
+        } else {
+            None
+        };
+
+        let endnotes = if let Some(content) = &self.endnotes {
+            Some(Endnotes::from_str(content)?)
+        } else {
+            None
+        };
+
+        let endnotes_rels = if let Some(content) = &self.endnotes_rels {
+            Some(Relationships::from_str(content)?)
+        } else {
+            None
+        };
+
+        let font_table = if let Some(content) = &self.font_table {
+            Some(FontTable::from_str(content)?)
+        } else {
+            None
+        };
+
+        let footnotes = if let Some(content) = &self.footnotes {
+            Some(Footnotes::from_str(content)?)
+        } else {
+            None
+        };
+
+        let footnotes_rels = if let Some(content) = &self.footnotes_rels {
+            Some(Relationships::from_str(content)?)
+        } else {
+            None
+        };
+
+        let people = if let Some(content) = &self.people {
+            Some(People::from_str(content)?)
+        } else {
+            None
+        };
+
+        let rels = Relationships::from_str(&self.rels)?;
+
+        let settings = if let Some(content) = &self.settings {
+            Some(Settings::from_str(content)?)
+        } else {
+            None
+        };
+
+        let styles = self
+            .styles
+            .as_ref()
+            .map(|content| Styles::from_str(&content))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Docx {
+            app,
+            charts,
+            comments,
+            comments_extended,
+            comments_ids,
+            comments_rels,
+            content_types,
+            core,
+            custom,
+            custom_xml,
+            document,
+            document_rels,
+            embeddings,
+            endnotes,
+            endnotes_rels,
+            font_table,
+            footers,
+            footer_rels,
+            footnotes,
+            footnotes_rels,
+            headers,
+            header_rels,
+            media,
+            people,
+            rels,
+            settings,
+            styles,
+            set_modified: false,
+            update_statistics: false,
+        })
+    }
+}
+
+/// Adds a relationship to a new part named `{prefix}{n}.xml`, returns its id
+fn add_part_rel(rels: &mut Option<Relationships>, ty: &'static str, prefix: &str) -> String {
+    let rels = rels.get_or_insert(Relationships::default());
+
+    let target = (1..)
+        .map(|n| format!("{}{}.xml", prefix, n))
+        .find(|target| rels.relationships.iter().all(|rel| rel.target != *target))
+        .unwrap();
+
+    rels.add_rel(ty, target).to_owned()
+}
+
+/// Returns the relationships of the header or footer with the given id
+fn find_part_rels<'b, 'a>(
+    rels: &'b [(Cow<'a, str>, Relationships<'a>)],
+    id: &str,
+) -> Option<&'b Relationships<'a>> {
+    rels.iter()
+        .find(|(rel_id, _)| rel_id == id)
+        .map(|(_, rels)| rels)
+}
+
+/// Returns the relationships of the header or footer part with the given
+/// id, adding them if the part has none
+fn part_rels_mut<'b, 'a>(
+    rels: &'b mut Vec<(Cow<'a, str>, Relationships<'a>)>,
+    id: &str,
+) -> &'b mut Relationships<'a> {
+    let index = match rels.iter().position(|(rel_id, _)| rel_id == id) {
+        Some(index) => index,
+        None => {
+            rels.push((id.to_owned().into(), Relationships::default()));
+            rels.len() - 1
+        }
+    };
+    &mut rels[index].1
+}
+
+/// Returns the name of the part which a relationship of a part in `word/`
+/// targets
+fn part_name(target: &str) -> String {
+    match target.strip_prefix('/') {
+        Some(name) => name.to_owned(),
+        None => format!("word/{}", target),
+    }
+}
+
+/// Returns the target of a relationship of a part in `word/` to the part
+/// with the given name
+fn part_target(name: &str) -> String {
+    match name.strip_prefix("word/") {
+        Some(target) => target.to_owned(),
+        None => format!("/{}", name),
+    }
+}
+
+/// Parses the part-level relationships of header or footer parts
+fn parse_part_rels(
+    parts: &[(String, String, Option<String>)],
+) -> DocxResult<Vec<(Cow<'_, str>, Relationships<'_>)>> {
+    parts
+        .iter()
+        .filter_map(|(id, _, rels)| Some((id, rels.as_ref()?)))
+        .map(|(id, rels)| Ok((Cow::Borrowed(&**id), Relationships::from_str(rels)?)))
+        .collect()
+}
+
+/// Returns the drawing in the run content, including the drawing of a shape
+/// with its fallback
+fn drawing_mut<'b, 'a>(content: &'b mut RunContent<'a>) -> Option<&'b mut Drawing<'a>> {
+    match content {
+        RunContent::Drawing(drawing) => Some(drawing),
+        RunContent::AlternateContent(content) => content.drawing_mut(),
+        _ => None,
+    }
+}
+
+/// Returns the name of the part-level relationships part of a part
+fn rels_name(name: &str) -> String {
+    match name.rsplit_once('/') {
+        Some((dir, file)) => format!("{}/_rels/{}.rels", dir, file),
+        None => format!("_rels/{}.rels", name),
+    }
+}
+
+/// Points the relationships of type `ty` to parts named `{prefix}{n}.xml`,
+/// in the order of `ids`, and removes the ones not in `ids`
+fn sync_part_rels<'b, 'a: 'b, I>(rels: &mut Relationships<'a>, ty: &'a str, prefix: &str, ids: I)
+where
+    I: Iterator<Item = &'b Cow<'a, str>> + Clone,
+{
+    rels.relationships
+        .retain(|rel| rel.ty != ty || ids.clone().any(|id| *id == rel.id));
+
+    for (i, id) in ids.enumerate() {
+        let target = format!("{}{}.xml", prefix, i + 1);
+
+        match rels.relationships.iter_mut().find(|rel| rel.id == *id) {
+            Some(rel) => {
+                rel.ty = ty.into();
+                rel.target = target.into();
+            }
+            None => rels.relationships.push(Relationship {
+                id: id.clone(),
+                target: target.into(),
+                ty: ty.into(),
+            }),
+        }
+    }
+}
+
+/// Reads a part as text, returns `None` if it doesn't exist
+///
+/// Unlike other parts, custom XML parts aren't always encoded in UTF-8,
+/// so UTF-16 parts with a byte order mark are decoded as well.
+fn read_text<T: Read + Seek>(zip: &mut ZipArchive<T>, name: &str) -> DocxResult<Option<String>> {
+    let mut buffer = Vec::new();
+    match zip.by_name(name) {
+        Err(ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(e.into()),
+        Ok(mut file) => file.read_to_end(&mut buffer)?,
+    };
+
+    let decode_utf16 = |bytes: &[u8], be: bool| {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|c| {
+                if be {
+                    u16::from_be_bytes([c[0], c[1]])
+                } else {
+                    u16::from_le_bytes([c[0], c[1]])
+                }
+            })
+            .collect();
+        String::from_utf16(&units).map_err(|e| DocxError::CustomXml(e.to_string()))
+    };
+
+    let text = match buffer.as_slice() {
+        [0xef, 0xbb, 0xbf, rest @ ..] => String::from_utf8(rest.to_vec()),
+        [0xff, 0xfe, rest @ ..] => return decode_utf16(rest, false).map(Some),
+        [0xfe, 0xff, rest @ ..] => return decode_utf16(rest, true).map(Some),
+        _ => String::from_utf8(buffer),
+    };
+
+    text.map(Some)
+        .map_err(|e| DocxError::CustomXml(e.to_string()))
+}
+
+/// Returns the XML with an encoding declaration of UTF-8
+///
+/// Parts are always written in UTF-8, including custom XML parts read from
+/// UTF-16, whose declaration would state UTF-16 otherwise.
+fn utf8_declaration(xml: &str) -> Cow<'_, str> {
+    let declaration = match xml.find("?>") {
+        Some(end) if xml.starts_with("<?xml") => &xml[..end],
+        _ => return xml.into(),
+    };
+    let start = match declaration.find("encoding") {
+        Some(start) => start + "encoding".len(),
+        None => return xml.into(),
+    };
+
+    let rest = declaration[start..].trim_start();
+    let rest = match rest.strip_prefix('=') {
+        Some(rest) => rest.trim_start(),
+        None => return xml.into(),
+    };
+    let quote = match rest.chars().next() {
+        Some(quote) if quote == '"' || quote == '\'' => quote,
+        _ => return xml.into(),
+    };
+    let value_start = declaration.len() - rest.len() + 1;
+    let value_end = match declaration[value_start..].find(quote) {
+        Some(len) => value_start + len,
+        None => return xml.into(),
+    };
+
+    if declaration[value_start..value_end].eq_ignore_ascii_case("utf-8") {
+        return xml.into();
+    }
+
+    format!("{}UTF-8{}", &xml[..value_start], &xml[value_end..]).into()
+}
+
+fn find_custom_xml<'b, 'a>(items: &'b [CustomXml<'a>], id: &str) -> Option<&'b CustomXml<'a>> {
+    items
+        .iter()
+        .find(|xml| matches!(xml.item_id(), Some(item_id) if item_id.eq_ignore_ascii_case(id)))
+}
+
+fn find_custom_xml_mut<'b, 'a>(
+    items: &'b mut [CustomXml<'a>],
+    id: &str,
+) -> Option<&'b mut CustomXml<'a>> {
+    items
+        .iter_mut()
+        .find(|xml| matches!(xml.item_id(), Some(item_id) if item_id.eq_ignore_ascii_case(id)))
+}
+
+/// Calls `f` with the properties and text of every content control with a
+/// data binding, replaces the text of the control if `f` returns some.
+///
+/// The content of bound controls isn't visited.
+fn for_each_bound_control<'a, F>(content: &mut [BodyContent<'a>], f: &mut F) -> DocxResult<()>
+where
+    F: FnMut(&SdtProperty<'a>, String) -> DocxResult<Option<String>>,
+{
+    fn paragraph<'a, F>(content: &mut [ParagraphContent<'a>], f: &mut F) -> DocxResult<()>
+    where
+        F: FnMut(&SdtProperty<'a>, String) -> DocxResult<Option<String>>,
+    {
+        for content in content {
+            if let ParagraphContent::Sdt(sdt) = content {
+                if sdt.property.data_binding.is_some() {
+                    if let Some(text) = f(&sdt.property, sdt.text())? {
+                        sdt.set_text(text);
+                    }
+                } else {
+                    paragraph(&mut sdt.content.content, f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn table_cells<'a, F>(table: &mut Table<'a>, f: &mut F) -> DocxResult<()>
+    where
+        F: FnMut(&SdtProperty<'a>, String) -> DocxResult<Option<String>>,
+    {
+        for row in &mut table.rows {
+            for cell in &mut row.cells {
+                for content in &mut cell.content {
+                    match content {
+                        TableCellContent::Paragraph(para) => paragraph(&mut para.content, f)?,
+                        TableCellContent::Table(table) => table_cells(table, f)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    for content in content {
+        match content {
+            BodyContent::Paragraph(para) => paragraph(&mut para.content, f)?,
+            BodyContent::Table(table) => table_cells(table, f)?,
+            BodyContent::Sdt(sdt) => {
+                if sdt.property.data_binding.is_some() {
+                    if let Some(text) = f(&sdt.property, sdt.text())? {
+                        sdt.set_text(text);
+                    }
+                } else {
+                    for_each_bound_control(&mut sdt.content.content, f)?;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the reader contains an OLE compound file with an
+/// `EncryptionInfo` stream, which is how password-encrypted packages are
+/// stored, then rewinds the reader
+///
+/// Other compound files, e.g. legacy `.doc` files, return `false`.
+pub(crate) fn is_encrypted_package<R: Read + Seek>(reader: &mut R) -> DocxResult<bool> {
+    let position = reader.stream_position()?;
+    let result = has_encryption_info(reader);
+    reader.seek(SeekFrom::Start(position))?;
+    result
+}
+
+fn has_encryption_info<R: Read + Seek>(reader: &mut R) -> DocxResult<bool> {
+    const SIGNATURE: [u8; 8] = [0xd0, 0xcf, 0x11, 0xe0, 0xa1, 0xb1, 0x1a, 0xe1];
+    const HEADER_DIFAT_LEN: usize = 109;
+
+    let start = reader.stream_position()?;
+
+    let mut header = [0; 512];
+    let mut len = 0;
+    while len < header.len() {
+        match reader.read(&mut header[len..])? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    if len < header.len() || header[..8] != SIGNATURE {
+        return Ok(false);
+    }
+
+    let u32_at = |bytes: &[u8], offset: usize| {
+        u32::from_le_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    };
+
+    let sector_shift = u16::from_le_bytes([header[0x1e], header[0x1f]]);
+    if sector_shift != 9 && sector_shift != 12 {
+        return Ok(false);
+    }
+    let sector_size = 1usize << sector_shift;
+    let ids_per_sector = sector_size / 4;
+
+    // the number of sectors following the header, which caps the untrusted
+    // counts and sector ids in the file, special ids such as the end of a
+    // chain included
+    let end = reader.seek(SeekFrom::End(0))?;
+    let sector_count = (end.saturating_sub(start) >> sector_shift).saturating_sub(1);
+    let in_file = |id: u32| (id as u64) < sector_count;
+
+    let mut read_sector = |id: u32| -> DocxResult<Vec<u8>> {
+        let mut sector = vec![0; sector_size];
+        reader.seek(SeekFrom::Start(start + ((id as u64 + 1) << sector_shift)))?;
+        reader.read_exact(&mut sector)?;
+        Ok(sector)
+    };
+
+    // the locations of the sectors of the allocation table are stored in the
+    // header, followed by a chain of sectors for large files
+    let fat_count = (u32_at(&header, 0x2c) as u64).min(sector_count) as usize;
+    let mut fat_sectors: Vec<u32> = (0..HEADER_DIFAT_LEN.min(fat_count))
+        .map(|i| u32_at(&header, 0x4c + i * 4))
+        .collect();
+    let mut difat = u32_at(&header, 0x44);
+    let mut visited = HashSet::new();
+    while fat_sectors.len() < fat_count && in_file(difat) && visited.insert(difat) {
+        let sector = read_sector(difat)?;
+        for i in 0..ids_per_sector - 1 {
+            fat_sectors.push(u32_at(&sector, i * 4));
+        }
+        difat = u32_at(&sector, sector_size - 4);
+    }
+
+    // walks the chain of directory sectors, stopping at cycles
+    let mut directory = u32_at(&header, 0x30);
+    let mut visited = HashSet::new();
+    while in_file(directory) && visited.insert(directory) {
+        let sector = read_sector(directory)?;
+        for entry in sector.chunks_exact(128) {
+            let name_len = u16::from_le_bytes([entry[0x40], entry[0x41]]) as usize;
+            let name: Vec<u16> = entry[..name_len.min(64)]
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .take_while(|c| *c != 0)
+                .collect();
+            // a stream entry
+            if entry[0x42] == 2
+                && String::from_utf16_lossy(&name).eq_ignore_ascii_case("EncryptionInfo")
+            {
+                return Ok(true);
+            }
+        }
+
+        let fat_sector = match fat_sectors.get(directory as usize / ids_per_sector) {
+            Some(&fat_sector) if in_file(fat_sector) => fat_sector,
+            _ => break,
+        };
+        let fat = read_sector(fat_sector)?;
+        directory = u32_at(&fat, directory as usize % ids_per_sector * 4);
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+/// Builds a compound file with a single stream of the given name
+fn compound_file(stream: &str) -> Vec<u8> {
+    const FREE: u32 = 0xffff_ffff;
+    const END_OF_CHAIN: u32 = 0xffff_fffe;
+
+    let mut file = vec![0; 512 * 3];
+    let mut put = |offset: usize, bytes: &[u8]| {
+        file[offset..offset + bytes.len()].copy_from_slice(bytes);
+    };
+
+    // header
+    put(0, &[0xd0, 0xcf, 0x11, 0xe0, 0xa1, 0xb1, 0x1a, 0xe1]);
+    put(0x18, &[0x3e, 0, 3, 0, 0xfe, 0xff, 9, 0, 6, 0]);
+    put(0x2c, &1u32.to_le_bytes());
+    put(0x30, &1u32.to_le_bytes());
+    put(0x38, &4096u32.to_le_bytes());
+    put(0x3c, &END_OF_CHAIN.to_le_bytes());
+    put(0x44, &END_OF_CHAIN.to_le_bytes());
+    put(0x4c, &0u32.to_le_bytes());
+    for i in 1..109 {
+        put(0x4c + i * 4, &FREE.to_le_bytes());
+    }
+
+    // the allocation table in sector 0, followed by the directory
+    put(512, &0xffff_fffdu32.to_le_bytes());
+    put(516, &END_OF_CHAIN.to_le_bytes());
+    for i in 2..128 {
+        put(512 + i * 4, &FREE.to_le_bytes());
+    }
+
+    for (i, (name, ty)) in [("Root Entry", 5), (stream, 2)].iter().enumerate() {
+        let offset = 1024 + i * 128;
+        let name: Vec<u8> = name
+            .encode_utf16()
+            .chain(Some(0))
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        put(offset, &name);
+        put(offset + 0x40, &(name.len() as u16).to_le_bytes());
+        put(offset + 0x42, &[*ty]);
+    }
+
+    file
+}
+
+#[test]
+fn compound_files() -> DocxResult<()> {
+    let encrypted = compound_file("EncryptionInfo");
+    assert!(is_encrypted_package(&mut Cursor::new(&encrypted))?);
+    assert!(matches!(
+        DocxFile::from_reader(Cursor::new(&encrypted)),
+        Err(DocxError::Encrypted)
+    ));
+
+    // e.g. a legacy binary document
+    let legacy = compound_file("WordDocument");
+    assert!(!is_encrypted_package(&mut Cursor::new(&legacy))?);
+    assert!(matches!(
+        DocxFile::from_reader(Cursor::new(&legacy)),
+        Err(DocxError::Zip(_))
+    ));
+
+    assert!(!is_encrypted_package(&mut Cursor::new(b"PK"))?);
+
+    // a huge count of allocation table sectors, listed in a sector which
+    // refers to itself as the next one
+    let mut cyclic = compound_file("EncryptionInfo");
+    cyclic[0x2c..0x30].copy_from_slice(&0x0400_0000u32.to_le_bytes());
+    cyclic[0x44..0x48].copy_from_slice(&2u32.to_le_bytes());
+    cyclic.resize(cyclic.len() + 508, 0xff);
+    cyclic.extend(&2u32.to_le_bytes());
+    assert!(is_encrypted_package(&mut Cursor::new(&cyclic))?);
+
+    // chains beyond the end of the file
+    let mut truncated = compound_file("EncryptionInfo");
+    truncated[0x30..0x34].copy_from_slice(&9u32.to_le_bytes());
+    assert!(!is_encrypted_package(&mut Cursor::new(&truncated))?);
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_read_write() -> DocxResult<()> {
+    use crate::document::Paragraph;
+
+    let mut docx = Docx::default();
+    docx.document
+        .push(Paragraph::default().push_text("Lorem Ipsum"));
+
+    let buffer = docx.write_async(Vec::new()).await?;
+
+    let file = DocxFile::from_async_reader(&buffer[..]).await?;
+    let docx = file.parse()?;
+
+    assert_eq!(docx.document.body.content.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn stream_read_write() -> DocxResult<()> {
+    use crate::document::{BodyContent, Paragraph};
+
+    /// A writer which doesn't implement `Seek`
+    struct Sink(Vec<u8>);
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut docx = Docx::default();
+    docx.document
+        .push(Paragraph::default().push_text("Lorem Ipsum"));
+
+    let Sink(buffer) = docx.write_stream(Sink(Vec::new()))?;
+
+    let file = DocxFile::from_reader(Cursor::new(buffer))?;
+    let docx = file.parse()?;
+
+    assert_eq!(docx.document.body.content.len(), 1);
+    match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => {
+            assert_eq!(para.iter_text().collect::<Vec<_>>(), ["Lorem Ipsum"]);
+        }
+        content => panic!("unexpected content: {:?}", content),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn settings_part() -> DocxResult<()> {
+    use crate::settings::{Compat, CompatSetting};
+
+    let mut docx = Docx {
+        settings: Some(
+            Settings::default()
+                .update_fields(true)
+                .compat(Compat::default().push_setting(CompatSetting::compatibility_mode(15))),
+        ),
+        ..Default::default()
+    };
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    let settings = docx.settings.unwrap();
+    assert_eq!(settings.update_fields.unwrap().value, Some(true));
+    assert_eq!(settings.compat.unwrap().compatibility_mode(), Some(15));
+    assert!(docx
+        .content_types
+        .overrides
+        .iter()
+        .any(|ele| ele.part == "/word/settings.xml"));
+    assert_eq!(
+        docx.document_rels
+            .unwrap()
+            .relationships
+            .iter()
+            .filter(|rel| rel.ty == SCHEMA_SETTINGS)
+            .count(),
+        1
+    );
+
+    Ok(())
+}
+
+#[test]
+fn set_modified() -> DocxResult<()> {
+    let mut docx = Docx {
+        set_modified: true,
+        ..Default::default()
+    };
+
+    let before = DateTime::now().0.timestamp();
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    let modified = docx.core.unwrap().modified.unwrap();
+    assert!(modified.0.timestamp() >= before);
+
+    Ok(())
+}
+
+#[test]
+fn custom_part() -> DocxResult<()> {
+    use crate::custom::CustomValue;
+
+    let mut custom = Custom::default();
+    custom.set("ContractId", "C-1");
+
+    let mut docx = Docx {
+        custom: Some(custom),
+        ..Default::default()
+    };
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    assert_eq!(
+        docx.custom.unwrap().get("ContractId"),
+        Some(&CustomValue::LpWStr("C-1".into()))
+    );
+    assert!(docx
+        .rels
+        .relationships
+        .iter()
+        .any(|rel| rel.ty == SCHEMA_REL_CUSTOM && rel.target == "docProps/custom.xml"));
+
+    Ok(())
+}
+
+#[test]
+fn custom_xml_part() -> DocxResult<()> {
+    use crate::document::{DataBinding, Paragraph, Run, SdtBlock, SdtRun};
+
+    let xml = CustomXml::new(r#"<contract xmlns="urn:contract"><client>ACME</client></contract>"#)?;
+    let binding = DataBinding::new(
+        "/ns0:contract[1]/ns0:client[1]",
+        xml.item_id().unwrap().to_owned(),
+    )
+    .prefix_mappings("xmlns:ns0='urn:contract'");
+
+    let mut docx = Docx {
+        custom_xml: vec![xml],
+        ..Default::default()
+    };
+    docx.document.push(
+        Paragraph::default().push(
+            SdtRun::default()
+                .property(SdtProperty::default().data_binding(binding))
+                .push(Run::default().push_text("Client")),
+        ),
+    );
+
+    docx.update_data_bindings()?;
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let mut docx = file.parse()?;
+
+    assert_eq!(docx.custom_xml.len(), 1);
+    assert!(docx.custom_xml[0].item_id().is_some());
+    assert!(docx
+        .content_types
+        .overrides
+        .iter()
+        .any(|ele| ele.part == "/customXml/itemProps1.xml"));
+    assert!(docx
+        .document_rels
+        .as_ref()
+        .unwrap()
+        .relationships
+        .iter()
+        .any(|rel| rel.ty == SCHEMA_REL_CUSTOM_XML && rel.target == "../customXml/item1.xml"));
+
+    let sdt = match &mut docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => match &mut para.content[0] {
+            ParagraphContent::Sdt(sdt) => sdt,
+            _ => panic!("expected a content control"),
+        },
+        _ => panic!("expected a paragraph"),
+    };
+    assert_eq!(sdt.text(), "ACME");
+
+    sdt.set_text("Foo & Bar");
+    docx.update_custom_xml()?;
+
+    assert_eq!(
+        docx.custom_xml[0].data,
+        r#"<contract xmlns="urn:contract"><client>Foo &amp; Bar</client></contract>"#
+    );
+
+    // block-level controls bound to unknown parts are left untouched
+    docx.document.body.content.push(
+        SdtBlock::default()
+            .property(SdtProperty::default().data_binding(DataBinding::new("/contract[1]", "{0}")))
+            .push(Paragraph::default().push_text("Untouched"))
+            .into(),
+    );
+    docx.update_data_bindings()?;
+    match &docx.document.body.content[1] {
+        BodyContent::Sdt(sdt) => assert_eq!(sdt.text(), "Untouched"),
+        _ => panic!("expected a content control"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn utf16_custom_xml() -> DocxResult<()> {
+    let data = r#"<?xml version="1.0" encoding="utf-16"?><root>é</root>"#;
+    let mut bytes = vec![0xff, 0xfe];
+    bytes.extend(data.encode_utf16().flat_map(u16::to_le_bytes));
+
+    let mut zip = ZipArchive::new(Docx::default().write(Cursor::new(Vec::new()))?)?;
+    let mut buffer = ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..zip.len() {
+        buffer.raw_copy_file(zip.by_index(i)?)?;
+    }
+    buffer.start_file("customXml/item1.xml", FileOptions::default())?;
+    buffer.write_all(&bytes)?;
+    let buffer = buffer.finish()?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let mut docx = file.parse()?;
+    assert_eq!(docx.custom_xml[0].data, data);
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+    let mut zip = ZipArchive::new(buffer)?;
+    let mut written = String::new();
+    zip.by_name("customXml/item1.xml")?
+        .read_to_string(&mut written)?;
+    assert_eq!(
+        written,
+        r#"<?xml version="1.0" encoding="UTF-8"?><root>é</root>"#
+    );
+
+    assert_eq!(utf8_declaration("<root/>"), "<root/>");
+    assert_eq!(
+        utf8_declaration("<?xml version='1.0' encoding = 'UTF-16' ?><root/>"),
+        "<?xml version='1.0' encoding = 'UTF-8' ?><root/>"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn header_footer_parts() -> DocxResult<()> {
+    use crate::document::Paragraph;
+    use crate::formatting::{HeaderFooterType, SectionProperty};
+
+    let mut docx = Docx::default();
+
+    let mut header = Header::default();
+    header.push(Paragraph::default().push_text("Header"));
+    let header_id = docx.add_header(header);
+
+    let mut rels = Relationships::default();
+    rels.add_rel(
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+        "media/image1.png",
+    );
+    docx.header_rels.push((header_id.clone().into(), rels));
+
+    let mut first = Header::default();
+    first.push(Paragraph::default().push_text("First"));
+    let first_id = docx.add_header(first);
+
+    let footer_id = docx.add_footer(Footer::default());
+
+    assert_ne!(header_id, first_id);
+
+    docx.document.body.property = Some(
+        SectionProperty::default()
+            .push_header(HeaderFooterType::Default, header_id.clone())
+            .push_header(HeaderFooterType::First, first_id.clone())
+            .push_footer(HeaderFooterType::Default, footer_id.clone()),
+    );
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    assert_eq!(docx.headers.len(), 2);
+    assert_eq!(docx.footers.len(), 1);
+    assert!(docx.header(&first_id).is_some());
+    assert!(docx.footer(&footer_id).is_some());
+    assert_eq!(docx.header_rels.len(), 1);
+    assert_eq!(docx.header_rels[0].0, header_id);
+    assert_eq!(
+        docx.header_rels[0].1.relationships[0].target,
+        "media/image1.png"
+    );
+    assert!(docx
+        .content_types
+        .overrides
+        .iter()
+        .any(|ele| ele.part == "/word/header2.xml"));
+
+    let property = docx.document.body.property.as_ref().unwrap();
+    assert_eq!(property.headers.len(), 2);
+    assert_eq!(property.headers[1].id, first_id);
+    assert_eq!(property.title_page.as_ref().unwrap().value, Some(true));
+
+    Ok(())
+}
+
+#[test]
+fn footnote_parts() -> DocxResult<()> {
+    use crate::document::{Run, RunContent};
+
+    let mut docx = Docx::default();
+
+    let footnote = docx.add_footnote(vec![Paragraph::default().push_text("Footnote")]);
+    let endnote = docx.add_endnote(vec![Paragraph::default().push_text("Endnote")]);
+    assert_eq!(footnote, 1);
+    assert_eq!(endnote, 1);
+    assert_eq!(docx.add_footnote(vec![]), 2);
+
+    let mut rels = Relationships::default();
+    rels.add_rel(SCHEMA_IMAGE, "media/image1.png");
+    docx.footnotes_rels = Some(rels);
+
+    docx.document.push(
+        Paragraph::default().push(
+            Run::default()
+                .push_text("Lorem")
+                .push_footnote(footnote)
+                .push_endnote(endnote),
+        ),
+    );
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    let footnotes = docx.footnotes.as_ref().unwrap();
+    assert_eq!(footnotes.notes.len(), 4);
+    assert!(footnotes.get(-1).is_some());
+    assert!(footnotes.get(0).is_some());
+    assert!(footnotes.get(2).is_some());
+    assert_eq!(docx.endnotes.as_ref().unwrap().notes.len(), 3);
+    assert_eq!(
+        docx.footnotes_rels.as_ref().unwrap().relationships[0].target,
+        "media/image1.png"
+    );
+    assert!(docx.endnotes_rels.is_none());
+
+    assert!(docx
+        .document_rels
+        .as_ref()
+        .unwrap()
+        .relationships
+        .iter()
+        .any(|rel| rel.ty == SCHEMA_FOOTNOTES && rel.target == "footnotes.xml"));
+    assert!(docx
+        .content_types
+        .overrides
+        .iter()
+        .any(|ele| ele.part == "/word/endnotes.xml"));
+
+    match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => match &para.content[0] {
+            ParagraphContent::Run(run) => {
+                assert!(matches!(run.content[1], RunContent::FootnoteReference(_)));
+                assert!(matches!(run.content[2], RunContent::EndnoteReference(_)));
+            }
+            _ => panic!("expected a run"),
+        },
+        _ => panic!("expected a paragraph"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn comment_parts() -> DocxResult<()> {
+    let mut docx = Docx::default();
+
+    let first = docx.add_comment(Comment::new("foo").initials("f").push_text("First"));
+    let second = docx.add_comment(Comment::new("bar").push_text("Second"));
+    let unanchored = docx.add_comment(Comment::new("baz"));
+    assert_eq!((first, second, unanchored), (0, 1, 2));
+
+    let mut rels = Relationships::default();
+    rels.add_rel(SCHEMA_IMAGE, "media/image1.png");
+    docx.comments_rels = Some(rels);
+
+    let mut para = Paragraph::default()
+        .push_text("Lorem ")
+        .push_text("ipsum")
+        .push_text(" dolor");
+    para.anchor_comment(first, 1..3);
+    para.anchor_comment(second, 0..1);
+    docx.document.push(para);
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    assert_eq!(docx.comments.as_ref().unwrap().comments.len(), 3);
+    assert_eq!(
+        docx.comments_rels.as_ref().unwrap().relationships[0].target,
+        "media/image1.png"
+    );
+    assert!(docx
+        .content_types
+        .overrides
+        .iter()
+        .any(|ele| ele.part == "/word/comments.xml"));
+
+    let anchored = docx.anchored_comments();
+    assert_eq!(anchored.len(), 2);
+    assert_eq!(anchored[0].0.author, "bar");
+    assert_eq!(anchored[0].1, "Lorem ");
+    assert_eq!(anchored[1].0.initials.as_deref(), Some("f"));
+    assert_eq!(anchored[1].1, "ipsum dolor");
+
+    Ok(())
+}
+
+#[test]
+fn comment_threads() -> DocxResult<()> {
+    let mut docx = Docx {
+        comments_ids: Some(CommentsIds::default()),
+        ..Default::default()
+    };
+
+    let first = docx.add_comment(Comment::new("foo").push_text("First"));
+    let second = docx.add_comment(Comment::new("bar").push_text("Second"));
+
+    let mut para = Paragraph::default().push_text("Lorem").push_text("ipsum");
+    para.anchor_comment(first, 0..1);
+    para.anchor_comment(second, 4..5);
+    docx.document.push(para);
+
+    let reply = docx.reply_to_comment(first, Comment::new("bar").push_text("Reply"));
+    let nested = docx.reply_to_comment(reply.unwrap(), Comment::new("baz"));
+    assert_eq!((reply, nested), (Some(2), Some(3)));
+    assert_eq!(docx.reply_to_comment(9, Comment::new("baz")), None);
+    assert!(docx.resolve_comment(second, true));
+    assert!(!docx.resolve_comment(9, true));
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    let threads = docx.comment_threads();
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].comment.id, first);
+    assert_eq!(
+        threads[0]
+            .replies
+            .iter()
+            .map(|reply| reply.id)
+            .collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+    assert!(!threads[0].done);
+    assert_eq!(threads[1].comment.id, second);
+    assert!(threads[1].replies.is_empty());
+    assert!(threads[1].done);
+
+    assert_eq!(docx.comments_ids.as_ref().unwrap().ids.len(), 4);
+    assert_eq!(docx.people.as_ref().unwrap().people.len(), 2);
+
+    // replies share the anchor of their thread
+    let anchored = docx.anchored_comments();
+    assert_eq!(anchored.len(), 4);
+    assert!(anchored
+        .iter()
+        .filter(|(comment, _)| comment.id != second)
+        .all(|(_, text)| text == "Lorem"));
+    assert!(anchored
+        .iter()
+        .any(|(comment, text)| comment.id == second && text == "ipsum"));
+
+    Ok(())
+}
+
+#[test]
+fn image_parts() -> DocxResult<()> {
+    use crate::document::{Paragraph, Run};
+    use crate::media::png;
+
+    let mut docx = Docx::default();
+
+    let image = png(200, 100, None);
+    let first = docx.add_image(image.clone())?.description("Logo");
+    let second = docx.add_image(image)?;
+    let third = docx.add_image(png(10, 10, None))?;
+
+    assert_eq!(docx.media.len(), 2);
+    assert_eq!(docx.media[1].name, "word/media/image2.png");
+    assert_eq!(first.extent(), Some((1905000, 952500)));
+    assert_eq!(
+        first.picture().unwrap().embed(),
+        second.picture().unwrap().embed()
+    );
+    assert_ne!(
+        first.picture().unwrap().embed(),
+        third.picture().unwrap().embed()
+    );
+
+    docx.document.push(
+        Paragraph::default()
+            .push(Run::default().push(first))
+            .push(Run::default().push(second))
+            .push(Run::default().push(third)),
+    );
+
+    assert!(docx.add_image(&b"not an image"[..]).is_err());
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    assert_eq!(docx.media.len(), 2);
+    assert_eq!(&*docx.media[0].data, &*png(200, 100, None));
+    assert!(docx
+        .content_types
+        .defaults
+        .iter()
+        .any(|ele| ele.ext == "png" && ele.ty == "image/png"));
+
+    let mut drawings = Vec::new();
+    if let BodyContent::Paragraph(para) = &docx.document.body.content[0] {
+        for content in &para.content {
+            if let ParagraphContent::Run(run) = content {
+                for content in &run.content {
+                    if let RunContent::Drawing(drawing) = content {
+                        drawings.push(drawing);
+                    }
+                }
+            }
+        }
+    }
+
+    let ids: Vec<_> = drawings
+        .iter()
+        .map(|drawing| drawing.doc_property().unwrap().id)
+        .collect();
+    assert_eq!(ids, [1, 2, 3]);
+
+    let property = drawings[0].doc_property().unwrap();
+    assert_eq!(property.description.as_deref(), Some("Logo"));
+
+    let rels = docx.document_rels.as_ref().unwrap();
+    let embed = drawings[2].picture().unwrap().embed().unwrap();
+    let rel = rels
+        .relationships
+        .iter()
+        .find(|rel| rel.id == embed)
+        .unwrap();
+    assert_eq!(rel.ty, SCHEMA_IMAGE);
+    assert_eq!(rel.target, "media/image2.png");
+
+    Ok(())
+}
+
+// returns the drawing at the beginning of the body
+#[cfg(test)]
+fn first_drawing<'a>(docx: &'a Docx) -> &'a Drawing<'a> {
+    match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => match &para.content[0] {
+            ParagraphContent::Run(run) => match &run.content[0] {
+                RunContent::Drawing(drawing) => drawing,
+                _ => panic!("the run doesn't start with a drawing"),
+            },
+            _ => panic!("the paragraph doesn't start with a run"),
+        },
+        _ => panic!("the body doesn't start with a paragraph"),
+    }
+}
+
+#[test]
+fn svg_image_parts() -> DocxResult<()> {
+    use crate::document::{Paragraph, Run};
+    use crate::media::png;
+
+    let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10"/>"#;
+
+    let mut docx = Docx::default();
+
+    assert!(docx
+        .add_svg_image(&b"<html/>"[..], png(2, 1, None))
+        .is_err());
+
+    let drawing = docx.add_svg_image(&svg[..], png(20, 10, None))?;
+    docx.document
+        .push(Paragraph::default().push(Run::default().push(drawing)));
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    assert_eq!(docx.media.len(), 2);
+    assert!(docx
+        .content_types
+        .defaults
+        .iter()
+        .any(|ele| ele.ext == "svg" && ele.ty == "image/svg+xml"));
+
+    let drawing = first_drawing(&docx);
+
+    let picture = drawing.picture().unwrap();
+    let rels = &docx.document_rels.as_ref().unwrap().relationships;
+    let target = |id| &rels.iter().find(|rel| rel.id == id).unwrap().target;
+    assert_eq!(target(picture.embed().unwrap()), "media/image1.png");
+    assert_eq!(target(picture.svg_embed().unwrap()), "media/image2.svg");
+
+    Ok(())
+}
+
+#[test]
+fn media_references() -> DocxResult<()> {
+    use crate::document::{Paragraph, Run};
+    use crate::media::png;
+
+    let mut docx = Docx::default();
+
+    let drawing = docx.add_image(png(20, 10, None))?.description("Chart");
+    docx.document.push(
+        Paragraph::default()
+            .push_text("Figure 1")
+            .push(Run::default().push(drawing)),
+    );
+
+    // the header refers to the same image by its own relationship
+    let header_id = docx.add_header(Header::default());
+    let drawing = docx.add_header_image(&header_id, png(20, 10, None))?;
+    docx.headers[0]
+        .1
+        .push(Paragraph::default().push(Run::default().push(drawing)));
+    assert_eq!(docx.media.len(), 1);
+    assert_eq!(docx.header_rels[0].0, header_id);
+    assert!(docx.add_footer_image("rId9", png(20, 10, None)).is_err());
+
+    // images can be stored outside of `word/media/`
+    let footer_id = docx.add_footer(Footer::default());
+    let drawing = docx.add_footer_image(&footer_id, png(10, 10, None))?;
+    docx.footers[0]
+        .1
+        .push(Paragraph::default().push(Run::default().push(drawing)));
+    docx.media[1].name = "word/images/logo.png".into();
+    docx.footer_rels[0].1.relationships[0].target = "images/logo.png".into();
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let mut docx = file.parse()?;
+
+    let references = docx.media_references();
+    assert_eq!(references.len(), 3);
+
+    assert_eq!(references[0].source, MediaSource::Document);
+    assert_eq!(references[0].media.name, "word/media/image1.png");
+    assert_eq!(references[0].media.content_type(), Some("image/png"));
+    assert_eq!(references[0].description(), Some("Chart"));
+    assert_eq!(references[0].extent(), Some((190500, 95250)));
+    assert_eq!(references[0].image_info().unwrap().width, 20);
+    assert!(references[0].svg.is_none());
+    assert_eq!(
+        references[0].paragraph.iter_text().collect::<Vec<_>>(),
+        ["Figure 1"]
+    );
+
+    assert_eq!(references[1].source, MediaSource::Header(&header_id));
+    assert_eq!(references[1].media.name, "word/media/image1.png");
+
+    assert_eq!(references[2].source, MediaSource::Footer(&footer_id));
+    assert_eq!(references[2].media.name, "word/images/logo.png");
+    assert_eq!(references[2].image_info().unwrap().width, 10);
+
+    let jpeg = [
+        0xff, 0xd8, 0xff, 0xc0, 0x00, 0x11, 8, 0x00, 0x0a, 0x00, 0x14, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    assert!(!docx.replace_media("word/media/image9.png", &jpeg[..])?);
+    assert!(docx.replace_media("word/media/image1.png", &jpeg[..])?);
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    let references = docx.media_references();
+    assert_eq!(references.len(), 3);
+    assert_eq!(references[0].media.name, "word/media/image1.jpeg");
+    assert_eq!(references[1].media.name, "word/media/image1.jpeg");
+    assert_eq!(&*references[0].media.data, &jpeg[..]);
+    assert_eq!(references[0].extent(), Some((190500, 95250)));
+    assert!(docx
+        .content_types
+        .defaults
+        .iter()
+        .any(|ele| ele.ext == "jpeg" && ele.ty == "image/jpeg"));
+
+    Ok(())
+}
+
+#[test]
+fn chart_parts() -> DocxResult<()> {
+    use crate::chart::{ChartKind, Series};
+    use crate::document::{Paragraph, Run};
+
+    let mut docx = Docx::default();
+
+    assert!(docx
+        .add_chart(Chart::new(ChartKind::Bar), 100, 100)
+        .is_err());
+    let chart = Chart::new(ChartKind::Bar)
+        .categories(vec!["North"])
+        .push(Series::new("Sales", vec![f64::NAN]));
+    assert!(docx.add_chart(chart, 100, 100).is_err());
+    assert!(docx.charts.is_empty());
+
+    let chart = Chart::new(ChartKind::Bar)
+        .title("Sales & Costs")
+        .categories(vec!["North", "South"])
+        .push(Series::new("Sales", vec![10.0, 20.5]).color("4472C4"))
+        .push(Series::new("Costs", vec![8.0, 12.0]).color("ED7D31"));
+    let drawing = docx.add_chart(chart, 5486400, 3200400)?;
+    docx.document
+        .push(Paragraph::default().push(Run::default().push(drawing)));
+
+    let buffer = docx.write(Cursor::new(Vec::new()))?;
+
+    let file = DocxFile::from_reader(buffer)?;
+    let docx = file.parse()?;
+
+    let drawing = first_drawing(&docx);
+    assert_eq!(drawing.extent(), Some((5486400, 3200400)));
+
+    let rels = &docx.document_rels.as_ref().unwrap().relationships;
+    let rel = rels
+        .iter()
+        .find(|rel| rel.id == drawing.chart().unwrap().id)
+        .unwrap();
+    assert_eq!(rel.ty, SCHEMA_REL_CHART);
+    assert_eq!(rel.target, "charts/chart1.xml");
+
+    assert!(docx
+        .content_types
+        .overrides
+        .iter()
+        .any(|ele| ele.part == "/word/charts/chart1.xml" && ele.ty == CONTENT_TYPE_CHART));
+    assert!(docx
+        .content_types
+        .defaults
+        .iter()
+        .any(|ele| ele.ext == "xlsx" && ele.ty == CONTENT_TYPE_XLSX));
+
+    assert_eq!(docx.charts.len(), 1);
+    let chart = &docx.charts[0];
+    assert_eq!(chart.name, "word/charts/chart1.xml");
+    assert!(chart.data.contains(r#"<c:barDir val="bar"/>"#));
+    assert!(chart.data.contains("<a:t>Sales &amp; Costs</a:t>"));
+    assert!(chart.data.contains(r#"<c:externalData r:id="rId1">"#));
+    let rel = &chart.rels.as_ref().unwrap().relationships[0];
+    assert_eq!(rel.ty, SCHEMA_REL_PACKAGE);
+    assert_eq!(rel.target, "../embeddings/Microsoft_Excel_Worksheet1.xlsx");
+
+    assert_eq!(docx.embeddings.len(), 1);
+    let mut workbook = ZipArchive::new(Cursor::new(&*docx.embeddings[0].data))?;
+    let mut sheet = String::new();
+    workbook
+        .by_name("xl/worksheets/sheet1.xml")?
+        .read_to_string(&mut sheet)?;
+    assert!(sheet.contains(r#"<c r="C1" t="inlineStr"><is><t>Costs</t></is></c>"#));
+    assert!(sheet.contains(r#"<c r="A3" t="inlineStr"><is><t>South</t></is></c><c r="B3"><v>20.5</v></c><c r="C3"><v>12</v></c>"#));
+    assert!(workbook.by_name("xl/workbook.xml").is_ok());
+
+    Ok(())
 }
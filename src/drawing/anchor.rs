@@ -0,0 +1,176 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    drawing::{
+        DocProperty, EffectExtent, Extent, Graphic, HorizontalPosition, SimplePosition,
+        VerticalPosition, Wrap,
+    },
+};
+
+/// Anchored Drawing
+///
+/// Specifies a floating drawing, positioned relative to the page, the
+/// margins, the column or the paragraph containing it, which the text may
+/// wrap around.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let anchor = Anchor::default()
+///     .position_h(HorizontalPosition::align(
+///         HorizontalRelativeFrom::Margin,
+///         HorizontalAlign::Right,
+///     ))
+///     .position_v(VerticalPosition::offset(VerticalRelativeFrom::Paragraph, 0))
+///     .wrap(WrapSquare::default())
+///     .relative_height(2u32);
+///
+/// // behind the text
+/// let watermark = Anchor::default()
+///     .wrap(WrapNone::default())
+///     .behind_doc(true);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:anchor")]
+pub struct Anchor<'a> {
+    /// Specifies the distance from the text above in EMUs.
+    #[xml(attr = "distT")]
+    pub distance_top: Option<u64>,
+    /// Specifies the distance from the text below in EMUs.
+    #[xml(attr = "distB")]
+    pub distance_bottom: Option<u64>,
+    /// Specifies the distance from the text on the left in EMUs.
+    #[xml(attr = "distL")]
+    pub distance_left: Option<u64>,
+    /// Specifies the distance from the text on the right in EMUs.
+    #[xml(attr = "distR")]
+    pub distance_right: Option<u64>,
+    /// Specifies that the drawing is positioned by `simple_position`.
+    #[xml(attr = "simplePos")]
+    pub use_simple_position: Option<bool>,
+    /// Specifies the z-order, drawings with larger values are on top.
+    #[xml(default, attr = "relativeHeight")]
+    pub relative_height: u32,
+    /// Specifies that the drawing is behind the text, rather than in front
+    /// of it, if it doesn't wrap the text.
+    #[xml(default, attr = "behindDoc")]
+    pub behind_doc: bool,
+    /// Specifies that the anchor can't be moved to other paragraphs.
+    #[xml(default, attr = "locked")]
+    pub locked: bool,
+    /// Specifies that the drawing is positioned within the table cell
+    /// containing it.
+    #[xml(default, attr = "layoutInCell")]
+    pub layout_in_cell: bool,
+    /// Specifies that the drawing is hidden.
+    #[xml(attr = "hidden")]
+    pub hidden: Option<bool>,
+    /// Specifies that the drawing can overlap other floating objects.
+    #[xml(default, attr = "allowOverlap")]
+    pub allow_overlap: bool,
+    #[xml(default, child = "wp:simplePos")]
+    pub simple_position: SimplePosition,
+    /// Specifies the horizontal position.
+    #[xml(default, child = "wp:positionH")]
+    pub position_h: HorizontalPosition,
+    /// Specifies the vertical position.
+    #[xml(default, child = "wp:positionV")]
+    pub position_v: VerticalPosition,
+    /// Specifies the size of the drawing.
+    #[xml(default, child = "wp:extent")]
+    pub extent: Extent,
+    /// Specifies the extent added for effects.
+    #[xml(child = "wp:effectExtent")]
+    pub effect_extent: Option<EffectExtent>,
+    /// Specifies how the text wraps around the drawing.
+    #[xml(
+        default,
+        child = "wp:wrapNone",
+        child = "wp:wrapSquare",
+        child = "wp:wrapTight",
+        child = "wp:wrapThrough",
+        child = "wp:wrapTopAndBottom"
+    )]
+    pub wrap: Wrap,
+    /// Specifies the id, the name and the alternative text.
+    #[xml(default, child = "wp:docPr")]
+    pub doc_property: DocProperty<'a>,
+    /// Specifies the graphic object.
+    #[xml(default, child = "a:graphic")]
+    pub graphic: Graphic<'a>,
+}
+
+impl<'a> Default for Anchor<'a> {
+    fn default() -> Self {
+        Anchor {
+            distance_top: Some(0),
+            distance_bottom: Some(0),
+            distance_left: Some(114300),
+            distance_right: Some(114300),
+            use_simple_position: Some(false),
+            relative_height: 0,
+            behind_doc: false,
+            locked: false,
+            layout_in_cell: true,
+            hidden: None,
+            allow_overlap: true,
+            simple_position: SimplePosition::default(),
+            position_h: HorizontalPosition::default(),
+            position_v: VerticalPosition::default(),
+            extent: Extent::default(),
+            effect_extent: None,
+            wrap: Wrap::default(),
+            doc_property: DocProperty::default(),
+            graphic: Graphic::default(),
+        }
+    }
+}
+
+impl<'a> Anchor<'a> {
+    __setter!(distance_top: Option<u64>);
+    __setter!(distance_bottom: Option<u64>);
+    __setter!(distance_left: Option<u64>);
+    __setter!(distance_right: Option<u64>);
+    __setter!(relative_height: u32);
+    __setter!(behind_doc: bool);
+    __setter!(locked: bool);
+    __setter!(layout_in_cell: bool);
+    __setter!(allow_overlap: bool);
+    __setter!(position_h: HorizontalPosition);
+    __setter!(position_v: VerticalPosition);
+    __setter!(effect_extent: Option<EffectExtent>);
+    __setter!(wrap: Wrap);
+
+    pub fn new<E, G>(extent: E, doc_property: DocProperty<'a>, graphic: G) -> Self
+    where
+        E: Into<Extent>,
+        G: Into<Graphic<'a>>,
+    {
+        Anchor {
+            extent: extent.into(),
+            doc_property,
+            graphic: graphic.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::drawing::{HorizontalAlign, HorizontalRelativeFrom, WrapNone};
+
+__xml_test_suites!(
+    Anchor,
+    Anchor::new((1, 2), DocProperty::new(1, "foo"), Graphic::default()),
+    r#"<wp:anchor distT="0" distB="0" distL="114300" distR="114300" simplePos="false" relativeHeight="0" behindDoc="false" locked="false" layoutInCell="true" allowOverlap="true"><wp:simplePos x="0" y="0"/><wp:positionH relativeFrom="column"><wp:posOffset>0</wp:posOffset></wp:positionH><wp:positionV relativeFrom="paragraph"><wp:posOffset>0</wp:posOffset></wp:positionV><wp:extent cx="1" cy="2"/><wp:wrapSquare wrapText="bothSides"/><wp:docPr id="1" name="foo"/><a:graphic><a:graphicData uri=""/></a:graphic></wp:anchor>"#,
+    Anchor::new((1, 2), DocProperty::new(1, "foo"), Graphic::default())
+        .position_h(HorizontalPosition::align(
+            HorizontalRelativeFrom::Page,
+            HorizontalAlign::Center
+        ))
+        .wrap(WrapNone::default())
+        .behind_doc(true)
+        .relative_height(5u32),
+    r#"<wp:anchor distT="0" distB="0" distL="114300" distR="114300" simplePos="false" relativeHeight="5" behindDoc="true" locked="false" layoutInCell="true" allowOverlap="true"><wp:simplePos x="0" y="0"/><wp:positionH relativeFrom="page"><wp:align>center</wp:align></wp:positionH><wp:positionV relativeFrom="paragraph"><wp:posOffset>0</wp:posOffset></wp:positionV><wp:extent cx="1" cy="2"/><wp:wrapNone/><wp:docPr id="1" name="foo"/><a:graphic><a:graphicData uri=""/></a:graphic></wp:anchor>"#,
+);
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::{__xml_test_suites, schema::SCHEMA_SVG};
+
+/// The uri of the extension containing the SVG original of an image
+pub const EXTENSION_SVG: &str = "{96DAC541-7B7A-43D3-8B79-37D633B846F1}";
+
+/// Extension List of an image
+///
+/// Stores data unknown to earlier versions of Word, e.g. the SVG original of
+/// an image. Extensions of other kinds are skipped when writing, since
+/// their content isn't kept.
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:extLst")]
+pub struct BlipExtensionList<'a> {
+    #[xml(child = "a:ext")]
+    pub extensions: Vec<BlipExtension<'a>>,
+}
+
+impl<'a> XmlWrite for BlipExtensionList<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        writer.write_element_start("a:extLst")?;
+
+        let mut extensions = self
+            .extensions
+            .iter()
+            .filter(|ext| ext.is_known())
+            .peekable();
+
+        if extensions.peek().is_none() {
+            writer.write_element_end_empty()?;
+            return Ok(());
+        }
+
+        writer.write_element_end_open()?;
+
+        for ext in extensions {
+            ext.to_writer(writer)?;
+        }
+
+        writer.write_element_end_close("a:extLst")?;
+
+        Ok(())
+    }
+}
+
+/// Extension of an image
+///
+/// The uri specifies the kind of the extension.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:ext")]
+pub struct BlipExtension<'a> {
+    #[xml(attr = "uri")]
+    pub uri: Cow<'a, str>,
+    #[xml(child = "asvg:svgBlip")]
+    pub svg_blip: Option<SvgBlip<'a>>,
+}
+
+impl<'a> BlipExtension<'a> {
+    /// Returns whether the content of the extension is modeled
+    pub fn is_known(&self) -> bool {
+        self.svg_blip.is_some()
+    }
+}
+
+impl<'a> From<SvgBlip<'a>> for BlipExtension<'a> {
+    fn from(svg_blip: SvgBlip<'a>) -> Self {
+        BlipExtension {
+            uri: EXTENSION_SVG.into(),
+            svg_blip: Some(svg_blip),
+        }
+    }
+}
+
+/// SVG Image
+///
+/// Refers to the SVG original of an image, which Word 2016 and later
+/// display instead of the image itself.
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "asvg:svgBlip")]
+pub struct SvgBlip<'a> {
+    /// Specifies the relationship of the embedded SVG image.
+    #[xml(attr = "r:embed")]
+    pub embed: Option<Cow<'a, str>>,
+    /// Specifies the relationship of the linked SVG image.
+    #[xml(attr = "r:link")]
+    pub link: Option<Cow<'a, str>>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for SvgBlip<'a> {
+    fn from(embed: T) -> Self {
+        SvgBlip {
+            embed: Some(embed.into()),
+            link: None,
+        }
+    }
+}
+
+// the namespace is declared on the element itself, as Word does
+impl<'a> XmlWrite for SvgBlip<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let SvgBlip { embed, link } = self;
+
+        writer.write_element_start("asvg:svgBlip")?;
+
+        writer.write_attribute("xmlns:asvg", SCHEMA_SVG)?;
+
+        if let Some(embed) = embed {
+            writer.write_attribute("r:embed", embed)?;
+        }
+
+        if let Some(link) = link {
+            writer.write_attribute("r:link", link)?;
+        }
+
+        writer.write_element_end_empty()?;
+
+        Ok(())
+    }
+}
+
+__xml_test_suites!(
+    BlipExtensionList,
+    BlipExtensionList::default(),
+    r#"<a:extLst/>"#,
+    BlipExtensionList {
+        extensions: vec![SvgBlip::from("rId2").into()],
+    },
+    r#"<a:extLst><a:ext uri="{96DAC541-7B7A-43D3-8B79-37D633B846F1}"><asvg:svgBlip xmlns:asvg="http://schemas.microsoft.com/office/drawing/2016/SVG/main" r:embed="rId2"/></a:ext></a:extLst>"#,
+);
+
+#[test]
+fn unknown_extensions() -> XmlResult<()> {
+    let xml = r#"<a:extLst><a:ext uri="{28A0092B-C50C-407E-A947-70E740481C1C}"><a14:useLocalDpi xmlns:a14="http://schemas.microsoft.com/office/drawing/2010/main" val="0"/></a:ext></a:extLst>"#;
+    let list = BlipExtensionList::from_str(xml)?;
+    assert_eq!(list.extensions.len(), 1);
+    assert_eq!(list.to_string()?, "<a:extLst/>");
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    drawing::{BlipExtensionList, SvgBlip},
+};
+
+/// Picture Fill
+///
+/// Specifies the image of the picture and how it fills the picture.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let fill = BlipFill::from("rId5");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "pic:blipFill")]
+pub struct BlipFill<'a> {
+    /// Specifies the image.
+    #[xml(default, child = "a:blip")]
+    pub blip: Blip<'a>,
+    /// Specifies that the image is stretched to fill the picture.
+    #[xml(child = "a:stretch")]
+    pub stretch: Option<Stretch>,
+}
+
+impl<'a, T: Into<Blip<'a>>> From<T> for BlipFill<'a> {
+    fn from(blip: T) -> Self {
+        BlipFill {
+            blip: blip.into(),
+            stretch: Some(Stretch::default()),
+        }
+    }
+}
+
+/// Image
+///
+/// Refers to the image part by the id of its relationship.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// // a PNG image with its SVG original
+/// let blip = Blip::from("rId5").svg("rId6");
+///
+/// assert_eq!(blip.svg_embed(), Some("rId6"));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:blip")]
+pub struct Blip<'a> {
+    /// Specifies the relationship of the embedded image.
+    #[xml(attr = "r:embed")]
+    pub embed: Option<Cow<'a, str>>,
+    /// Specifies the relationship of the linked image.
+    #[xml(attr = "r:link")]
+    pub link: Option<Cow<'a, str>>,
+    /// Specifies the extensions, e.g. the SVG original.
+    #[xml(child = "a:extLst")]
+    pub extension_list: Option<BlipExtensionList<'a>>,
+}
+
+impl<'a> Blip<'a> {
+    /// Specifies the relationship of the SVG original
+    ///
+    /// The image itself is used as the fallback by earlier versions of
+    /// Word, and should be a PNG image.
+    pub fn svg<T: Into<Cow<'a, str>>>(mut self, embed: T) -> Self {
+        let list = self.extension_list.get_or_insert_with(Default::default);
+        list.extensions.retain(|ext| ext.svg_blip.is_none());
+        list.extensions.push(SvgBlip::from(embed).into());
+        self
+    }
+
+    /// Returns the relationship id of the SVG original
+    pub fn svg_embed(&self) -> Option<&str> {
+        self.extension_list
+            .iter()
+            .flat_map(|list| &list.extensions)
+            .find_map(|ext| ext.svg_blip.as_ref()?.embed.as_deref())
+    }
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for Blip<'a> {
+    fn from(embed: T) -> Self {
+        Blip {
+            embed: Some(embed.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Stretch
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:stretch")]
+pub struct Stretch {
+    /// Specifies the rectangle to fill, which is the whole picture by default.
+    #[xml(child = "a:fillRect")]
+    pub fill_rect: Option<FillRect>,
+}
+
+impl Default for Stretch {
+    fn default() -> Self {
+        Stretch {
+            fill_rect: Some(FillRect {}),
+        }
+    }
+}
+
+/// Fill Rectangle
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:fillRect")]
+pub struct FillRect {}
+
+__xml_test_suites!(
+    BlipFill,
+    BlipFill::default(),
+    r#"<pic:blipFill><a:blip/></pic:blipFill>"#,
+    BlipFill::from("rId5"),
+    r#"<pic:blipFill><a:blip r:embed="rId5"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill>"#,
+);
@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::{__xml_test_suites, schema::SCHEMA_CHART};
+
+/// Chart Reference
+///
+/// Refers to a chart part by the id of its relationship from the part
+/// containing the drawing.
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "c:chart")]
+pub struct ChartReference<'a> {
+    /// Specifies the relationship of the chart part.
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for ChartReference<'a> {
+    fn from(id: T) -> Self {
+        ChartReference { id: id.into() }
+    }
+}
+
+// the namespace is declared on the element itself, as Word does
+impl<'a> XmlWrite for ChartReference<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let ChartReference { id } = self;
+
+        writer.write_element_start("c:chart")?;
+
+        writer.write_attribute("xmlns:c", SCHEMA_CHART)?;
+
+        writer.write_attribute("r:id", id)?;
+
+        writer.write_element_end_empty()?;
+
+        Ok(())
+    }
+}
+
+__xml_test_suites!(
+    ChartReference,
+    ChartReference::from("rId4"),
+    r#"<c:chart xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" r:id="rId4"/>"#,
+);
@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+/// Drawing Object Non-Visual Properties
+///
+/// Specifies the id, the name and the alternative text of the drawing.
+///
+/// The id should be unique in the document. Drawings whose id is zero or
+/// shared with another one get a new id when the document is written.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let prop = DocProperty::new(1, "Picture 1")
+///     .description("A red square")
+///     .title("Logo");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:docPr")]
+pub struct DocProperty<'a> {
+    /// Specifies the unique id of the drawing.
+    #[xml(attr = "id")]
+    pub id: usize,
+    /// Specifies the name of the drawing.
+    #[xml(attr = "name")]
+    pub name: Cow<'a, str>,
+    /// Specifies the alternative text of the drawing.
+    #[xml(attr = "descr")]
+    pub description: Option<Cow<'a, str>>,
+    /// Specifies the title of the drawing.
+    #[xml(attr = "title")]
+    pub title: Option<Cow<'a, str>>,
+    /// Specifies that the drawing is hidden.
+    #[xml(attr = "hidden")]
+    pub hidden: Option<bool>,
+}
+
+impl<'a> DocProperty<'a> {
+    __setter!(description: Option<Cow<'a, str>>);
+    __setter!(title: Option<Cow<'a, str>>);
+    __setter!(hidden: Option<bool>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(id: usize, name: T) -> Self {
+        DocProperty {
+            id,
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Non-Visual Drawing Properties of a picture
+///
+/// Specifies the same properties as `DocProperty`, for the picture itself.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "pic:cNvPr")]
+pub struct NonVisualProperty<'a> {
+    #[xml(attr = "id")]
+    pub id: usize,
+    #[xml(attr = "name")]
+    pub name: Cow<'a, str>,
+    #[xml(attr = "descr")]
+    pub description: Option<Cow<'a, str>>,
+    #[xml(attr = "title")]
+    pub title: Option<Cow<'a, str>>,
+}
+
+impl<'a> NonVisualProperty<'a> {
+    __setter!(description: Option<Cow<'a, str>>);
+    __setter!(title: Option<Cow<'a, str>>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(id: usize, name: T) -> Self {
+        NonVisualProperty {
+            id,
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+__xml_test_suites!(
+    DocProperty,
+    DocProperty::new(1, "Picture 1"),
+    r#"<wp:docPr id="1" name="Picture 1"/>"#,
+    DocProperty::new(2, "Picture 2")
+        .description("A red square")
+        .title("Logo")
+        .hidden(false),
+    r#"<wp:docPr id="2" name="Picture 2" descr="A red square" title="Logo" hidden="false"/>"#,
+);
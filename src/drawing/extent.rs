@@ -0,0 +1,56 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Drawing Extent
+///
+/// Specifies the size of the drawing in EMUs.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let extent = Extent::from((EMU_PER_INCH, EMU_PER_INCH / 2));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:extent")]
+pub struct Extent {
+    /// Specifies the width.
+    #[xml(attr = "cx")]
+    pub width: u64,
+    /// Specifies the height.
+    #[xml(attr = "cy")]
+    pub height: u64,
+}
+
+impl From<(u64, u64)> for Extent {
+    fn from((width, height): (u64, u64)) -> Self {
+        Extent { width, height }
+    }
+}
+
+/// Effect Extent
+///
+/// Specifies the additional extent added to each edge of the drawing to
+/// compensate for effects, e.g. shadows.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:effectExtent")]
+pub struct EffectExtent {
+    #[xml(attr = "l")]
+    pub left: i64,
+    #[xml(attr = "t")]
+    pub top: i64,
+    #[xml(attr = "r")]
+    pub right: i64,
+    #[xml(attr = "b")]
+    pub bottom: i64,
+}
+
+__xml_test_suites!(
+    Extent,
+    Extent::default(),
+    r#"<wp:extent cx="0" cy="0"/>"#,
+    Extent::from((914400, 457200)),
+    r#"<wp:extent cx="914400" cy="457200"/>"#,
+);
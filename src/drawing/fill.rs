@@ -0,0 +1,81 @@
+use derive_more::From;
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Fill of a shape or an outline
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let fill = Fill::solid("4472C4");
+/// let none = Fill::from(NoFill::default());
+/// ```
+#[derive(Debug, From, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum Fill<'a> {
+    #[xml(tag = "a:noFill")]
+    None(NoFill),
+    #[xml(tag = "a:solidFill")]
+    Solid(SolidFill<'a>),
+}
+
+impl<'a> Fill<'a> {
+    /// Creates a solid fill of the color in hex, e.g. `FF0000`
+    pub fn solid<T: Into<Cow<'a, str>>>(color: T) -> Self {
+        Fill::Solid(SolidFill::from(color))
+    }
+
+    /// Returns the color in hex of a solid fill
+    pub fn color(&self) -> Option<&str> {
+        match self {
+            Fill::Solid(fill) => Some(&fill.color.value),
+            Fill::None(_) => None,
+        }
+    }
+}
+
+/// No Fill
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:noFill")]
+pub struct NoFill {}
+
+/// Solid Fill
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:solidFill")]
+pub struct SolidFill<'a> {
+    #[xml(default, child = "a:srgbClr")]
+    pub color: RgbColor<'a>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for SolidFill<'a> {
+    fn from(color: T) -> Self {
+        SolidFill {
+            color: RgbColor {
+                value: color.into(),
+            },
+        }
+    }
+}
+
+/// RGB Color
+///
+/// Specifies the color in hex, e.g. `FF0000`.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:srgbClr")]
+pub struct RgbColor<'a> {
+    #[xml(attr = "val")]
+    pub value: Cow<'a, str>,
+}
+
+__xml_test_suites!(
+    Fill,
+    Fill::from(NoFill::default()),
+    r#"<a:noFill/>"#,
+    Fill::solid("FF0000"),
+    r#"<a:solidFill><a:srgbClr val="FF0000"/></a:solidFill>"#,
+);
@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    drawing::{ChartReference, Picture, Shape},
+};
+
+/// The uri of graphic data containing a picture
+pub const GRAPHIC_DATA_PICTURE: &str = "http://schemas.openxmlformats.org/drawingml/2006/picture";
+
+/// The uri of graphic data containing a word processing shape
+pub const GRAPHIC_DATA_SHAPE: &str =
+    "http://schemas.microsoft.com/office/word/2010/wordprocessingShape";
+
+/// The uri of graphic data containing a chart
+pub const GRAPHIC_DATA_CHART: &str = "http://schemas.openxmlformats.org/drawingml/2006/chart";
+
+/// Graphic Object
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let graphic = Graphic::from(Picture::new("rId5", 914400, 457200));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:graphic")]
+pub struct Graphic<'a> {
+    #[xml(default, child = "a:graphicData")]
+    pub data: GraphicData<'a>,
+}
+
+impl<'a> From<Picture<'a>> for Graphic<'a> {
+    fn from(picture: Picture<'a>) -> Self {
+        Graphic {
+            data: GraphicData {
+                uri: GRAPHIC_DATA_PICTURE.into(),
+                picture: Some(picture),
+                shape: None,
+                chart: None,
+            },
+        }
+    }
+}
+
+impl<'a> From<Shape<'a>> for Graphic<'a> {
+    fn from(shape: Shape<'a>) -> Self {
+        Graphic {
+            data: GraphicData {
+                uri: GRAPHIC_DATA_SHAPE.into(),
+                picture: None,
+                shape: Some(shape),
+                chart: None,
+            },
+        }
+    }
+}
+
+impl<'a> From<ChartReference<'a>> for Graphic<'a> {
+    fn from(chart: ChartReference<'a>) -> Self {
+        Graphic {
+            data: GraphicData {
+                uri: GRAPHIC_DATA_CHART.into(),
+                picture: None,
+                shape: None,
+                chart: Some(chart),
+            },
+        }
+    }
+}
+
+/// Graphic Object Data
+///
+/// The uri specifies the kind of the graphic object.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:graphicData")]
+pub struct GraphicData<'a> {
+    #[xml(attr = "uri")]
+    pub uri: Cow<'a, str>,
+    #[xml(child = "pic:pic")]
+    pub picture: Option<Picture<'a>>,
+    #[xml(child = "wps:wsp")]
+    pub shape: Option<Shape<'a>>,
+    #[xml(child = "c:chart")]
+    pub chart: Option<ChartReference<'a>>,
+}
+
+impl<'a> GraphicData<'a> {
+    /// Returns if the graphic data contains a picture, a shape or a chart
+    ///
+    /// Other kinds of graphic data, e.g. SmartArt, are written back
+    /// without their content.
+    pub fn is_known(&self) -> bool {
+        self.picture.is_some() || self.shape.is_some() || self.chart.is_some()
+    }
+}
+
+__xml_test_suites!(
+    Graphic,
+    Graphic::default(),
+    r#"<a:graphic><a:graphicData uri=""/></a:graphic>"#,
+    Graphic::from(ChartReference::from("rId1")),
+    r#"<a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart"><c:chart xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" r:id="rId1"/></a:graphicData></a:graphic>"#,
+);
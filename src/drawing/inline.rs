@@ -0,0 +1,86 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    drawing::{DocProperty, EffectExtent, Extent, Graphic},
+};
+
+/// Inline Drawing
+///
+/// Specifies a drawing placed inline with the text.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let inline = Inline::new(
+///     (914400, 457200),
+///     DocProperty::new(1, "Picture 1"),
+///     Picture::new("rId5", 914400, 457200),
+/// );
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:inline")]
+pub struct Inline<'a> {
+    /// Specifies the distance from the text above in EMUs.
+    #[xml(attr = "distT")]
+    pub distance_top: Option<u64>,
+    /// Specifies the distance from the text below in EMUs.
+    #[xml(attr = "distB")]
+    pub distance_bottom: Option<u64>,
+    /// Specifies the distance from the text on the left in EMUs.
+    #[xml(attr = "distL")]
+    pub distance_left: Option<u64>,
+    /// Specifies the distance from the text on the right in EMUs.
+    #[xml(attr = "distR")]
+    pub distance_right: Option<u64>,
+    /// Specifies the size of the drawing.
+    #[xml(default, child = "wp:extent")]
+    pub extent: Extent,
+    /// Specifies the extent added for effects.
+    #[xml(child = "wp:effectExtent")]
+    pub effect_extent: Option<EffectExtent>,
+    /// Specifies the id, the name and the alternative text.
+    #[xml(default, child = "wp:docPr")]
+    pub doc_property: DocProperty<'a>,
+    /// Specifies the graphic object.
+    #[xml(default, child = "a:graphic")]
+    pub graphic: Graphic<'a>,
+}
+
+impl<'a> Inline<'a> {
+    __setter!(distance_top: Option<u64>);
+    __setter!(distance_bottom: Option<u64>);
+    __setter!(distance_left: Option<u64>);
+    __setter!(distance_right: Option<u64>);
+    __setter!(effect_extent: Option<EffectExtent>);
+
+    pub fn new<E, G>(extent: E, doc_property: DocProperty<'a>, graphic: G) -> Self
+    where
+        E: Into<Extent>,
+        G: Into<Graphic<'a>>,
+    {
+        Inline {
+            extent: extent.into(),
+            doc_property,
+            graphic: graphic.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::drawing::Picture;
+
+__xml_test_suites!(
+    Inline,
+    Inline::new((1, 2), DocProperty::new(1, "foo"), Graphic::default()),
+    r#"<wp:inline><wp:extent cx="1" cy="2"/><wp:docPr id="1" name="foo"/><a:graphic><a:graphicData uri=""/></a:graphic></wp:inline>"#,
+    Inline::new(
+        (1, 2),
+        DocProperty::new(1, "foo").description("bar"),
+        Picture::new("rId1", 1, 2)
+    )
+    .distance_top(0u64),
+    r#"<wp:inline distT="0"><wp:extent cx="1" cy="2"/><wp:docPr id="1" name="foo" descr="bar"/><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/picture"><pic:pic><pic:nvPicPr><pic:cNvPr id="0" name=""/><pic:cNvPicPr/></pic:nvPicPr><pic:blipFill><a:blip r:embed="rId1"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill><pic:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="1" cy="2"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></pic:spPr></pic:pic></a:graphicData></a:graphic></wp:inline>"#,
+);
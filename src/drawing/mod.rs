@@ -0,0 +1,31 @@
+//! DrawingML
+//!
+//! Drawings are placed in runs with `document::Drawing`, either inline with
+//! the text or anchored, and contain pictures, shapes, charts and other
+//! graphic objects.
+
+mod anchor;
+mod blip_extension;
+mod blip_fill;
+mod chart_reference;
+mod doc_property;
+mod extent;
+mod fill;
+mod graphic;
+mod inline;
+mod outline;
+mod picture;
+mod position;
+mod shape;
+mod shape_property;
+mod wrap;
+
+// re-export
+pub use self::{
+    anchor::*, blip_extension::*, blip_fill::*, chart_reference::*, doc_property::*, extent::*,
+    fill::*, graphic::*, inline::*, outline::*, picture::*, position::*, shape::*,
+    shape_property::*, wrap::*,
+};
+
+/// English Metric Units per inch
+pub const EMU_PER_INCH: u64 = 914_400;
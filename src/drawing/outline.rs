@@ -0,0 +1,40 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, drawing::Fill};
+
+/// Outline
+///
+/// Specifies the line around a shape, or the line itself for line shapes.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// // a black line of 1pt
+/// let outline = Outline::default().width(12700u64).fill(Fill::solid("000000"));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:ln")]
+pub struct Outline<'a> {
+    /// Specifies the width of the line in EMUs.
+    #[xml(attr = "w")]
+    pub width: Option<u64>,
+    /// Specifies the fill of the line.
+    #[xml(child = "a:noFill", child = "a:solidFill")]
+    pub fill: Option<Fill<'a>>,
+}
+
+impl<'a> Outline<'a> {
+    __setter!(width: Option<u64>);
+    __setter!(fill: Option<Fill<'a>>);
+}
+
+__xml_test_suites!(
+    Outline,
+    Outline::default(),
+    r#"<a:ln/>"#,
+    Outline::default()
+        .width(12700u64)
+        .fill(Fill::solid("000000")),
+    r#"<a:ln w="12700"><a:solidFill><a:srgbClr val="000000"/></a:solidFill></a:ln>"#,
+);
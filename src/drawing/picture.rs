@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __xml_test_suites,
+    drawing::{BlipFill, NonVisualProperty, ShapeProperty},
+};
+
+/// Picture
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let pic = Picture::new("rId5", 914400, 457200);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "pic:pic")]
+pub struct Picture<'a> {
+    /// Specifies the non-visual properties.
+    #[xml(default, child = "pic:nvPicPr")]
+    pub non_visual: NonVisualPictureProperty<'a>,
+    /// Specifies the image of the picture.
+    #[xml(default, child = "pic:blipFill")]
+    pub blip_fill: BlipFill<'a>,
+    /// Specifies the size and the geometry.
+    #[xml(default, child = "pic:spPr")]
+    pub shape_property: ShapeProperty<'a>,
+}
+
+impl<'a> Picture<'a> {
+    /// Creates a picture of the image with the given relationship id,
+    /// stretched to the size in EMUs
+    pub fn new<T: Into<Cow<'a, str>>>(embed: T, width: u64, height: u64) -> Self {
+        Picture {
+            non_visual: NonVisualPictureProperty {
+                property: NonVisualProperty::new(0, ""),
+                picture_property: NonVisualPictureDrawingProperty {},
+            },
+            blip_fill: BlipFill::from(embed),
+            shape_property: ShapeProperty::default()
+                .transform((width, height))
+                .geometry("rect"),
+        }
+    }
+
+    /// Returns the id of the relationship of the embedded image
+    pub fn embed(&self) -> Option<&str> {
+        self.blip_fill.blip.embed.as_deref()
+    }
+
+    /// Returns the id of the relationship of the SVG original, if the
+    /// embedded image is a fallback of it
+    pub fn svg_embed(&self) -> Option<&str> {
+        self.blip_fill.blip.svg_embed()
+    }
+}
+
+/// Non-Visual Picture Properties
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "pic:nvPicPr")]
+pub struct NonVisualPictureProperty<'a> {
+    #[xml(default, child = "pic:cNvPr")]
+    pub property: NonVisualProperty<'a>,
+    #[xml(default, child = "pic:cNvPicPr")]
+    pub picture_property: NonVisualPictureDrawingProperty,
+}
+
+/// Non-Visual Picture Drawing Properties
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "pic:cNvPicPr")]
+pub struct NonVisualPictureDrawingProperty {}
+
+__xml_test_suites!(
+    Picture,
+    Picture::new("rId5", 914400, 457200),
+    r#"<pic:pic><pic:nvPicPr><pic:cNvPr id="0" name=""/><pic:cNvPicPr/></pic:nvPicPr><pic:blipFill><a:blip r:embed="rId5"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill><pic:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="914400" cy="457200"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></pic:spPr></pic:pic>"#,
+);
@@ -0,0 +1,212 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__string_enum, __xml_test_suites};
+
+/// Simple Positioning Coordinates
+///
+/// Specifies the position of the drawing relative to the page, only used if
+/// `Anchor::use_simple_position` is set.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:simplePos")]
+pub struct SimplePosition {
+    #[xml(attr = "x")]
+    pub x: i64,
+    #[xml(attr = "y")]
+    pub y: i64,
+}
+
+/// Horizontal Positioning
+///
+/// Positions the drawing either by an offset in EMUs or by an alignment,
+/// relative to the given base.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let right = HorizontalPosition::align(HorizontalRelativeFrom::Margin, HorizontalAlign::Right);
+/// let inch = HorizontalPosition::offset(HorizontalRelativeFrom::Page, 914400);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:positionH")]
+pub struct HorizontalPosition {
+    /// Specifies the base which the position is relative to.
+    #[xml(attr = "relativeFrom")]
+    pub relative_from: HorizontalRelativeFrom,
+    /// Specifies the alignment relative to the base.
+    #[xml(flatten_text = "wp:align")]
+    pub align: Option<HorizontalAlign>,
+    /// Specifies the offset from the base in EMUs.
+    #[xml(flatten_text = "wp:posOffset")]
+    pub offset: Option<i64>,
+}
+
+impl HorizontalPosition {
+    pub fn offset(relative_from: HorizontalRelativeFrom, offset: i64) -> Self {
+        HorizontalPosition {
+            relative_from,
+            align: None,
+            offset: Some(offset),
+        }
+    }
+
+    pub fn align(relative_from: HorizontalRelativeFrom, align: HorizontalAlign) -> Self {
+        HorizontalPosition {
+            relative_from,
+            align: Some(align),
+            offset: None,
+        }
+    }
+}
+
+impl Default for HorizontalPosition {
+    fn default() -> Self {
+        HorizontalPosition::offset(HorizontalRelativeFrom::Column, 0)
+    }
+}
+
+/// Vertical Positioning
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let top = VerticalPosition::align(VerticalRelativeFrom::Page, VerticalAlign::Top);
+/// let below = VerticalPosition::offset(VerticalRelativeFrom::Paragraph, 457200);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:positionV")]
+pub struct VerticalPosition {
+    /// Specifies the base which the position is relative to.
+    #[xml(attr = "relativeFrom")]
+    pub relative_from: VerticalRelativeFrom,
+    /// Specifies the alignment relative to the base.
+    #[xml(flatten_text = "wp:align")]
+    pub align: Option<VerticalAlign>,
+    /// Specifies the offset from the base in EMUs.
+    #[xml(flatten_text = "wp:posOffset")]
+    pub offset: Option<i64>,
+}
+
+impl VerticalPosition {
+    pub fn offset(relative_from: VerticalRelativeFrom, offset: i64) -> Self {
+        VerticalPosition {
+            relative_from,
+            align: None,
+            offset: Some(offset),
+        }
+    }
+
+    pub fn align(relative_from: VerticalRelativeFrom, align: VerticalAlign) -> Self {
+        VerticalPosition {
+            relative_from,
+            align: Some(align),
+            offset: None,
+        }
+    }
+}
+
+impl Default for VerticalPosition {
+    fn default() -> Self {
+        VerticalPosition::offset(VerticalRelativeFrom::Paragraph, 0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HorizontalRelativeFrom {
+    Character,
+    Column,
+    InsideMargin,
+    LeftMargin,
+    Margin,
+    OutsideMargin,
+    Page,
+    RightMargin,
+}
+
+__string_enum! {
+    HorizontalRelativeFrom {
+        Character = "character",
+        Column = "column",
+        InsideMargin = "insideMargin",
+        LeftMargin = "leftMargin",
+        Margin = "margin",
+        OutsideMargin = "outsideMargin",
+        Page = "page",
+        RightMargin = "rightMargin",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerticalRelativeFrom {
+    BottomMargin,
+    InsideMargin,
+    Line,
+    Margin,
+    OutsideMargin,
+    Page,
+    Paragraph,
+    TopMargin,
+}
+
+__string_enum! {
+    VerticalRelativeFrom {
+        BottomMargin = "bottomMargin",
+        InsideMargin = "insideMargin",
+        Line = "line",
+        Margin = "margin",
+        OutsideMargin = "outsideMargin",
+        Page = "page",
+        Paragraph = "paragraph",
+        TopMargin = "topMargin",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HorizontalAlign {
+    Left,
+    Right,
+    Center,
+    Inside,
+    Outside,
+}
+
+__string_enum! {
+    HorizontalAlign {
+        Left = "left",
+        Right = "right",
+        Center = "center",
+        Inside = "inside",
+        Outside = "outside",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerticalAlign {
+    Top,
+    Bottom,
+    Center,
+    Inside,
+    Outside,
+}
+
+__string_enum! {
+    VerticalAlign {
+        Top = "top",
+        Bottom = "bottom",
+        Center = "center",
+        Inside = "inside",
+        Outside = "outside",
+    }
+}
+
+__xml_test_suites!(
+    HorizontalPosition,
+    HorizontalPosition::default(),
+    r#"<wp:positionH relativeFrom="column"><wp:posOffset>0</wp:posOffset></wp:positionH>"#,
+    HorizontalPosition::align(HorizontalRelativeFrom::Margin, HorizontalAlign::Right),
+    r#"<wp:positionH relativeFrom="margin"><wp:align>right</wp:align></wp:positionH>"#,
+    HorizontalPosition::offset(HorizontalRelativeFrom::Page, -914400),
+    r#"<wp:positionH relativeFrom="page"><wp:posOffset>-914400</wp:posOffset></wp:positionH>"#,
+);
@@ -0,0 +1,252 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __string_enum, __xml_test_suites,
+    document::{BodyContent, TextBoxContent},
+    drawing::{Fill, Outline, PresetGeometry, Transform},
+};
+
+/// Word Processing Shape
+///
+/// A DrawingML shape, e.g. a rectangle or a line, which can contain a text
+/// box. Shapes are placed with `document::Drawing::inline_shape`, and usually
+/// wrapped in `document::AlternateContent` to provide a fallback for older
+/// readers.
+///
+/// ```rust
+/// use docx::document::*;
+/// use docx::drawing::*;
+///
+/// let text_box = Shape::text_box(1828800, 457200)
+///     .push(Paragraph::default().push_text("Call-out"))
+///     .fill(Fill::solid("FFF2CC"));
+///
+/// let divider = Shape::line(5486400, 0).outline(Outline::default().width(12700u64));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wps:wsp")]
+pub struct Shape<'a> {
+    /// Specifies the non-visual properties of a shape.
+    #[xml(child = "wps:cNvSpPr")]
+    pub non_visual: Option<NonVisualShapeProperty>,
+    /// Specifies the non-visual properties of a connector, e.g. a line.
+    #[xml(child = "wps:cNvCnPr")]
+    pub non_visual_connector: Option<NonVisualConnectorProperty>,
+    /// Specifies the geometry, the fill and the outline.
+    #[xml(default, child = "wps:spPr")]
+    pub property: WpsShapeProperty<'a>,
+    /// Specifies the text box in the shape.
+    #[xml(child = "wps:txbx")]
+    pub text_box: Option<TextBox<'a>>,
+    /// Specifies how the text box is laid out in the shape.
+    #[xml(default, child = "wps:bodyPr")]
+    pub body_property: BodyProperty,
+}
+
+impl<'a> Shape<'a> {
+    __setter!(property: WpsShapeProperty<'a>);
+    __setter!(body_property: BodyProperty);
+
+    /// Creates a shape of the preset geometry, e.g. `rect` or `roundRect`,
+    /// in the size in EMUs
+    pub fn new<T: Into<PresetGeometry<'a>>>(geometry: T, width: u64, height: u64) -> Self {
+        Shape {
+            non_visual: Some(NonVisualShapeProperty::default()),
+            property: WpsShapeProperty::default()
+                .transform((width, height))
+                .geometry(geometry),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a rectangular text box with a white fill and a black
+    /// outline, in the size in EMUs
+    pub fn text_box(width: u64, height: u64) -> Self {
+        let mut shape = Shape::new("rect", width, height)
+            .fill(Fill::solid("FFFFFF"))
+            .outline(
+                Outline::default()
+                    .width(6350u64)
+                    .fill(Fill::solid("000000")),
+            );
+        shape.text_box = Some(TextBox::default());
+        shape.non_visual = Some(NonVisualShapeProperty {
+            text_box: Some(true),
+        });
+        shape
+    }
+
+    /// Creates a straight line from the top left to the bottom right of
+    /// the size in EMUs
+    pub fn line(width: u64, height: u64) -> Self {
+        Shape {
+            non_visual: None,
+            non_visual_connector: Some(NonVisualConnectorProperty::default()),
+            ..Shape::new("line", width, height)
+        }
+        .outline(
+            Outline::default()
+                .width(6350u64)
+                .fill(Fill::solid("000000")),
+        )
+    }
+
+    /// Specifies the fill
+    pub fn fill<T: Into<Fill<'a>>>(mut self, fill: T) -> Self {
+        self.property.fill = Some(fill.into());
+        self
+    }
+
+    /// Specifies the outline
+    pub fn outline<T: Into<Outline<'a>>>(mut self, outline: T) -> Self {
+        self.property.outline = Some(outline.into());
+        self
+    }
+
+    /// Appends the content to the text box, creating one if needed
+    pub fn push<T: Into<BodyContent<'a>>>(mut self, content: T) -> Self {
+        self.text_box
+            .get_or_insert_with(TextBox::default)
+            .content
+            .content
+            .push(content.into());
+        self
+    }
+
+    /// Returns the name of the preset geometry
+    pub fn geometry(&self) -> Option<&str> {
+        self.property
+            .geometry
+            .as_ref()
+            .map(|geometry| &*geometry.preset)
+    }
+
+    /// Returns the content of the text box
+    pub fn text_box_content(&self) -> Option<&TextBoxContent<'a>> {
+        self.text_box.as_ref().map(|text_box| &text_box.content)
+    }
+
+    pub fn text_box_content_mut(&mut self) -> Option<&mut TextBoxContent<'a>> {
+        self.text_box.as_mut().map(|text_box| &mut text_box.content)
+    }
+}
+
+/// Non-Visual Shape Properties
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wps:cNvSpPr")]
+pub struct NonVisualShapeProperty {
+    /// Specifies that the shape is a text box.
+    #[xml(attr = "txBox")]
+    pub text_box: Option<bool>,
+}
+
+/// Non-Visual Connector Properties
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wps:cNvCnPr")]
+pub struct NonVisualConnectorProperty {}
+
+/// Shape Properties of a shape
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wps:spPr")]
+pub struct WpsShapeProperty<'a> {
+    /// Specifies the position and size.
+    #[xml(child = "a:xfrm")]
+    pub transform: Option<Transform>,
+    /// Specifies the preset geometry.
+    #[xml(child = "a:prstGeom")]
+    pub geometry: Option<PresetGeometry<'a>>,
+    /// Specifies the fill.
+    #[xml(child = "a:noFill", child = "a:solidFill")]
+    pub fill: Option<Fill<'a>>,
+    /// Specifies the outline.
+    #[xml(child = "a:ln")]
+    pub outline: Option<Outline<'a>>,
+}
+
+impl<'a> WpsShapeProperty<'a> {
+    __setter!(transform: Option<Transform>);
+    __setter!(geometry: Option<PresetGeometry<'a>>);
+    __setter!(fill: Option<Fill<'a>>);
+    __setter!(outline: Option<Outline<'a>>);
+}
+
+/// Text Box of a shape
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wps:txbx")]
+pub struct TextBox<'a> {
+    #[xml(default, child = "w:txbxContent")]
+    pub content: TextBoxContent<'a>,
+}
+
+/// Body Properties of a shape
+///
+/// Specifies the insets and the vertical alignment of the text box.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wps:bodyPr")]
+pub struct BodyProperty {
+    /// Specifies the left inset in EMUs.
+    #[xml(attr = "lIns")]
+    pub left_inset: Option<u64>,
+    /// Specifies the top inset in EMUs.
+    #[xml(attr = "tIns")]
+    pub top_inset: Option<u64>,
+    /// Specifies the right inset in EMUs.
+    #[xml(attr = "rIns")]
+    pub right_inset: Option<u64>,
+    /// Specifies the bottom inset in EMUs.
+    #[xml(attr = "bIns")]
+    pub bottom_inset: Option<u64>,
+    /// Specifies the vertical alignment of the text.
+    #[xml(attr = "anchor")]
+    pub anchor: Option<TextAnchor>,
+}
+
+impl BodyProperty {
+    __setter!(left_inset: Option<u64>);
+    __setter!(top_inset: Option<u64>);
+    __setter!(right_inset: Option<u64>);
+    __setter!(bottom_inset: Option<u64>);
+    __setter!(anchor: Option<TextAnchor>);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextAnchor {
+    Top,
+    Center,
+    Bottom,
+    Justified,
+    Distributed,
+}
+
+__string_enum! {
+    TextAnchor {
+        Top = "t",
+        Center = "ctr",
+        Bottom = "b",
+        Justified = "just",
+        Distributed = "dist",
+    }
+}
+
+#[cfg(test)]
+use crate::document::Paragraph;
+
+__xml_test_suites!(
+    Shape,
+    Shape::default(),
+    r#"<wps:wsp><wps:spPr/><wps:bodyPr/></wps:wsp>"#,
+    Shape::new("roundRect", 2, 1).fill(Fill::solid("FF0000")),
+    r#"<wps:wsp><wps:cNvSpPr/><wps:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="2" cy="1"/></a:xfrm><a:prstGeom prst="roundRect"><a:avLst/></a:prstGeom><a:solidFill><a:srgbClr val="FF0000"/></a:solidFill></wps:spPr><wps:bodyPr/></wps:wsp>"#,
+    Shape::line(2, 0),
+    r#"<wps:wsp><wps:cNvCnPr/><wps:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="2" cy="0"/></a:xfrm><a:prstGeom prst="line"><a:avLst/></a:prstGeom><a:ln w="6350"><a:solidFill><a:srgbClr val="000000"/></a:solidFill></a:ln></wps:spPr><wps:bodyPr/></wps:wsp>"#,
+    Shape::new("rect", 2, 1)
+        .push(Paragraph::default().push_text("text"))
+        .body_property(BodyProperty::default().anchor(TextAnchor::Center)),
+    r#"<wps:wsp><wps:cNvSpPr/><wps:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="2" cy="1"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></wps:spPr><wps:txbx><w:txbxContent><w:p><w:pPr/><w:r><w:rPr/><w:t>text</w:t></w:r></w:p></w:txbxContent></wps:txbx><wps:bodyPr anchor="ctr"/></wps:wsp>"#,
+);
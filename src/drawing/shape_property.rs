@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+/// Shape Properties of a picture
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let prop = ShapeProperty::default()
+///     .transform((914400, 457200))
+///     .geometry("rect");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "pic:spPr")]
+pub struct ShapeProperty<'a> {
+    /// Specifies the position and size.
+    #[xml(child = "a:xfrm")]
+    pub transform: Option<Transform>,
+    /// Specifies the preset geometry.
+    #[xml(child = "a:prstGeom")]
+    pub geometry: Option<PresetGeometry<'a>>,
+}
+
+impl<'a> ShapeProperty<'a> {
+    __setter!(transform: Option<Transform>);
+    __setter!(geometry: Option<PresetGeometry<'a>>);
+}
+
+/// 2D Transform
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:xfrm")]
+pub struct Transform {
+    /// Specifies the offset in EMUs.
+    #[xml(child = "a:off")]
+    pub offset: Option<Offset>,
+    /// Specifies the size in EMUs.
+    #[xml(child = "a:ext")]
+    pub extent: Option<ShapeExtent>,
+}
+
+impl From<(u64, u64)> for Transform {
+    fn from((width, height): (u64, u64)) -> Self {
+        Transform {
+            offset: Some(Offset::default()),
+            extent: Some(ShapeExtent { width, height }),
+        }
+    }
+}
+
+/// Offset
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:off")]
+pub struct Offset {
+    #[xml(attr = "x")]
+    pub x: i64,
+    #[xml(attr = "y")]
+    pub y: i64,
+}
+
+/// Extent of a shape
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:ext")]
+pub struct ShapeExtent {
+    #[xml(attr = "cx")]
+    pub width: u64,
+    #[xml(attr = "cy")]
+    pub height: u64,
+}
+
+/// Preset Geometry
+///
+/// Specifies the shape by a preset name, e.g. `rect`.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:prstGeom")]
+pub struct PresetGeometry<'a> {
+    #[xml(attr = "prst")]
+    pub preset: Cow<'a, str>,
+    #[xml(default, child = "a:avLst")]
+    pub adjust_values: AdjustValues,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for PresetGeometry<'a> {
+    fn from(preset: T) -> Self {
+        PresetGeometry {
+            preset: preset.into(),
+            adjust_values: AdjustValues {},
+        }
+    }
+}
+
+/// Shape Adjust Values
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:avLst")]
+pub struct AdjustValues {}
+
+__xml_test_suites!(
+    ShapeProperty,
+    ShapeProperty::default(),
+    r#"<pic:spPr/>"#,
+    ShapeProperty::default()
+        .transform((914400, 457200))
+        .geometry("rect"),
+    r#"<pic:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="914400" cy="457200"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></pic:spPr>"#,
+);
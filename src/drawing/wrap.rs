@@ -0,0 +1,226 @@
+use derive_more::From;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites, drawing::EffectExtent};
+
+/// Text wrapping of an anchored drawing
+///
+/// Drawings behind or in front of the text don't wrap it, which one is
+/// specified by `Anchor::behind_doc`.
+///
+/// ```rust
+/// use docx::drawing::*;
+///
+/// let square = Wrap::from(WrapSquare::default().wrap_text(WrapText::Largest));
+/// let top_and_bottom = Wrap::from(WrapTopAndBottom::default());
+/// let none = Wrap::from(WrapNone::default());
+/// ```
+#[derive(Debug, From, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum Wrap {
+    #[xml(tag = "wp:wrapNone")]
+    None(WrapNone),
+    #[xml(tag = "wp:wrapSquare")]
+    Square(WrapSquare),
+    #[xml(tag = "wp:wrapTight")]
+    Tight(WrapTight),
+    #[xml(tag = "wp:wrapThrough")]
+    Through(WrapThrough),
+    #[xml(tag = "wp:wrapTopAndBottom")]
+    TopAndBottom(WrapTopAndBottom),
+}
+
+impl Default for Wrap {
+    fn default() -> Self {
+        Wrap::Square(WrapSquare::default())
+    }
+}
+
+/// No Text Wrapping
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:wrapNone")]
+pub struct WrapNone {}
+
+/// Square Wrapping
+///
+/// Wraps the text around the bounding box of the drawing.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:wrapSquare")]
+pub struct WrapSquare {
+    /// Specifies which sides the text wraps on.
+    #[xml(attr = "wrapText")]
+    pub wrap_text: WrapText,
+    #[xml(attr = "distT")]
+    pub distance_top: Option<u64>,
+    #[xml(attr = "distB")]
+    pub distance_bottom: Option<u64>,
+    #[xml(attr = "distL")]
+    pub distance_left: Option<u64>,
+    #[xml(attr = "distR")]
+    pub distance_right: Option<u64>,
+    #[xml(child = "wp:effectExtent")]
+    pub effect_extent: Option<EffectExtent>,
+}
+
+impl WrapSquare {
+    __setter!(wrap_text: WrapText);
+    __setter!(distance_top: Option<u64>);
+    __setter!(distance_bottom: Option<u64>);
+    __setter!(distance_left: Option<u64>);
+    __setter!(distance_right: Option<u64>);
+}
+
+/// Tight Wrapping
+///
+/// Wraps the text around the polygon, which is the bounding box by default.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:wrapTight")]
+pub struct WrapTight {
+    #[xml(attr = "wrapText")]
+    pub wrap_text: WrapText,
+    #[xml(attr = "distL")]
+    pub distance_left: Option<u64>,
+    #[xml(attr = "distR")]
+    pub distance_right: Option<u64>,
+    #[xml(default, child = "wp:wrapPolygon")]
+    pub polygon: WrapPolygon,
+}
+
+impl WrapTight {
+    __setter!(wrap_text: WrapText);
+    __setter!(distance_left: Option<u64>);
+    __setter!(distance_right: Option<u64>);
+    __setter!(polygon: WrapPolygon);
+}
+
+/// Through Wrapping
+///
+/// Wraps the text around the polygon, including its interior gaps.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:wrapThrough")]
+pub struct WrapThrough {
+    #[xml(attr = "wrapText")]
+    pub wrap_text: WrapText,
+    #[xml(attr = "distL")]
+    pub distance_left: Option<u64>,
+    #[xml(attr = "distR")]
+    pub distance_right: Option<u64>,
+    #[xml(default, child = "wp:wrapPolygon")]
+    pub polygon: WrapPolygon,
+}
+
+impl WrapThrough {
+    __setter!(wrap_text: WrapText);
+    __setter!(distance_left: Option<u64>);
+    __setter!(distance_right: Option<u64>);
+    __setter!(polygon: WrapPolygon);
+}
+
+/// Top and Bottom Wrapping
+///
+/// Places the text above and below the drawing, but not beside it.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:wrapTopAndBottom")]
+pub struct WrapTopAndBottom {
+    #[xml(attr = "distT")]
+    pub distance_top: Option<u64>,
+    #[xml(attr = "distB")]
+    pub distance_bottom: Option<u64>,
+    #[xml(child = "wp:effectExtent")]
+    pub effect_extent: Option<EffectExtent>,
+}
+
+impl WrapTopAndBottom {
+    __setter!(distance_top: Option<u64>);
+    __setter!(distance_bottom: Option<u64>);
+}
+
+/// Wrapping Polygon
+///
+/// Coordinates are relative to the drawing, in units where 21600 is its
+/// width or height.
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:wrapPolygon")]
+pub struct WrapPolygon {
+    /// Specifies that the polygon is edited by the user.
+    #[xml(attr = "edited")]
+    pub edited: Option<bool>,
+    #[xml(default, child = "wp:start")]
+    pub start: PolygonStart,
+    #[xml(child = "wp:lineTo")]
+    pub line_to: Vec<PolygonLineTo>,
+}
+
+impl Default for WrapPolygon {
+    /// Returns the bounding box of the drawing
+    fn default() -> Self {
+        WrapPolygon {
+            edited: Some(false),
+            start: PolygonStart { x: 0, y: 0 },
+            line_to: [(0, 21600), (21600, 21600), (21600, 0), (0, 0)]
+                .iter()
+                .map(|&(x, y)| PolygonLineTo { x, y })
+                .collect(),
+        }
+    }
+}
+
+/// Wrapping Polygon Start
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:start")]
+pub struct PolygonStart {
+    #[xml(attr = "x")]
+    pub x: i64,
+    #[xml(attr = "y")]
+    pub y: i64,
+}
+
+/// Wrapping Polygon Line End Position
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "wp:lineTo")]
+pub struct PolygonLineTo {
+    #[xml(attr = "x")]
+    pub x: i64,
+    #[xml(attr = "y")]
+    pub y: i64,
+}
+
+/// Sides of the drawing which the text wraps on
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum WrapText {
+    #[default]
+    BothSides,
+    Left,
+    Right,
+    /// Only the side with the largest space.
+    Largest,
+}
+
+__string_enum! {
+    WrapText {
+        BothSides = "bothSides",
+        Left = "left",
+        Right = "right",
+        Largest = "largest",
+    }
+}
+
+__xml_test_suites!(
+    Wrap,
+    Wrap::default(),
+    r#"<wp:wrapSquare wrapText="bothSides"/>"#,
+    Wrap::from(WrapNone::default()),
+    r#"<wp:wrapNone/>"#,
+    Wrap::from(WrapTopAndBottom::default().distance_top(0u64)),
+    r#"<wp:wrapTopAndBottom distT="0"/>"#,
+    Wrap::from(WrapTight::default().wrap_text(WrapText::Largest)),
+    r#"<wp:wrapTight wrapText="largest"><wp:wrapPolygon edited="false"><wp:start x="0" y="0"/><wp:lineTo x="0" y="21600"/><wp:lineTo x="21600" y="21600"/><wp:lineTo x="21600" y="0"/><wp:lineTo x="0" y="0"/></wp:wrapPolygon></wp:wrapTight>"#,
+);
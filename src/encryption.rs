@@ -0,0 +1,677 @@
+//! Password-encrypted packages
+//!
+//! An encrypted package is an OLE compound file containing an
+//! `EncryptionInfo` stream, which describes how to derive the key from the
+//! password, and an `EncryptedPackage` stream, which holds the encrypted ZIP
+//! archive. Only the agile encryption is supported.
+
+use aes::cipher::{
+    block_padding::NoPadding, BlockCipher, BlockDecryptMut, BlockEncryptMut, KeyInit, KeyIvInit,
+};
+use aes::{Aes128, Aes192, Aes256};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::borrow::Cow;
+use std::convert::TryInto;
+use std::io::{Cursor, Read, Seek, Write};
+use strong_xml::{XmlError, XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::{
+    __string_enum,
+    error::{DocxError, DocxResult},
+};
+
+const SCHEMA_ENCRYPTION: &str = "http://schemas.microsoft.com/office/2006/encryption";
+const SCHEMA_KEY_ENCRYPTOR_PASSWORD: &str =
+    "http://schemas.microsoft.com/office/2006/keyEncryptor/password";
+
+const BLOCK_KEY_VERIFIER_INPUT: [u8; 8] = [0xfe, 0xa7, 0xd2, 0x76, 0x3b, 0x4b, 0x9e, 0x79];
+const BLOCK_KEY_VERIFIER_VALUE: [u8; 8] = [0xd7, 0xaa, 0x0f, 0x6d, 0x30, 0x61, 0x34, 0x4e];
+const BLOCK_KEY_ENCRYPTED_KEY: [u8; 8] = [0x14, 0x6e, 0x0b, 0xe7, 0xab, 0xac, 0xd0, 0xd6];
+const BLOCK_KEY_INTEGRITY_KEY: [u8; 8] = [0x5f, 0xb2, 0xad, 0x01, 0x0c, 0xb9, 0xe1, 0xf6];
+const BLOCK_KEY_INTEGRITY_VALUE: [u8; 8] = [0xa0, 0x67, 0x7f, 0x02, 0xb2, 0x2c, 0x84, 0x33];
+
+const SEGMENT_LENGTH: usize = 4096;
+
+/// The spin count used by Office when encrypting documents
+pub const SPIN_COUNT: u32 = 100_000;
+
+/// Decrypts an encrypted package and returns the ZIP archive
+pub fn decrypt<R: Read + Seek>(reader: R, password: &str) -> DocxResult<Vec<u8>> {
+    let mut file = cfb::CompoundFile::open(reader)?;
+
+    if !file.is_stream("/EncryptionInfo") || !file.is_stream("/EncryptedPackage") {
+        return Err(DocxError::Encryption(
+            "Compound file doesn't contain an encrypted package".into(),
+        ));
+    }
+
+    let mut info = Vec::new();
+    file.open_stream("/EncryptionInfo")?
+        .read_to_end(&mut info)?;
+
+    let mut package = Vec::new();
+    file.open_stream("/EncryptedPackage")?
+        .read_to_end(&mut package)?;
+
+    if info.len() < 8 {
+        return Err(DocxError::Encryption(
+            "Malformed EncryptionInfo stream".into(),
+        ));
+    }
+
+    let major = u16::from_le_bytes([info[0], info[1]]);
+    let minor = u16::from_le_bytes([info[2], info[3]]);
+
+    if (major, minor) != (4, 4) {
+        return Err(DocxError::Encryption(format!(
+            "Unsupported encryption version {}.{}, only agile encryption is supported",
+            major, minor
+        )));
+    }
+
+    let xml = std::str::from_utf8(&info[8..]).map_err(XmlError::from)?;
+    let info = EncryptionInfo::from_str(xml)?;
+
+    let key_data = &info.key_data;
+    key_data.check_cipher()?;
+
+    let encryptor = info
+        .key_encryptors
+        .encryptors
+        .iter()
+        .find_map(|encryptor| encryptor.password.as_ref())
+        .ok_or_else(|| DocxError::Encryption("No password key encryptor found".into()))?;
+    encryptor.check_cipher()?;
+
+    let salt = decode(&encryptor.salt_value)?;
+    let hash = password_hash(
+        encryptor.hash_algorithm,
+        &salt,
+        password,
+        encryptor.spin_count,
+    );
+    let key_len = encryptor.key_bits / 8;
+
+    // verify the password
+    let key = derive_key(
+        encryptor.hash_algorithm,
+        &hash,
+        &BLOCK_KEY_VERIFIER_INPUT,
+        key_len,
+    );
+    let mut verifier_input = decode(&encryptor.encrypted_verifier_hash_input)?;
+    cbc_decrypt(&key, &salt, &mut verifier_input)?;
+    verifier_input.truncate(encryptor.salt_size);
+
+    let key = derive_key(
+        encryptor.hash_algorithm,
+        &hash,
+        &BLOCK_KEY_VERIFIER_VALUE,
+        key_len,
+    );
+    let mut verifier_hash = decode(&encryptor.encrypted_verifier_hash_value)?;
+    cbc_decrypt(&key, &salt, &mut verifier_hash)?;
+    verifier_hash.truncate(encryptor.hash_size);
+
+    if encryptor.hash_algorithm.digest(&[&verifier_input]) != verifier_hash {
+        return Err(DocxError::InvalidPassword);
+    }
+
+    let key = derive_key(
+        encryptor.hash_algorithm,
+        &hash,
+        &BLOCK_KEY_ENCRYPTED_KEY,
+        key_len,
+    );
+    let mut secret_key = decode(&encryptor.encrypted_key_value)?;
+    cbc_decrypt(&key, &salt, &mut secret_key)?;
+    secret_key.truncate(key_data.key_bits / 8);
+
+    let key_salt = decode(&key_data.salt_value)?;
+
+    // verify the integrity of the encrypted package
+    if let Some(integrity) = &info.data_integrity {
+        let mut hmac_key = decode(&integrity.encrypted_hmac_key)?;
+        let iv = key_data.iv(&key_salt, &BLOCK_KEY_INTEGRITY_KEY);
+        cbc_decrypt(&secret_key, &iv, &mut hmac_key)?;
+        hmac_key.truncate(key_data.hash_size);
+
+        let mut hmac_value = decode(&integrity.encrypted_hmac_value)?;
+        let iv = key_data.iv(&key_salt, &BLOCK_KEY_INTEGRITY_VALUE);
+        cbc_decrypt(&secret_key, &iv, &mut hmac_value)?;
+        hmac_value.truncate(key_data.hash_size);
+
+        if key_data.hash_algorithm.hmac(&hmac_key, &package)? != hmac_value {
+            return Err(DocxError::Encryption(
+                "Data integrity check of the encrypted package failed".into(),
+            ));
+        }
+    }
+
+    if package.len() < 8 {
+        return Err(DocxError::Encryption(
+            "Malformed EncryptedPackage stream".into(),
+        ));
+    }
+
+    let size = u64::from_le_bytes(package[0..8].try_into().unwrap()) as usize;
+
+    let mut output = Vec::with_capacity(package.len() - 8);
+    for (index, segment) in package[8..].chunks(SEGMENT_LENGTH).enumerate() {
+        let mut segment = segment.to_vec();
+        let iv = key_data.iv(&key_salt, &(index as u32).to_le_bytes());
+        cbc_decrypt(&secret_key, &iv, &mut segment)?;
+        output.extend_from_slice(&segment);
+    }
+
+    if output.len() < size {
+        return Err(DocxError::Encryption(
+            "Truncated EncryptedPackage stream".into(),
+        ));
+    }
+
+    output.truncate(size);
+
+    Ok(output)
+}
+
+/// Encrypts a ZIP archive and returns the encrypted package
+pub fn encrypt(package: &[u8], password: &str, spin_count: u32) -> DocxResult<Vec<u8>> {
+    const HASH_ALGORITHM: HashAlgorithm = HashAlgorithm::Sha512;
+    const KEY_LEN: usize = 32;
+    const SALT_LEN: usize = 16;
+    const BLOCK_SIZE: usize = 16;
+    const HASH_SIZE: usize = 64;
+
+    let key_data = KeyData {
+        salt_size: SALT_LEN,
+        block_size: BLOCK_SIZE,
+        key_bits: KEY_LEN * 8,
+        hash_size: HASH_SIZE,
+        cipher_algorithm: "AES".into(),
+        cipher_chaining: "ChainingModeCBC".into(),
+        hash_algorithm: HASH_ALGORITHM,
+        salt_value: base64::encode(random(SALT_LEN)?).into(),
+    };
+
+    let key_salt = decode(&key_data.salt_value)?;
+    let secret_key = random(KEY_LEN)?;
+
+    // encrypt the package segment by segment
+    let mut encrypted = Vec::with_capacity(package.len() + 8 + BLOCK_SIZE);
+    encrypted.extend_from_slice(&(package.len() as u64).to_le_bytes());
+    for (index, segment) in package.chunks(SEGMENT_LENGTH).enumerate() {
+        let mut segment = pad(segment.to_vec(), BLOCK_SIZE);
+        let iv = key_data.iv(&key_salt, &(index as u32).to_le_bytes());
+        cbc_encrypt(&secret_key, &iv, &mut segment)?;
+        encrypted.extend_from_slice(&segment);
+    }
+
+    // sign the encrypted package
+    let hmac_key = random(HASH_SIZE)?;
+    let hmac_value = HASH_ALGORITHM.hmac(&hmac_key, &encrypted)?;
+
+    let mut encrypted_hmac_key = hmac_key;
+    let iv = key_data.iv(&key_salt, &BLOCK_KEY_INTEGRITY_KEY);
+    cbc_encrypt(&secret_key, &iv, &mut encrypted_hmac_key)?;
+
+    let mut encrypted_hmac_value = hmac_value;
+    let iv = key_data.iv(&key_salt, &BLOCK_KEY_INTEGRITY_VALUE);
+    cbc_encrypt(&secret_key, &iv, &mut encrypted_hmac_value)?;
+
+    // encrypt the secret key with the password
+    let salt = random(SALT_LEN)?;
+    let hash = password_hash(HASH_ALGORITHM, &salt, password, spin_count);
+
+    let verifier_input = random(SALT_LEN)?;
+    let verifier_hash = HASH_ALGORITHM.digest(&[&verifier_input]);
+
+    let mut encrypted_verifier_input = verifier_input;
+    let key = derive_key(HASH_ALGORITHM, &hash, &BLOCK_KEY_VERIFIER_INPUT, KEY_LEN);
+    cbc_encrypt(&key, &salt, &mut encrypted_verifier_input)?;
+
+    let mut encrypted_verifier_hash = pad(verifier_hash, BLOCK_SIZE);
+    let key = derive_key(HASH_ALGORITHM, &hash, &BLOCK_KEY_VERIFIER_VALUE, KEY_LEN);
+    cbc_encrypt(&key, &salt, &mut encrypted_verifier_hash)?;
+
+    let mut encrypted_key = secret_key;
+    let key = derive_key(HASH_ALGORITHM, &hash, &BLOCK_KEY_ENCRYPTED_KEY, KEY_LEN);
+    cbc_encrypt(&key, &salt, &mut encrypted_key)?;
+
+    let info = EncryptionInfo {
+        key_data,
+        data_integrity: Some(DataIntegrity {
+            encrypted_hmac_key: base64::encode(encrypted_hmac_key).into(),
+            encrypted_hmac_value: base64::encode(encrypted_hmac_value).into(),
+        }),
+        key_encryptors: KeyEncryptors {
+            encryptors: vec![KeyEncryptor {
+                uri: SCHEMA_KEY_ENCRYPTOR_PASSWORD.into(),
+                password: Some(PasswordKeyEncryptor {
+                    spin_count,
+                    salt_size: SALT_LEN,
+                    block_size: BLOCK_SIZE,
+                    key_bits: KEY_LEN * 8,
+                    hash_size: HASH_SIZE,
+                    cipher_algorithm: "AES".into(),
+                    cipher_chaining: "ChainingModeCBC".into(),
+                    hash_algorithm: HASH_ALGORITHM,
+                    salt_value: base64::encode(salt).into(),
+                    encrypted_verifier_hash_input: base64::encode(encrypted_verifier_input).into(),
+                    encrypted_verifier_hash_value: base64::encode(encrypted_verifier_hash).into(),
+                    encrypted_key_value: base64::encode(encrypted_key).into(),
+                }),
+            }],
+        },
+    };
+
+    // version 4.4 and the reserved flags of agile encryption
+    let mut info_stream = vec![0x04, 0x00, 0x04, 0x00, 0x40, 0x00, 0x00, 0x00];
+    info_stream.extend_from_slice(br#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#);
+    info_stream.extend_from_slice(b"\r\n");
+    info.to_writer(&mut XmlWriter::new(&mut info_stream))?;
+
+    let mut file = cfb::CompoundFile::create(Cursor::new(Vec::new()))?;
+
+    file.create_storage_all("/\u{6}DataSpaces/DataSpaceInfo")?;
+    file.create_storage_all("/\u{6}DataSpaces/TransformInfo/StrongEncryptionTransform")?;
+
+    macro_rules! write_stream {
+        ($path:expr, $content:expr) => {
+            file.create_stream($path)?.write_all(&$content)?;
+        };
+    }
+
+    write_stream!("/\u{6}DataSpaces/Version", data_space_version());
+    write_stream!("/\u{6}DataSpaces/DataSpaceMap", data_space_map());
+    write_stream!(
+        "/\u{6}DataSpaces/DataSpaceInfo/StrongEncryptionDataSpace",
+        data_space_definition()
+    );
+    write_stream!(
+        "/\u{6}DataSpaces/TransformInfo/StrongEncryptionTransform/\u{6}Primary",
+        transform_info()
+    );
+    write_stream!("/EncryptionInfo", info_stream);
+    write_stream!("/EncryptedPackage", encrypted);
+
+    file.flush()?;
+
+    Ok(file.into_inner().into_inner())
+}
+
+/// Hashes the password with salt, iterating `spin_count` times
+fn password_hash(
+    algorithm: HashAlgorithm,
+    salt: &[u8],
+    password: &str,
+    spin_count: u32,
+) -> Vec<u8> {
+    let password: Vec<u8> = password.encode_utf16().flat_map(u16::to_le_bytes).collect();
+
+    let mut hash = algorithm.digest(&[salt, &password]);
+
+    for i in 0..spin_count {
+        hash = algorithm.digest(&[&i.to_le_bytes(), &hash]);
+    }
+
+    hash
+}
+
+/// Derives the encryption key for a block from the password hash
+fn derive_key(algorithm: HashAlgorithm, hash: &[u8], block_key: &[u8], len: usize) -> Vec<u8> {
+    let mut key = algorithm.digest(&[hash, block_key]);
+    key.resize(len, 0x36);
+    key
+}
+
+fn decode(value: &str) -> DocxResult<Vec<u8>> {
+    base64::decode(value)
+        .map_err(|_| DocxError::Encryption(format!("Malformed base64 value `{}`", value)))
+}
+
+/// Pads data with zeros to a multiple of the block size
+fn pad(mut data: Vec<u8>, block_size: usize) -> Vec<u8> {
+    let len = data.len().div_ceil(block_size) * block_size;
+    data.resize(len, 0);
+    data
+}
+
+fn random(len: usize) -> DocxResult<Vec<u8>> {
+    let mut buffer = vec![0; len];
+    getrandom::getrandom(&mut buffer)?;
+    Ok(buffer)
+}
+
+fn cbc_encrypt(key: &[u8], iv: &[u8], data: &mut [u8]) -> DocxResult<()> {
+    fn encrypt<C: BlockEncryptMut + BlockCipher + KeyInit>(
+        key: &[u8],
+        iv: &[u8],
+        data: &mut [u8],
+    ) -> DocxResult<()> {
+        let len = data.len();
+        cbc::Encryptor::<C>::new_from_slices(key, iv)
+            .map_err(|_| DocxError::Encryption("Invalid key or IV length".into()))?
+            .encrypt_padded_mut::<NoPadding>(data, len)
+            .map_err(|_| DocxError::Encryption("Data is not aligned to block size".into()))?;
+        Ok(())
+    }
+
+    match key.len() {
+        16 => encrypt::<Aes128>(key, iv, data),
+        24 => encrypt::<Aes192>(key, iv, data),
+        32 => encrypt::<Aes256>(key, iv, data),
+        len => Err(DocxError::Encryption(format!(
+            "Unsupported key length {}",
+            len * 8
+        ))),
+    }
+}
+
+fn cbc_decrypt(key: &[u8], iv: &[u8], data: &mut [u8]) -> DocxResult<()> {
+    fn decrypt<C: BlockDecryptMut + BlockCipher + KeyInit>(
+        key: &[u8],
+        iv: &[u8],
+        data: &mut [u8],
+    ) -> DocxResult<()> {
+        cbc::Decryptor::<C>::new_from_slices(key, iv)
+            .map_err(|_| DocxError::Encryption("Invalid key or IV length".into()))?
+            .decrypt_padded_mut::<NoPadding>(data)
+            .map_err(|_| DocxError::Encryption("Data is not aligned to block size".into()))?;
+        Ok(())
+    }
+
+    match key.len() {
+        16 => decrypt::<Aes128>(key, iv, data),
+        24 => decrypt::<Aes192>(key, iv, data),
+        32 => decrypt::<Aes256>(key, iv, data),
+        len => Err(DocxError::Encryption(format!(
+            "Unsupported key length {}",
+            len * 8
+        ))),
+    }
+}
+
+/// Writes a length-prefixed UTF-16 string padded to 4 bytes
+fn write_unicode_lp_p4(buffer: &mut Vec<u8>, value: &str) {
+    let value: Vec<u8> = value.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(&pad(value, 4));
+}
+
+fn data_space_version() -> Vec<u8> {
+    let mut buffer = Vec::new();
+    write_unicode_lp_p4(&mut buffer, "Microsoft.Container.DataSpaces");
+    // reader, updater and writer version, all 1.0
+    buffer.extend_from_slice(&[1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+    buffer
+}
+
+fn data_space_map() -> Vec<u8> {
+    let mut entry = Vec::new();
+    // reference component count
+    entry.extend_from_slice(&1u32.to_le_bytes());
+    // reference component type: stream
+    entry.extend_from_slice(&0u32.to_le_bytes());
+    write_unicode_lp_p4(&mut entry, "EncryptedPackage");
+    write_unicode_lp_p4(&mut entry, "StrongEncryptionDataSpace");
+
+    let mut buffer = Vec::new();
+    // header length
+    buffer.extend_from_slice(&8u32.to_le_bytes());
+    // entry count
+    buffer.extend_from_slice(&1u32.to_le_bytes());
+    buffer.extend_from_slice(&(entry.len() as u32 + 4).to_le_bytes());
+    buffer.extend_from_slice(&entry);
+    buffer
+}
+
+fn data_space_definition() -> Vec<u8> {
+    let mut buffer = Vec::new();
+    // header length
+    buffer.extend_from_slice(&8u32.to_le_bytes());
+    // transform reference count
+    buffer.extend_from_slice(&1u32.to_le_bytes());
+    write_unicode_lp_p4(&mut buffer, "StrongEncryptionTransform");
+    buffer
+}
+
+fn transform_info() -> Vec<u8> {
+    let mut id = Vec::new();
+    write_unicode_lp_p4(&mut id, "{FF9A3F03-56EF-4613-BDD5-5A41C1D07246}");
+
+    let mut buffer = Vec::new();
+    // transform length: the bytes before transform name
+    buffer.extend_from_slice(&(id.len() as u32 + 8).to_le_bytes());
+    // transform type
+    buffer.extend_from_slice(&1u32.to_le_bytes());
+    buffer.extend_from_slice(&id);
+    write_unicode_lp_p4(&mut buffer, "Microsoft.Container.EncryptionTransform");
+    // reader, updater and writer version, all 1.0
+    buffer.extend_from_slice(&[1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+    // encryption name (empty), block size, cipher mode and reserved
+    buffer.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0]);
+    buffer
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HashAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+__string_enum! {
+    HashAlgorithm {
+        Sha1 = "SHA1",
+        Sha256 = "SHA256",
+        Sha384 = "SHA384",
+        Sha512 = "SHA512",
+    }
+}
+
+impl HashAlgorithm {
+    fn digest(self, parts: &[&[u8]]) -> Vec<u8> {
+        fn digest<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+            let mut hasher = D::new();
+            for part in parts {
+                hasher.update(part);
+            }
+            hasher.finalize().to_vec()
+        }
+
+        match self {
+            HashAlgorithm::Sha1 => digest::<Sha1>(parts),
+            HashAlgorithm::Sha256 => digest::<Sha256>(parts),
+            HashAlgorithm::Sha384 => digest::<Sha384>(parts),
+            HashAlgorithm::Sha512 => digest::<Sha512>(parts),
+        }
+    }
+
+    fn hmac(self, key: &[u8], data: &[u8]) -> DocxResult<Vec<u8>> {
+        fn hmac<M: Mac + KeyInit>(key: &[u8], data: &[u8]) -> DocxResult<Vec<u8>> {
+            let mut mac = <M as KeyInit>::new_from_slice(key)
+                .map_err(|_| DocxError::Encryption("Invalid HMAC key length".into()))?;
+            mac.update(data);
+            Ok(mac.finalize().into_bytes().to_vec())
+        }
+
+        match self {
+            HashAlgorithm::Sha1 => hmac::<Hmac<Sha1>>(key, data),
+            HashAlgorithm::Sha256 => hmac::<Hmac<Sha256>>(key, data),
+            HashAlgorithm::Sha384 => hmac::<Hmac<Sha384>>(key, data),
+            HashAlgorithm::Sha512 => hmac::<Hmac<Sha512>>(key, data),
+        }
+    }
+}
+
+#[derive(Debug, XmlRead)]
+#[xml(tag = "encryption")]
+struct EncryptionInfo<'a> {
+    #[xml(child = "keyData")]
+    key_data: KeyData<'a>,
+    #[xml(child = "dataIntegrity")]
+    data_integrity: Option<DataIntegrity<'a>>,
+    #[xml(child = "keyEncryptors")]
+    key_encryptors: KeyEncryptors<'a>,
+}
+
+impl<'a> XmlWrite for EncryptionInfo<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let EncryptionInfo {
+            key_data,
+            data_integrity,
+            key_encryptors,
+        } = self;
+
+        log::debug!("[EncryptionInfo] Started writing.");
+
+        writer.write_element_start("encryption")?;
+
+        writer.write_attribute("xmlns", SCHEMA_ENCRYPTION)?;
+        writer.write_attribute("xmlns:p", SCHEMA_KEY_ENCRYPTOR_PASSWORD)?;
+
+        writer.write_element_end_open()?;
+
+        key_data.to_writer(writer)?;
+
+        if let Some(ele) = data_integrity {
+            ele.to_writer(writer)?;
+        }
+
+        key_encryptors.to_writer(writer)?;
+
+        writer.write_element_end_close("encryption")?;
+
+        log::debug!("[EncryptionInfo] Finished writing.");
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, XmlRead, XmlWrite)]
+#[xml(tag = "keyData")]
+struct KeyData<'a> {
+    #[xml(attr = "saltSize")]
+    salt_size: usize,
+    #[xml(attr = "blockSize")]
+    block_size: usize,
+    #[xml(attr = "keyBits")]
+    key_bits: usize,
+    #[xml(attr = "hashSize")]
+    hash_size: usize,
+    #[xml(attr = "cipherAlgorithm")]
+    cipher_algorithm: Cow<'a, str>,
+    #[xml(attr = "cipherChaining")]
+    cipher_chaining: Cow<'a, str>,
+    #[xml(attr = "hashAlgorithm")]
+    hash_algorithm: HashAlgorithm,
+    #[xml(attr = "saltValue")]
+    salt_value: Cow<'a, str>,
+}
+
+impl<'a> KeyData<'a> {
+    fn check_cipher(&self) -> DocxResult<()> {
+        check_cipher(&self.cipher_algorithm, &self.cipher_chaining)
+    }
+
+    /// Generates the initialization vector from the salt and block key
+    fn iv(&self, salt: &[u8], block_key: &[u8]) -> Vec<u8> {
+        let mut iv = self.hash_algorithm.digest(&[salt, block_key]);
+        iv.resize(self.block_size, 0x36);
+        iv
+    }
+}
+
+#[derive(Debug, XmlRead, XmlWrite)]
+#[xml(tag = "dataIntegrity")]
+struct DataIntegrity<'a> {
+    #[xml(attr = "encryptedHmacKey")]
+    encrypted_hmac_key: Cow<'a, str>,
+    #[xml(attr = "encryptedHmacValue")]
+    encrypted_hmac_value: Cow<'a, str>,
+}
+
+#[derive(Debug, XmlRead, XmlWrite)]
+#[xml(tag = "keyEncryptors")]
+struct KeyEncryptors<'a> {
+    #[xml(child = "keyEncryptor")]
+    encryptors: Vec<KeyEncryptor<'a>>,
+}
+
+#[derive(Debug, XmlRead, XmlWrite)]
+#[xml(tag = "keyEncryptor")]
+struct KeyEncryptor<'a> {
+    #[xml(attr = "uri")]
+    uri: Cow<'a, str>,
+    #[xml(child = "p:encryptedKey")]
+    password: Option<PasswordKeyEncryptor<'a>>,
+}
+
+#[derive(Debug, XmlRead, XmlWrite)]
+#[xml(tag = "p:encryptedKey")]
+struct PasswordKeyEncryptor<'a> {
+    #[xml(attr = "spinCount")]
+    spin_count: u32,
+    #[xml(attr = "saltSize")]
+    salt_size: usize,
+    #[xml(attr = "blockSize")]
+    block_size: usize,
+    #[xml(attr = "keyBits")]
+    key_bits: usize,
+    #[xml(attr = "hashSize")]
+    hash_size: usize,
+    #[xml(attr = "cipherAlgorithm")]
+    cipher_algorithm: Cow<'a, str>,
+    #[xml(attr = "cipherChaining")]
+    cipher_chaining: Cow<'a, str>,
+    #[xml(attr = "hashAlgorithm")]
+    hash_algorithm: HashAlgorithm,
+    #[xml(attr = "saltValue")]
+    salt_value: Cow<'a, str>,
+    #[xml(attr = "encryptedVerifierHashInput")]
+    encrypted_verifier_hash_input: Cow<'a, str>,
+    #[xml(attr = "encryptedVerifierHashValue")]
+    encrypted_verifier_hash_value: Cow<'a, str>,
+    #[xml(attr = "encryptedKeyValue")]
+    encrypted_key_value: Cow<'a, str>,
+}
+
+impl<'a> PasswordKeyEncryptor<'a> {
+    fn check_cipher(&self) -> DocxResult<()> {
+        check_cipher(&self.cipher_algorithm, &self.cipher_chaining)
+    }
+}
+
+fn check_cipher(algorithm: &str, chaining: &str) -> DocxResult<()> {
+    if algorithm != "AES" || chaining != "ChainingModeCBC" {
+        return Err(DocxError::Encryption(format!(
+            "Unsupported cipher {} with {}",
+            algorithm, chaining
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn encrypt_decrypt() -> DocxResult<()> {
+    let package: Vec<u8> = (0..10000u32).map(|i| (i % 251) as u8).collect();
+
+    let encrypted = encrypt(&package, "password", 10)?;
+
+    assert!(crate::docx::is_encrypted_package(&mut Cursor::new(
+        &encrypted
+    ))?);
+
+    assert_eq!(decrypt(Cursor::new(&encrypted), "password")?, package);
+
+    assert!(matches!(
+        decrypt(Cursor::new(&encrypted), "wrong"),
+        Err(DocxError::InvalidPassword)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//! Endnotes part
+//!
+//! The corresponding ZIP item is `/word/endnotes.xml`.
+
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::__xml_test_suites;
+use crate::document::{BodyContent, ContinuationSeparator, EndnoteRef, Paragraph, Separator};
+use crate::footnotes::{note_content, separator, NoteType};
+use crate::schema::{
+    SCHEMA_DRAWING_MAIN, SCHEMA_MAIN, SCHEMA_MARKUP_COMPATIBILITY,
+    SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS, SCHEMA_PICTURE, SCHEMA_VML,
+    SCHEMA_WORDPROCESSING_DRAWING, SCHEMA_WORDPROCESSING_SHAPE, SCHEMA_WORD_2010, SCHEMA_WORD_2012,
+};
+
+/// Endnotes of the document
+///
+/// The default value contains the separator and continuation separator
+/// notes, which Word requires.
+///
+/// ```rust
+/// use docx::document::Paragraph;
+/// use docx::endnotes::Endnotes;
+///
+/// let mut endnotes = Endnotes::default();
+/// let id = endnotes.push(vec![Paragraph::default().push_text("See the appendix.")]);
+///
+/// assert_eq!(id, 1);
+/// assert!(endnotes.get(id).is_some());
+/// ```
+#[derive(Debug, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:endnotes")]
+pub struct Endnotes<'a> {
+    #[xml(child = "w:endnote")]
+    pub notes: Vec<Endnote<'a>>,
+}
+
+impl Default for Endnotes<'_> {
+    fn default() -> Self {
+        Endnotes {
+            notes: vec![
+                Endnote {
+                    ty: Some(NoteType::Separator),
+                    id: -1,
+                    content: vec![separator(Separator::default())],
+                },
+                Endnote {
+                    ty: Some(NoteType::ContinuationSeparator),
+                    id: 0,
+                    content: vec![separator(ContinuationSeparator::default())],
+                },
+            ],
+        }
+    }
+}
+
+impl<'a> Endnotes<'a> {
+    /// Appends a endnote with the given paragraphs, returns its id
+    ///
+    /// A endnote reference mark is inserted at the start of the first
+    /// paragraph.
+    pub fn push<I: IntoIterator<Item = Paragraph<'a>>>(&mut self, content: I) -> isize {
+        let id = self
+            .notes
+            .iter()
+            .map(|note| note.id)
+            .max()
+            .unwrap_or(0)
+            .max(0)
+            + 1;
+
+        self.notes.push(Endnote {
+            ty: None,
+            id,
+            content: note_content(content, EndnoteRef::default()),
+        });
+
+        id
+    }
+
+    /// Returns the endnote with the given id
+    pub fn get(&self, id: isize) -> Option<&Endnote<'a>> {
+        self.notes.iter().find(|note| note.id == id)
+    }
+}
+
+impl<'a> XmlWrite for Endnotes<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Endnotes { notes } = self;
+
+        log::debug!("[Endnotes] Started writing.");
+
+        writer.write_element_start("w:endnotes")?;
+
+        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+        writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+        writer.write_attribute("xmlns:w14", SCHEMA_WORD_2010)?;
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:wp", SCHEMA_WORDPROCESSING_DRAWING)?;
+        writer.write_attribute("xmlns:a", SCHEMA_DRAWING_MAIN)?;
+        writer.write_attribute("xmlns:pic", SCHEMA_PICTURE)?;
+        writer.write_attribute("xmlns:wps", SCHEMA_WORDPROCESSING_SHAPE)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("xmlns:v", SCHEMA_VML)?;
+        writer.write_attribute("mc:Ignorable", "w14 w15")?;
+
+        if notes.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in notes {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w:endnotes")?;
+        }
+
+        log::debug!("[Endnotes] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Endnote
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:endnote")]
+pub struct Endnote<'a> {
+    /// Specifies whether it's a normal note or a separator.
+    #[xml(attr = "w:type")]
+    pub ty: Option<NoteType>,
+    /// Specifies the id which references refer to.
+    #[xml(attr = "w:id")]
+    pub id: isize,
+    /// Specifies the content of the note.
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
+    pub content: Vec<BodyContent<'a>>,
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v="urn:schemas-microsoft-com:vml" mc:Ignorable="w14 w15""#;
+
+__xml_test_suites!(
+    Endnotes,
+    Endnotes { notes: vec![] },
+    format!(r#"<w:endnotes {}/>"#, NAMESPACES).as_str(),
+    Endnotes::default(),
+    format!(
+        r#"<w:endnotes {}><w:endnote w:type="separator" w:id="-1"><w:p><w:pPr/><w:r><w:rPr/><w:separator/></w:r></w:p></w:endnote><w:endnote w:type="continuationSeparator" w:id="0"><w:p><w:pPr/><w:r><w:rPr/><w:continuationSeparator/></w:r></w:p></w:endnote></w:endnotes>"#,
+        NAMESPACES
+    )
+    .as_str(),
+    {
+        let mut endnotes = Endnotes { notes: vec![] };
+        endnotes.push(vec![Paragraph::default().push_text("foo")]);
+        endnotes
+    },
+    format!(
+        r#"<w:endnotes {}><w:endnote w:id="1"><w:p><w:pPr/><w:r><w:rPr><w:vertAlign w:val="superscript"/></w:rPr><w:endnoteRef/></w:r><w:r><w:rPr/><w:t xml:space="preserve"> </w:t></w:r><w:r><w:rPr/><w:t>foo</w:t></w:r></w:p></w:endnote></w:endnotes>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
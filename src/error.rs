@@ -9,6 +9,24 @@ pub enum DocxError {
     IO(IOError),
     Xml(XmlError),
     Zip(ZipError),
+    /// The file is a password-encrypted package, which is stored in an OLE
+    /// compound file rather than a ZIP archive.
+    ///
+    /// Such files can be read with `DocxFile::from_encrypted_reader`,
+    /// available with the `encryption` feature.
+    Encrypted,
+    /// The password of the encrypted package is incorrect.
+    InvalidPassword,
+    /// The encrypted package is malformed or uses unsupported algorithms.
+    Encryption(String),
+    /// The custom XML part or the XPath of a data binding is malformed or
+    /// unsupported.
+    CustomXml(String),
+    /// The chart has no data or has non-finite values.
+    Chart(String),
+    /// The image is malformed or its format is unsupported, or the part to
+    /// add it to doesn't exist.
+    Image(String),
 }
 
 impl From<IOError> for DocxError {
@@ -29,5 +47,11 @@ impl From<ZipError> for DocxError {
     }
 }
 
+impl From<getrandom::Error> for DocxError {
+    fn from(err: getrandom::Error) -> Self {
+        DocxError::IO(IOError::other(err.to_string()))
+    }
+}
+
 /// Specialized `Result` which the error value is `DocxError`.
 pub type DocxResult<T> = Result<T, DocxError>;
@@ -0,0 +1,102 @@
+//! Footer part
+//!
+//! The corresponding ZIP items are `/word/footer{n}.xml`.
+
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::__xml_test_suites;
+use crate::document::BodyContent;
+#[cfg(test)]
+use crate::document::{Paragraph, Table};
+use crate::schema::{
+    SCHEMA_DRAWING_MAIN, SCHEMA_MAIN, SCHEMA_MARKUP_COMPATIBILITY,
+    SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS, SCHEMA_PICTURE, SCHEMA_VML,
+    SCHEMA_WORDPROCESSING_DRAWING, SCHEMA_WORDPROCESSING_SHAPE, SCHEMA_WORD_2010, SCHEMA_WORD_2012,
+};
+
+/// Footer
+///
+/// It's referred by sections via the id of its relationship, which is
+/// returned by `Docx::add_footer`.
+///
+/// ```rust
+/// use docx::document::Paragraph;
+/// use docx::footer::Footer;
+///
+/// let mut footer = Footer::default();
+/// footer.push(Paragraph::default().push_text("Confidential"));
+/// ```
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:ftr")]
+pub struct Footer<'a> {
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
+    pub content: Vec<BodyContent<'a>>,
+}
+
+impl<'a> Footer<'a> {
+    pub fn push<T: Into<BodyContent<'a>>>(&mut self, content: T) -> &mut Self {
+        self.content.push(content.into());
+        self
+    }
+}
+
+impl<'a> XmlWrite for Footer<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Footer { content } = self;
+
+        log::debug!("[Footer] Started writing.");
+
+        writer.write_element_start("w:ftr")?;
+
+        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+        writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+        writer.write_attribute("xmlns:w14", SCHEMA_WORD_2010)?;
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:wp", SCHEMA_WORDPROCESSING_DRAWING)?;
+        writer.write_attribute("xmlns:a", SCHEMA_DRAWING_MAIN)?;
+        writer.write_attribute("xmlns:pic", SCHEMA_PICTURE)?;
+        writer.write_attribute("xmlns:wps", SCHEMA_WORDPROCESSING_SHAPE)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("xmlns:v", SCHEMA_VML)?;
+        writer.write_attribute("mc:Ignorable", "w14 w15")?;
+
+        if content.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in content {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w:ftr")?;
+        }
+
+        log::debug!("[Footer] Finished writing.");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v="urn:schemas-microsoft-com:vml" mc:Ignorable="w14 w15""#;
+
+__xml_test_suites!(
+    Footer,
+    Footer::default(),
+    format!(r#"<w:ftr {}/>"#, NAMESPACES).as_str(),
+    Footer {
+        content: vec![Paragraph::default().into(), Table::default().into()]
+    },
+    format!(
+        r#"<w:ftr {}><w:p><w:pPr/></w:p><w:tbl><w:tblPr/></w:tbl></w:ftr>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
@@ -0,0 +1,220 @@
+//! Footnotes part
+//!
+//! The corresponding ZIP item is `/word/footnotes.xml`.
+
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::document::{
+    BodyContent, ContinuationSeparator, FootnoteRef, Paragraph, Run, RunContent, Separator,
+    TextSpace,
+};
+use crate::formatting::{CharacterProperty, VerticalAlignVal};
+use crate::schema::{
+    SCHEMA_DRAWING_MAIN, SCHEMA_MAIN, SCHEMA_MARKUP_COMPATIBILITY,
+    SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS, SCHEMA_PICTURE, SCHEMA_VML,
+    SCHEMA_WORDPROCESSING_DRAWING, SCHEMA_WORDPROCESSING_SHAPE, SCHEMA_WORD_2010, SCHEMA_WORD_2012,
+};
+use crate::{__string_enum, __xml_test_suites};
+
+/// Footnotes of the document
+///
+/// The default value contains the separator and continuation separator
+/// notes, which Word requires.
+///
+/// ```rust
+/// use docx::document::Paragraph;
+/// use docx::footnotes::Footnotes;
+///
+/// let mut footnotes = Footnotes::default();
+/// let id = footnotes.push(vec![Paragraph::default().push_text("Ibid.")]);
+///
+/// assert_eq!(id, 1);
+/// assert!(footnotes.get(id).is_some());
+/// ```
+#[derive(Debug, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footnotes")]
+pub struct Footnotes<'a> {
+    #[xml(child = "w:footnote")]
+    pub notes: Vec<Footnote<'a>>,
+}
+
+impl Default for Footnotes<'_> {
+    fn default() -> Self {
+        Footnotes {
+            notes: vec![
+                Footnote {
+                    ty: Some(NoteType::Separator),
+                    id: -1,
+                    content: vec![separator(Separator::default())],
+                },
+                Footnote {
+                    ty: Some(NoteType::ContinuationSeparator),
+                    id: 0,
+                    content: vec![separator(ContinuationSeparator::default())],
+                },
+            ],
+        }
+    }
+}
+
+impl<'a> Footnotes<'a> {
+    /// Appends a footnote with the given paragraphs, returns its id
+    ///
+    /// A footnote reference mark is inserted at the start of the first
+    /// paragraph.
+    pub fn push<I: IntoIterator<Item = Paragraph<'a>>>(&mut self, content: I) -> isize {
+        let id = self
+            .notes
+            .iter()
+            .map(|note| note.id)
+            .max()
+            .unwrap_or(0)
+            .max(0)
+            + 1;
+
+        self.notes.push(Footnote {
+            ty: None,
+            id,
+            content: note_content(content, FootnoteRef::default()),
+        });
+
+        id
+    }
+
+    /// Returns the footnote with the given id
+    pub fn get(&self, id: isize) -> Option<&Footnote<'a>> {
+        self.notes.iter().find(|note| note.id == id)
+    }
+}
+
+impl<'a> XmlWrite for Footnotes<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Footnotes { notes } = self;
+
+        log::debug!("[Footnotes] Started writing.");
+
+        writer.write_element_start("w:footnotes")?;
+
+        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+        writer.write_attribute("xmlns:r", SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS)?;
+        writer.write_attribute("xmlns:w14", SCHEMA_WORD_2010)?;
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:wp", SCHEMA_WORDPROCESSING_DRAWING)?;
+        writer.write_attribute("xmlns:a", SCHEMA_DRAWING_MAIN)?;
+        writer.write_attribute("xmlns:pic", SCHEMA_PICTURE)?;
+        writer.write_attribute("xmlns:wps", SCHEMA_WORDPROCESSING_SHAPE)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("xmlns:v", SCHEMA_VML)?;
+        writer.write_attribute("mc:Ignorable", "w14 w15")?;
+
+        if notes.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in notes {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w:footnotes")?;
+        }
+
+        log::debug!("[Footnotes] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Footnote
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footnote")]
+pub struct Footnote<'a> {
+    /// Specifies whether it's a normal note or a separator.
+    #[xml(attr = "w:type")]
+    pub ty: Option<NoteType>,
+    /// Specifies the id which references refer to.
+    #[xml(attr = "w:id")]
+    pub id: isize,
+    /// Specifies the content of the note.
+    #[xml(
+        child = "w:p",
+        child = "w:tbl",
+        child = "w:sdt",
+        child = "w:permStart",
+        child = "w:permEnd"
+    )]
+    pub content: Vec<BodyContent<'a>>,
+}
+
+/// Type of footnotes and endnotes
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum NoteType {
+    Normal,
+    Separator,
+    ContinuationSeparator,
+    ContinuationNotice,
+}
+
+__string_enum! {
+    NoteType {
+        Normal = "normal",
+        Separator = "separator",
+        ContinuationSeparator = "continuationSeparator",
+        ContinuationNotice = "continuationNotice",
+    }
+}
+
+/// Returns the content of a separator note
+pub(crate) fn separator<'a, T: Into<RunContent<'a>>>(mark: T) -> BodyContent<'a> {
+    Paragraph::default().push(Run::default().push(mark)).into()
+}
+
+/// Returns the content of a note, with the reference mark inserted
+pub(crate) fn note_content<'a, I, T>(content: I, mark: T) -> Vec<BodyContent<'a>>
+where
+    I: IntoIterator<Item = Paragraph<'a>>,
+    T: Into<RunContent<'a>>,
+{
+    let mut paragraphs: Vec<Paragraph<'a>> = content.into_iter().collect();
+
+    if paragraphs.is_empty() {
+        paragraphs.push(Paragraph::default());
+    }
+
+    let mark = Run::default()
+        .property(CharacterProperty::default().vertical_align(VerticalAlignVal::Superscript))
+        .push(mark);
+    let space = Run::default().push_text((" ", TextSpace::Preserve));
+    paragraphs[0]
+        .content
+        .splice(0..0, vec![mark.into(), space.into()]);
+
+    paragraphs.into_iter().map(Into::into).collect()
+}
+
+#[cfg(test)]
+const NAMESPACES: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v="urn:schemas-microsoft-com:vml" mc:Ignorable="w14 w15""#;
+
+__xml_test_suites!(
+    Footnotes,
+    Footnotes { notes: vec![] },
+    format!(r#"<w:footnotes {}/>"#, NAMESPACES).as_str(),
+    Footnotes::default(),
+    format!(
+        r#"<w:footnotes {}><w:footnote w:type="separator" w:id="-1"><w:p><w:pPr/><w:r><w:rPr/><w:separator/></w:r></w:p></w:footnote><w:footnote w:type="continuationSeparator" w:id="0"><w:p><w:pPr/><w:r><w:rPr/><w:continuationSeparator/></w:r></w:p></w:footnote></w:footnotes>"#,
+        NAMESPACES
+    )
+    .as_str(),
+    {
+        let mut footnotes = Footnotes { notes: vec![] };
+        footnotes.push(vec![Paragraph::default().push_text("foo")]);
+        footnotes
+    },
+    format!(
+        r#"<w:footnotes {}><w:footnote w:id="1"><w:p><w:pPr/><w:r><w:rPr><w:vertAlign w:val="superscript"/></w:rPr><w:footnoteRef/></w:r><w:r><w:rPr/><w:t xml:space="preserve"> </w:t></w:r><w:r><w:rPr/><w:t>foo</w:t></w:r></w:p></w:footnote></w:footnotes>"#,
+        NAMESPACES
+    )
+    .as_str(),
+);
@@ -3,7 +3,10 @@ use strong_xml::{XmlRead, XmlWrite};
 
 use crate::{
     __setter, __xml_test_suites,
-    formatting::{Bold, Color, Dstrike, Italics, Outline, Size, Strike, Underline},
+    formatting::{
+        Bold, CharacterPropertyChange, Color, Dstrike, Italics, Outline, Size, Strike, Underline,
+        VerticalAlign,
+    },
 };
 
 /// Character Property
@@ -25,7 +28,7 @@ use crate::{
 ///     .underline("00ff00")
 ///     .underline(("ff0000", UnderlineStyle::Dash));
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[derive(Debug, Clone, Default, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:rPr")]
 pub struct CharacterProperty<'a> {
@@ -56,6 +59,12 @@ pub struct CharacterProperty<'a> {
     /// Specifies that the content should be displayed with an underline
     #[xml(child = "w:u")]
     pub underline: Option<Underline<'a>>,
+    /// Specifies that the content should be displayed as superscript or subscript
+    #[xml(child = "w:vertAlign")]
+    pub vertical_align: Option<VerticalAlign>,
+    /// Specifies the tracked change of the run properties.
+    #[xml(child = "w:rPrChange")]
+    pub change: Option<CharacterPropertyChange<'a>>,
 }
 
 impl<'a> CharacterProperty<'a> {
@@ -68,9 +77,11 @@ impl<'a> CharacterProperty<'a> {
     __setter!(strike: Option<Strike>);
     __setter!(size: Option<Size>);
     __setter!(underline: Option<Underline<'a>>);
+    __setter!(vertical_align: Option<VerticalAlign>);
+    __setter!(change: Option<CharacterPropertyChange<'a>>);
 }
 
-#[derive(Debug, XmlRead, XmlWrite)]
+#[derive(Debug, Clone, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:rStyle")]
 pub struct CharacterStyleId<'a> {
@@ -84,6 +95,9 @@ impl<'a, T: Into<Cow<'a, str>>> From<T> for CharacterStyleId<'a> {
     }
 }
 
+#[cfg(test)]
+use crate::formatting::VerticalAlignVal;
+
 __xml_test_suites!(
     CharacterProperty,
     CharacterProperty::default(),
@@ -106,4 +120,14 @@ __xml_test_suites!(
     r#"<w:rPr><w:dstrike w:val="true"/></w:rPr>"#,
     CharacterProperty::default().underline(Underline::default()),
     r#"<w:rPr><w:u/></w:rPr>"#,
+    CharacterProperty::default().vertical_align(VerticalAlignVal::Superscript),
+    r#"<w:rPr><w:vertAlign w:val="superscript"/></w:rPr>"#,
+    CharacterProperty::default()
+        .bold(true)
+        .change(CharacterPropertyChange::new(
+            0,
+            "foo",
+            CharacterProperty::default()
+        )),
+    r#"<w:rPr><w:b w:val="true"/><w:rPrChange w:id="0" w:author="foo"><w:rPr/></w:rPrChange></w:rPr>"#,
 );
@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+
+use crate::{__setter, __xml_test_suites, formatting::CharacterProperty, DateTime};
+
+/// Revision Information for Run Properties
+///
+/// Records a tracked change of the run properties, with the properties
+/// before the change.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let change = CharacterPropertyChange::new(1, "Reviewer", CharacterProperty::default());
+/// let prop = CharacterProperty::default().bold(true).change(change);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct CharacterPropertyChange<'a> {
+    /// Specifies the id of the revision.
+    pub id: usize,
+    /// Specifies the author of the revision.
+    pub author: Cow<'a, str>,
+    /// Specifies when the revision was made.
+    pub date: Option<DateTime>,
+    /// Specifies the run properties before the revision.
+    pub property: Box<CharacterProperty<'a>>,
+}
+
+impl<'a> CharacterPropertyChange<'a> {
+    __setter!(date: Option<DateTime>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(
+        id: usize,
+        author: T,
+        property: CharacterProperty<'a>,
+    ) -> Self {
+        CharacterPropertyChange {
+            id,
+            author: author.into(),
+            date: None,
+            property: Box::new(property),
+        }
+    }
+}
+
+impl<'i: 'a, 'a> XmlRead<'i> for CharacterPropertyChange<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let RawCharacterPropertyChange {
+            id,
+            author,
+            date,
+            mut property,
+        } = RawCharacterPropertyChange::from_reader(reader)?;
+
+        Ok(CharacterPropertyChange {
+            id,
+            author,
+            date,
+            property: Box::new(property.pop().unwrap_or_default()),
+        })
+    }
+}
+
+impl<'a> XmlWrite for CharacterPropertyChange<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let CharacterPropertyChange {
+            id,
+            author,
+            date,
+            property,
+        } = self;
+
+        writer.write_element_start("w:rPrChange")?;
+        writer.write_attribute("w:id", &id.to_string())?;
+        writer.write_attribute("w:author", author)?;
+        if let Some(date) = date {
+            writer.write_attribute("w:date", &date.to_string())?;
+        }
+        writer.write_element_end_open()?;
+        property.to_writer(writer)?;
+        writer.write_element_end_close("w:rPrChange")?;
+
+        Ok(())
+    }
+}
+
+// the properties are stored in a vector, since a recursive type
+// can't be read directly
+#[derive(XmlRead)]
+#[xml(tag = "w:rPrChange")]
+struct RawCharacterPropertyChange<'a> {
+    #[xml(attr = "w:id")]
+    id: usize,
+    #[xml(attr = "w:author")]
+    author: Cow<'a, str>,
+    #[xml(attr = "w:date")]
+    date: Option<DateTime>,
+    #[xml(child = "w:rPr")]
+    property: Vec<CharacterProperty<'a>>,
+}
+
+__xml_test_suites!(
+    CharacterPropertyChange,
+    CharacterPropertyChange::new(1, "foo", CharacterProperty::default()),
+    r#"<w:rPrChange w:id="1" w:author="foo"><w:rPr/></w:rPrChange>"#,
+    CharacterPropertyChange::new(2, "bar", CharacterProperty::default().bold(true))
+        .date("2020-04-01T00:00:00Z".parse::<DateTime>().unwrap()),
+    r#"<w:rPrChange w:id="2" w:author="bar" w:date="2020-04-01T00:00:00Z"><w:rPr><w:b w:val="true"/></w:rPr></w:rPrChange>"#,
+);
@@ -0,0 +1,102 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+/// Column Definitions
+///
+/// Distances are measured in twentieths of a point.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// // two columns of equal width
+/// let cols = Columns::from(2usize).space(720usize).separator(true);
+///
+/// // two columns of different widths
+/// let cols = Columns::default()
+///     .equal_width(false)
+///     .push(Column::from(6000).space(720usize))
+///     .push(Column::from(3000));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:cols")]
+pub struct Columns {
+    /// Specifies whether columns are of equal width.
+    #[xml(attr = "w:equalWidth")]
+    pub equal_width: Option<bool>,
+    /// Specifies the spacing between columns of equal width.
+    #[xml(attr = "w:space")]
+    pub space: Option<usize>,
+    /// Specifies the number of columns of equal width.
+    #[xml(attr = "w:num")]
+    pub count: Option<usize>,
+    /// Specifies whether a line is drawn between columns.
+    #[xml(attr = "w:sep")]
+    pub separator: Option<bool>,
+    /// Specifies each column if they're not of equal width.
+    #[xml(child = "w:col")]
+    pub columns: Vec<Column>,
+}
+
+impl Columns {
+    __setter!(equal_width: Option<bool>);
+    __setter!(space: Option<usize>);
+    __setter!(count: Option<usize>);
+    __setter!(separator: Option<bool>);
+
+    #[inline(always)]
+    pub fn push<T: Into<Column>>(mut self, column: T) -> Self {
+        self.columns.push(column.into());
+        self
+    }
+}
+
+impl From<usize> for Columns {
+    fn from(val: usize) -> Self {
+        Columns {
+            count: Some(val),
+            ..Default::default()
+        }
+    }
+}
+
+/// Single Column Definition
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:col")]
+pub struct Column {
+    /// Specifies the width of the column.
+    #[xml(attr = "w:w")]
+    pub width: Option<usize>,
+    /// Specifies the spacing after the column.
+    #[xml(attr = "w:space")]
+    pub space: Option<usize>,
+}
+
+impl Column {
+    __setter!(width: Option<usize>);
+    __setter!(space: Option<usize>);
+}
+
+impl From<usize> for Column {
+    fn from(val: usize) -> Self {
+        Column {
+            width: Some(val),
+            space: None,
+        }
+    }
+}
+
+__xml_test_suites!(
+    Columns,
+    Columns::default(),
+    r#"<w:cols/>"#,
+    Columns::from(2usize).space(720usize).separator(true),
+    r#"<w:cols w:space="720" w:num="2" w:sep="true"/>"#,
+    Columns::default()
+        .equal_width(false)
+        .push(Column::from(6000).space(720usize))
+        .push(Column::from(3000)),
+    r#"<w:cols w:equalWidth="false"><w:col w:w="6000" w:space="720"/><w:col w:w="3000"/></w:cols>"#,
+);
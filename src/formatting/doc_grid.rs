@@ -0,0 +1,59 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Document Grid
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let grid = DocGrid::default().ty(DocGridType::Lines).line_pitch(360usize);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:docGrid")]
+pub struct DocGrid {
+    /// Specifies the type of the grid.
+    #[xml(attr = "w:type")]
+    pub ty: Option<DocGridType>,
+    /// Specifies the pitch of lines, in twentieths of a point.
+    #[xml(attr = "w:linePitch")]
+    pub line_pitch: Option<usize>,
+    /// Specifies the additional space of characters.
+    #[xml(attr = "w:charSpace")]
+    pub char_space: Option<isize>,
+}
+
+impl DocGrid {
+    __setter!(ty: Option<DocGridType>);
+    __setter!(line_pitch: Option<usize>);
+    __setter!(char_space: Option<isize>);
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum DocGridType {
+    Default,
+    Lines,
+    LinesAndChars,
+    SnapToChars,
+}
+
+__string_enum! {
+    DocGridType {
+        Default = "default",
+        Lines = "lines",
+        LinesAndChars = "linesAndChars",
+        SnapToChars = "snapToChars",
+    }
+}
+
+__xml_test_suites!(
+    DocGrid,
+    DocGrid::default(),
+    r#"<w:docGrid/>"#,
+    DocGrid::default()
+        .ty(DocGridType::Lines)
+        .line_pitch(360usize),
+    r#"<w:docGrid w:type="lines" w:linePitch="360"/>"#,
+);
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__xml_test_suites, formatting::HeaderFooterType};
+
+/// Footer Reference
+///
+/// Refers to a footer part by the id of its relationship from the main
+/// document part.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let footer = FooterReference::new(HeaderFooterType::Even, "rId5");
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footerReference")]
+pub struct FooterReference<'a> {
+    /// Specifies which pages the footer is used for.
+    #[xml(attr = "w:type")]
+    pub ty: HeaderFooterType,
+    /// Specifies the relationship id of the footer part.
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> FooterReference<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(ty: HeaderFooterType, id: T) -> Self {
+        FooterReference { ty, id: id.into() }
+    }
+}
+
+__xml_test_suites!(
+    FooterReference,
+    FooterReference::new(HeaderFooterType::Even, "rId1"),
+    r#"<w:footerReference w:type="even" r:id="rId1"/>"#,
+);
@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__string_enum, __xml_test_suites};
+
+/// Header Reference
+///
+/// Refers to a header part by the id of its relationship from the main
+/// document part.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let header = HeaderReference::new(HeaderFooterType::Default, "rId4");
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:headerReference")]
+pub struct HeaderReference<'a> {
+    /// Specifies which pages the header is used for.
+    #[xml(attr = "w:type")]
+    pub ty: HeaderFooterType,
+    /// Specifies the relationship id of the header part.
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> HeaderReference<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(ty: HeaderFooterType, id: T) -> Self {
+        HeaderReference { ty, id: id.into() }
+    }
+}
+
+/// Pages which a header or footer is used for
+///
+/// The first page header and footer are only used if the section has a
+/// title page, while the even page ones are only used if
+/// `Settings::even_and_odd_headers` is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HeaderFooterType {
+    /// Used for all pages, or odd pages if even pages use their own.
+    Default,
+    /// Used for the first page of the section.
+    First,
+    /// Used for even pages.
+    Even,
+}
+
+__string_enum! {
+    HeaderFooterType {
+        Default = "default",
+        First = "first",
+        Even = "even",
+    }
+}
+
+__xml_test_suites!(
+    HeaderReference,
+    HeaderReference::new(HeaderFooterType::Default, "rId1"),
+    r#"<w:headerReference w:type="default" r:id="rId1"/>"#,
+    HeaderReference::new(HeaderFooterType::First, "rId2"),
+    r#"<w:headerReference w:type="first" r:id="rId2"/>"#,
+);
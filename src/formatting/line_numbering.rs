@@ -0,0 +1,64 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Line Numbering Settings
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let num = LineNumbering::default()
+///     .count_by(5usize)
+///     .restart(LineNumberRestart::NewPage);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:lnNumType")]
+pub struct LineNumbering {
+    /// Specifies the increment between displayed line numbers.
+    #[xml(attr = "w:countBy")]
+    pub count_by: Option<usize>,
+    /// Specifies the starting line number.
+    #[xml(attr = "w:start")]
+    pub start: Option<usize>,
+    /// Specifies the distance between text and line numbers, in twentieths of a point.
+    #[xml(attr = "w:distance")]
+    pub distance: Option<usize>,
+    /// Specifies when line numbers restart.
+    #[xml(attr = "w:restart")]
+    pub restart: Option<LineNumberRestart>,
+}
+
+impl LineNumbering {
+    __setter!(count_by: Option<usize>);
+    __setter!(start: Option<usize>);
+    __setter!(distance: Option<usize>);
+    __setter!(restart: Option<LineNumberRestart>);
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum LineNumberRestart {
+    NewPage,
+    NewSection,
+    Continuous,
+}
+
+__string_enum! {
+    LineNumberRestart {
+        NewPage = "newPage",
+        NewSection = "newSection",
+        Continuous = "continuous",
+    }
+}
+
+__xml_test_suites!(
+    LineNumbering,
+    LineNumbering::default(),
+    r#"<w:lnNumType/>"#,
+    LineNumbering::default()
+        .count_by(5usize)
+        .distance(360usize)
+        .restart(LineNumberRestart::NewPage),
+    r#"<w:lnNumType w:countBy="5" w:distance="360" w:restart="newPage"/>"#,
+);
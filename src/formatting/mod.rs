@@ -7,15 +7,29 @@ mod bold;
 mod border;
 mod borders;
 mod character_property;
+mod character_property_change;
 mod color;
+mod columns;
+mod doc_grid;
 mod dstrike;
+mod footer_reference;
+mod header_reference;
 mod indent_level;
 mod italics;
 mod justification;
+mod line_numbering;
+mod note_property;
 mod numbering_id;
 mod numbering_property;
 mod outline;
+mod page_margin;
+mod page_number_type;
+mod page_size;
+mod paragraph_mark_property;
 mod paragraph_property;
+mod paragraph_property_change;
+mod section_property;
+mod section_type;
 mod size;
 mod strike;
 mod table_borders;
@@ -25,13 +39,20 @@ mod table_justification;
 mod table_property;
 mod table_row_property;
 mod table_width;
+mod title_page;
 mod underline;
+mod vertical_align;
+mod vertical_justification;
 
 // re-export
 pub use self::{
-    bold::*, border::*, borders::*, character_property::*, color::*, dstrike::*, indent_level::*,
-    italics::*, justification::*, numbering_id::*, numbering_property::*, outline::*,
-    paragraph_property::*, size::*, strike::*, table_borders::*, table_cell_property::*,
-    table_indent::*, table_justification::*, table_property::*, table_row_property::*,
-    table_width::*, underline::*,
+    bold::*, border::*, borders::*, character_property::*, character_property_change::*, color::*,
+    columns::*, doc_grid::*, dstrike::*, footer_reference::*, header_reference::*, indent_level::*,
+    italics::*, justification::*, line_numbering::*, note_property::*, numbering_id::*,
+    numbering_property::*, outline::*, page_margin::*, page_number_type::*, page_size::*,
+    paragraph_mark_property::*, paragraph_property::*, paragraph_property_change::*,
+    section_property::*, section_type::*, size::*, strike::*, table_borders::*,
+    table_cell_property::*, table_indent::*, table_justification::*, table_property::*,
+    table_row_property::*, table_width::*, title_page::*, underline::*, vertical_align::*,
+    vertical_justification::*,
 };
@@ -0,0 +1,252 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites, formatting::NumberFormat};
+
+/// Footnote Properties
+///
+/// Used in both the settings part, which applies to the whole document, and
+/// section properties.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let prop = FootnoteProperty::default()
+///     .format(NumberFormat::LowerRoman)
+///     .restart(NoteNumberRestartVal::EachPage);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footnotePr")]
+pub struct FootnoteProperty {
+    /// Specifies where footnotes are placed.
+    #[xml(child = "w:pos")]
+    pub position: Option<FootnotePosition>,
+    /// Specifies the number format of footnotes.
+    #[xml(child = "w:numFmt")]
+    pub format: Option<NoteNumberFormat>,
+    /// Specifies the number of the first footnote.
+    #[xml(child = "w:numStart")]
+    pub start: Option<NoteNumberStart>,
+    /// Specifies when footnote numbers restart.
+    #[xml(child = "w:numRestart")]
+    pub restart: Option<NoteNumberRestart>,
+    /// Specifies the ids of separator notes, only used in the settings part.
+    #[xml(child = "w:footnote")]
+    pub separators: Vec<FootnoteSeparator>,
+}
+
+impl FootnoteProperty {
+    __setter!(position: Option<FootnotePosition>);
+    __setter!(format: Option<NoteNumberFormat>);
+    __setter!(start: Option<NoteNumberStart>);
+    __setter!(restart: Option<NoteNumberRestart>);
+}
+
+/// Endnote Properties
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let prop = EndnoteProperty::default()
+///     .position(EndnotePositionVal::SectionEnd)
+///     .format(NumberFormat::Decimal);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:endnotePr")]
+pub struct EndnoteProperty {
+    /// Specifies where endnotes are placed.
+    #[xml(child = "w:pos")]
+    pub position: Option<EndnotePosition>,
+    /// Specifies the number format of endnotes.
+    #[xml(child = "w:numFmt")]
+    pub format: Option<NoteNumberFormat>,
+    /// Specifies the number of the first endnote.
+    #[xml(child = "w:numStart")]
+    pub start: Option<NoteNumberStart>,
+    /// Specifies when endnote numbers restart.
+    #[xml(child = "w:numRestart")]
+    pub restart: Option<NoteNumberRestart>,
+    /// Specifies the ids of separator notes, only used in the settings part.
+    #[xml(child = "w:endnote")]
+    pub separators: Vec<EndnoteSeparator>,
+}
+
+impl EndnoteProperty {
+    __setter!(position: Option<EndnotePosition>);
+    __setter!(format: Option<NoteNumberFormat>);
+    __setter!(start: Option<NoteNumberStart>);
+    __setter!(restart: Option<NoteNumberRestart>);
+}
+
+/// Footnote Placement
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pos")]
+pub struct FootnotePosition {
+    #[xml(attr = "w:val")]
+    pub value: FootnotePositionVal,
+}
+
+impl From<FootnotePositionVal> for FootnotePosition {
+    fn from(value: FootnotePositionVal) -> Self {
+        FootnotePosition { value }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum FootnotePositionVal {
+    /// At the bottom of the page.
+    PageBottom,
+    /// Immediately after the text on the page.
+    BeneathText,
+}
+
+__string_enum! {
+    FootnotePositionVal {
+        PageBottom = "pageBottom",
+        BeneathText = "beneathText",
+    }
+}
+
+/// Endnote Placement
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pos")]
+pub struct EndnotePosition {
+    #[xml(attr = "w:val")]
+    pub value: EndnotePositionVal,
+}
+
+impl From<EndnotePositionVal> for EndnotePosition {
+    fn from(value: EndnotePositionVal) -> Self {
+        EndnotePosition { value }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum EndnotePositionVal {
+    /// At the end of each section.
+    SectionEnd,
+    /// At the end of the document.
+    DocumentEnd,
+}
+
+__string_enum! {
+    EndnotePositionVal {
+        SectionEnd = "sectEnd",
+        DocumentEnd = "docEnd",
+    }
+}
+
+/// Note Numbering Format
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:numFmt")]
+pub struct NoteNumberFormat {
+    #[xml(attr = "w:val")]
+    pub value: NumberFormat,
+}
+
+impl From<NumberFormat> for NoteNumberFormat {
+    fn from(value: NumberFormat) -> Self {
+        NoteNumberFormat { value }
+    }
+}
+
+/// Note Numbering Starting Value
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:numStart")]
+pub struct NoteNumberStart {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl From<usize> for NoteNumberStart {
+    fn from(value: usize) -> Self {
+        NoteNumberStart { value }
+    }
+}
+
+/// Note Numbering Restart Location
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:numRestart")]
+pub struct NoteNumberRestart {
+    #[xml(attr = "w:val")]
+    pub value: NoteNumberRestartVal,
+}
+
+impl From<NoteNumberRestartVal> for NoteNumberRestart {
+    fn from(value: NoteNumberRestartVal) -> Self {
+        NoteNumberRestart { value }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum NoteNumberRestartVal {
+    Continuous,
+    EachSection,
+    EachPage,
+}
+
+__string_enum! {
+    NoteNumberRestartVal {
+        Continuous = "continuous",
+        EachSection = "eachSect",
+        EachPage = "eachPage",
+    }
+}
+
+/// Footnote Separator Reference
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:footnote")]
+pub struct FootnoteSeparator {
+    #[xml(attr = "w:id")]
+    pub id: isize,
+}
+
+/// Endnote Separator Reference
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:endnote")]
+pub struct EndnoteSeparator {
+    #[xml(attr = "w:id")]
+    pub id: isize,
+}
+
+__xml_test_suites!(
+    FootnoteProperty,
+    FootnoteProperty::default(),
+    r#"<w:footnotePr/>"#,
+    FootnoteProperty::default()
+        .position(FootnotePositionVal::BeneathText)
+        .format(NumberFormat::LowerRoman)
+        .start(3usize)
+        .restart(NoteNumberRestartVal::EachPage),
+    r#"<w:footnotePr><w:pos w:val="beneathText"/><w:numFmt w:val="lowerRoman"/><w:numStart w:val="3"/><w:numRestart w:val="eachPage"/></w:footnotePr>"#,
+    FootnoteProperty {
+        separators: vec![FootnoteSeparator { id: -1 }, FootnoteSeparator { id: 0 }],
+        ..Default::default()
+    },
+    r#"<w:footnotePr><w:footnote w:id="-1"/><w:footnote w:id="0"/></w:footnotePr>"#,
+);
+
+#[test]
+fn endnote_property() -> strong_xml::XmlResult<()> {
+    let prop = EndnoteProperty::default()
+        .position(EndnotePositionVal::SectionEnd)
+        .restart(NoteNumberRestartVal::EachSection);
+    let xml =
+        r#"<w:endnotePr><w:pos w:val="sectEnd"/><w:numRestart w:val="eachSect"/></w:endnotePr>"#;
+
+    assert_eq!(prop.to_string()?, xml);
+    assert_eq!(EndnoteProperty::from_str(xml)?, prop);
+
+    Ok(())
+}
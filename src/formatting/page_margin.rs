@@ -0,0 +1,76 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+/// Page Margins
+///
+/// All distances are measured in twentieths of a point. Negative top or
+/// bottom margins allow text to overlap headers and footers.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let margin = PageMargin::from(1440)
+///     .header(720usize)
+///     .footer(720usize)
+///     .gutter(360usize);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pgMar")]
+pub struct PageMargin {
+    /// Specifies the distance between the top of text and the top edge.
+    #[xml(default, attr = "w:top")]
+    pub top: isize,
+    /// Specifies the distance between the right of text and the right edge.
+    #[xml(default, attr = "w:right")]
+    pub right: usize,
+    /// Specifies the distance between the bottom of text and the bottom edge.
+    #[xml(default, attr = "w:bottom")]
+    pub bottom: isize,
+    /// Specifies the distance between the left of text and the left edge.
+    #[xml(default, attr = "w:left")]
+    pub left: usize,
+    /// Specifies the distance between the top of header and the top edge.
+    #[xml(default, attr = "w:header")]
+    pub header: usize,
+    /// Specifies the distance between the bottom of footer and the bottom edge.
+    #[xml(default, attr = "w:footer")]
+    pub footer: usize,
+    /// Specifies the extra space added to margins for binding.
+    #[xml(default, attr = "w:gutter")]
+    pub gutter: usize,
+}
+
+impl PageMargin {
+    __setter!(top: isize);
+    __setter!(right: usize);
+    __setter!(bottom: isize);
+    __setter!(left: usize);
+    __setter!(header: usize);
+    __setter!(footer: usize);
+    __setter!(gutter: usize);
+}
+
+/// Uses the same distance for all four margins
+impl From<usize> for PageMargin {
+    fn from(val: usize) -> Self {
+        PageMargin {
+            top: val as isize,
+            right: val,
+            bottom: val as isize,
+            left: val,
+            ..Default::default()
+        }
+    }
+}
+
+__xml_test_suites!(
+    PageMargin,
+    PageMargin::default(),
+    r#"<w:pgMar w:top="0" w:right="0" w:bottom="0" w:left="0" w:header="0" w:footer="0" w:gutter="0"/>"#,
+    PageMargin::from(1440).header(720usize).footer(720usize),
+    r#"<w:pgMar w:top="1440" w:right="1440" w:bottom="1440" w:left="1440" w:header="720" w:footer="720" w:gutter="0"/>"#,
+    PageMargin::default().top(-20isize),
+    r#"<w:pgMar w:top="-20" w:right="0" w:bottom="0" w:left="0" w:header="0" w:footer="0" w:gutter="0"/>"#,
+);
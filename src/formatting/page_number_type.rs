@@ -0,0 +1,80 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Page Numbering Settings
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let num = PageNumberType::default()
+///     .format(NumberFormat::LowerRoman)
+///     .start(1usize);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pgNumType")]
+pub struct PageNumberType {
+    /// Specifies the number format of page numbers.
+    #[xml(attr = "w:fmt")]
+    pub format: Option<NumberFormat>,
+    /// Specifies the page number of the first page in the section.
+    #[xml(attr = "w:start")]
+    pub start: Option<usize>,
+}
+
+impl PageNumberType {
+    __setter!(format: Option<NumberFormat>);
+    __setter!(start: Option<usize>);
+}
+
+/// Numbering Format
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum NumberFormat {
+    Decimal,
+    UpperRoman,
+    LowerRoman,
+    UpperLetter,
+    LowerLetter,
+    Ordinal,
+    CardinalText,
+    OrdinalText,
+    DecimalZero,
+    DecimalEnclosedCircle,
+    DecimalFullWidth,
+    NumberInDash,
+    Chicago,
+    Bullet,
+    None,
+}
+
+__string_enum! {
+    NumberFormat {
+        Decimal = "decimal",
+        UpperRoman = "upperRoman",
+        LowerRoman = "lowerRoman",
+        UpperLetter = "upperLetter",
+        LowerLetter = "lowerLetter",
+        Ordinal = "ordinal",
+        CardinalText = "cardinalText",
+        OrdinalText = "ordinalText",
+        DecimalZero = "decimalZero",
+        DecimalEnclosedCircle = "decimalEnclosedCircle",
+        DecimalFullWidth = "decimalFullWidth",
+        NumberInDash = "numberInDash",
+        Chicago = "chicago",
+        Bullet = "bullet",
+        None = "none",
+    }
+}
+
+__xml_test_suites!(
+    PageNumberType,
+    PageNumberType::default(),
+    r#"<w:pgNumType/>"#,
+    PageNumberType::default()
+        .format(NumberFormat::LowerRoman)
+        .start(1usize),
+    r#"<w:pgNumType w:fmt="lowerRoman" w:start="1"/>"#,
+);
@@ -0,0 +1,103 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Page Size
+///
+/// Both width and height are measured in twentieths of a point.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let size = PageSize::a4();
+/// let size = PageSize::letter().landscape();
+/// let size = PageSize::from((12240, 15840));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pgSz")]
+pub struct PageSize {
+    /// Specifies the width of pages.
+    #[xml(attr = "w:w")]
+    pub width: Option<usize>,
+    /// Specifies the height of pages.
+    #[xml(attr = "w:h")]
+    pub height: Option<usize>,
+    /// Specifies the orientation of pages.
+    #[xml(attr = "w:orient")]
+    pub orientation: Option<PageOrientation>,
+    /// Specifies the paper code of printer.
+    #[xml(attr = "w:code")]
+    pub code: Option<usize>,
+}
+
+impl PageSize {
+    __setter!(width: Option<usize>);
+    __setter!(height: Option<usize>);
+    __setter!(orientation: Option<PageOrientation>);
+    __setter!(code: Option<usize>);
+
+    /// ISO A4 paper, 210mm x 297mm
+    pub fn a4() -> Self {
+        PageSize::from((11906, 16838))
+    }
+
+    /// US Letter paper, 8.5in x 11in
+    pub fn letter() -> Self {
+        PageSize::from((12240, 15840))
+    }
+
+    /// Turns pages into landscape, swapping the width and height if needed
+    pub fn landscape(mut self) -> Self {
+        if self.width < self.height {
+            std::mem::swap(&mut self.width, &mut self.height);
+        }
+        self.orientation = Some(PageOrientation::Landscape);
+        self
+    }
+
+    /// Turns pages into portrait, swapping the width and height if needed
+    pub fn portrait(mut self) -> Self {
+        if self.width > self.height {
+            std::mem::swap(&mut self.width, &mut self.height);
+        }
+        self.orientation = Some(PageOrientation::Portrait);
+        self
+    }
+}
+
+impl From<(usize, usize)> for PageSize {
+    fn from(val: (usize, usize)) -> Self {
+        PageSize {
+            width: Some(val.0),
+            height: Some(val.1),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum PageOrientation {
+    Portrait,
+    Landscape,
+}
+
+__string_enum! {
+    PageOrientation {
+        Portrait = "portrait",
+        Landscape = "landscape",
+    }
+}
+
+__xml_test_suites!(
+    PageSize,
+    PageSize::default(),
+    r#"<w:pgSz/>"#,
+    PageSize::a4(),
+    r#"<w:pgSz w:w="11906" w:h="16838"/>"#,
+    PageSize::a4().landscape(),
+    r#"<w:pgSz w:w="16838" w:h="11906" w:orient="landscape"/>"#,
+    PageSize::a4().code(9usize),
+    r#"<w:pgSz w:w="11906" w:h="16838" w:code="9"/>"#,
+);
@@ -0,0 +1,44 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    document::{Deletion, Insertion},
+};
+
+/// Paragraph Mark Property
+///
+/// Specifies the tracked changes of the paragraph mark. When the mark is
+/// removed, the paragraph is merged with the following one.
+///
+/// ```rust
+/// use docx::document::Insertion;
+/// use docx::formatting::ParagraphMarkProperty;
+///
+/// let prop = ParagraphMarkProperty::default().insertion(Insertion::new(0, "Reviewer"));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:rPr")]
+pub struct ParagraphMarkProperty<'a> {
+    /// Specifies that the paragraph mark is inserted.
+    #[xml(child = "w:ins")]
+    pub insertion: Option<Insertion<'a>>,
+    /// Specifies that the paragraph mark is deleted.
+    #[xml(child = "w:del")]
+    pub deletion: Option<Deletion<'a>>,
+}
+
+impl<'a> ParagraphMarkProperty<'a> {
+    __setter!(insertion: Option<Insertion<'a>>);
+    __setter!(deletion: Option<Deletion<'a>>);
+}
+
+__xml_test_suites!(
+    ParagraphMarkProperty,
+    ParagraphMarkProperty::default(),
+    r#"<w:rPr/>"#,
+    ParagraphMarkProperty::default().insertion(Insertion::new(0, "foo")),
+    r#"<w:rPr><w:ins w:id="0" w:author="foo"/></w:rPr>"#,
+    ParagraphMarkProperty::default().deletion(Deletion::new(1, "bar")),
+    r#"<w:rPr><w:del w:id="1" w:author="bar"/></w:rPr>"#,
+);
@@ -3,7 +3,10 @@ use strong_xml::{XmlRead, XmlWrite};
 
 use crate::{
     __setter, __xml_test_suites,
-    formatting::{Borders, Justification, NumberingProperty},
+    formatting::{
+        Borders, Justification, NumberingProperty, ParagraphMarkProperty, ParagraphPropertyChange,
+        SectionProperty,
+    },
 };
 
 /// Paragraph Property
@@ -32,6 +35,15 @@ pub struct ParagraphProperty<'a> {
     /// Specifies that the paragraph should be numbered.
     #[xml(child = "w:numPr")]
     pub numbering: Option<NumberingProperty>,
+    /// Specifies the properties of the paragraph mark.
+    #[xml(child = "w:rPr")]
+    pub mark: Option<ParagraphMarkProperty<'a>>,
+    /// Specifies the properties of the section which ends with the paragraph.
+    #[xml(child = "w:sectPr")]
+    pub section_property: Option<SectionProperty<'a>>,
+    /// Specifies the tracked change of the paragraph properties.
+    #[xml(child = "w:pPrChange")]
+    pub change: Option<ParagraphPropertyChange<'a>>,
 }
 
 impl<'a> ParagraphProperty<'a> {
@@ -39,6 +51,9 @@ impl<'a> ParagraphProperty<'a> {
     __setter!(justification: Option<Justification>);
     __setter!(border: Option<Borders<'a>>);
     __setter!(numbering: Option<NumberingProperty>);
+    __setter!(mark: Option<ParagraphMarkProperty<'a>>);
+    __setter!(section_property: Option<SectionProperty<'a>>);
+    __setter!(change: Option<ParagraphPropertyChange<'a>>);
 }
 
 #[derive(Debug, XmlRead, XmlWrite)]
@@ -70,4 +85,14 @@ __xml_test_suites!(
     r#"<w:pPr><w:pBdr/></w:pPr>"#,
     ParagraphProperty::default().numbering(NumberingProperty::default()),
     r#"<w:pPr><w:numPr><w:numId w:val="0"/><w:ilvl w:val="0"/></w:numPr></w:pPr>"#,
+    ParagraphProperty::default().mark(ParagraphMarkProperty::default()),
+    r#"<w:pPr><w:rPr/></w:pPr>"#,
+    ParagraphProperty::default().section_property(SectionProperty::default()),
+    r#"<w:pPr><w:sectPr/></w:pPr>"#,
+    ParagraphProperty::default().change(ParagraphPropertyChange::new(
+        0,
+        "foo",
+        ParagraphProperty::default()
+    )),
+    r#"<w:pPr><w:pPrChange w:id="0" w:author="foo"><w:pPr/></w:pPrChange></w:pPr>"#,
 );
@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+
+use crate::{__setter, __xml_test_suites, formatting::ParagraphProperty, DateTime};
+
+/// Revision Information for Paragraph Properties
+///
+/// Records a tracked change of the paragraph properties, with the properties
+/// before the change.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let change = ParagraphPropertyChange::new(1, "Reviewer", ParagraphProperty::default());
+/// let prop = ParagraphProperty::default()
+///     .justification(JustificationVal::Center)
+///     .change(change);
+/// ```
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct ParagraphPropertyChange<'a> {
+    /// Specifies the id of the revision.
+    pub id: usize,
+    /// Specifies the author of the revision.
+    pub author: Cow<'a, str>,
+    /// Specifies when the revision was made.
+    pub date: Option<DateTime>,
+    /// Specifies the paragraph properties before the revision.
+    pub property: Box<ParagraphProperty<'a>>,
+}
+
+impl<'a> ParagraphPropertyChange<'a> {
+    __setter!(date: Option<DateTime>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(
+        id: usize,
+        author: T,
+        property: ParagraphProperty<'a>,
+    ) -> Self {
+        ParagraphPropertyChange {
+            id,
+            author: author.into(),
+            date: None,
+            property: Box::new(property),
+        }
+    }
+}
+
+impl<'i: 'a, 'a> XmlRead<'i> for ParagraphPropertyChange<'a> {
+    fn from_reader(reader: &mut XmlReader<'i>) -> XmlResult<Self> {
+        let RawParagraphPropertyChange {
+            id,
+            author,
+            date,
+            mut property,
+        } = RawParagraphPropertyChange::from_reader(reader)?;
+
+        Ok(ParagraphPropertyChange {
+            id,
+            author,
+            date,
+            property: Box::new(property.pop().unwrap_or_default()),
+        })
+    }
+}
+
+impl<'a> XmlWrite for ParagraphPropertyChange<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let ParagraphPropertyChange {
+            id,
+            author,
+            date,
+            property,
+        } = self;
+
+        writer.write_element_start("w:pPrChange")?;
+        writer.write_attribute("w:id", &id.to_string())?;
+        writer.write_attribute("w:author", author)?;
+        if let Some(date) = date {
+            writer.write_attribute("w:date", &date.to_string())?;
+        }
+        writer.write_element_end_open()?;
+        property.to_writer(writer)?;
+        writer.write_element_end_close("w:pPrChange")?;
+
+        Ok(())
+    }
+}
+
+// the properties are stored in a vector, since a recursive type
+// can't be read directly
+#[derive(XmlRead)]
+#[xml(tag = "w:pPrChange")]
+struct RawParagraphPropertyChange<'a> {
+    #[xml(attr = "w:id")]
+    id: usize,
+    #[xml(attr = "w:author")]
+    author: Cow<'a, str>,
+    #[xml(attr = "w:date")]
+    date: Option<DateTime>,
+    #[xml(child = "w:pPr")]
+    property: Vec<ParagraphProperty<'a>>,
+}
+
+__xml_test_suites!(
+    ParagraphPropertyChange,
+    ParagraphPropertyChange::new(1, "foo", ParagraphProperty::default()),
+    r#"<w:pPrChange w:id="1" w:author="foo"><w:pPr/></w:pPrChange>"#,
+    ParagraphPropertyChange::new(2, "bar", ParagraphProperty::default().style_id("foo"))
+        .date("2020-04-01T00:00:00Z".parse::<DateTime>().unwrap()),
+    r#"<w:pPrChange w:id="2" w:author="bar" w:date="2020-04-01T00:00:00Z"><w:pPr><w:pStyle w:val="foo"/></w:pPr></w:pPrChange>"#,
+);
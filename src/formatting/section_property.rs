@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{
+    __setter, __xml_test_suites,
+    formatting::{
+        Columns, DocGrid, EndnoteProperty, FooterReference, FootnoteProperty, HeaderFooterType,
+        HeaderReference, LineNumbering, PageMargin, PageNumberType, PageSize, SectionType,
+        TitlePage, VerticalJustification,
+    },
+};
+
+/// Section Property
+///
+/// The properties of the last section are stored at the end of the body,
+/// while the others are stored in the paragraph property of the last
+/// paragraph of each section.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let prop = SectionProperty::default()
+///     .section_type(SectionTypeVal::OddPage)
+///     .page_size(PageSize::a4().landscape())
+///     .page_margin(PageMargin::from(1440).header(720usize).footer(720usize))
+///     .columns(Columns::from(2usize).space(720usize))
+///     .page_numbering(PageNumberType::default().start(1usize))
+///     .title_page(true);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sectPr")]
+pub struct SectionProperty<'a> {
+    /// Specifies the headers of the section.
+    #[xml(child = "w:headerReference")]
+    pub headers: Vec<HeaderReference<'a>>,
+    /// Specifies the footers of the section.
+    #[xml(child = "w:footerReference")]
+    pub footers: Vec<FooterReference<'a>>,
+    /// Specifies the footnote properties of the section.
+    #[xml(child = "w:footnotePr")]
+    pub footnote_property: Option<FootnoteProperty>,
+    /// Specifies the endnote properties of the section.
+    #[xml(child = "w:endnotePr")]
+    pub endnote_property: Option<EndnoteProperty>,
+    /// Specifies how the section starts.
+    #[xml(child = "w:type")]
+    pub section_type: Option<SectionType>,
+    /// Specifies the size and orientation of pages.
+    #[xml(child = "w:pgSz")]
+    pub page_size: Option<PageSize>,
+    /// Specifies the page margins.
+    #[xml(child = "w:pgMar")]
+    pub page_margin: Option<PageMargin>,
+    /// Specifies the line numbering.
+    #[xml(child = "w:lnNumType")]
+    pub line_numbering: Option<LineNumbering>,
+    /// Specifies the page numbering.
+    #[xml(child = "w:pgNumType")]
+    pub page_numbering: Option<PageNumberType>,
+    /// Specifies the text columns.
+    #[xml(child = "w:cols")]
+    pub columns: Option<Columns>,
+    /// Specifies the vertical alignment of text on pages.
+    #[xml(child = "w:vAlign")]
+    pub vertical_justification: Option<VerticalJustification>,
+    /// Specifies whether the first page has its own header and footer.
+    #[xml(child = "w:titlePg")]
+    pub title_page: Option<TitlePage>,
+    /// Specifies the document grid.
+    #[xml(child = "w:docGrid")]
+    pub doc_grid: Option<DocGrid>,
+}
+
+impl<'a> SectionProperty<'a> {
+    __setter!(footnote_property: Option<FootnoteProperty>);
+    __setter!(endnote_property: Option<EndnoteProperty>);
+    __setter!(section_type: Option<SectionType>);
+    __setter!(page_size: Option<PageSize>);
+    __setter!(page_margin: Option<PageMargin>);
+    __setter!(line_numbering: Option<LineNumbering>);
+    __setter!(page_numbering: Option<PageNumberType>);
+    __setter!(columns: Option<Columns>);
+    __setter!(vertical_justification: Option<VerticalJustification>);
+    __setter!(title_page: Option<TitlePage>);
+    __setter!(doc_grid: Option<DocGrid>);
+
+    /// Uses the header with the given relationship id
+    ///
+    /// It replaces the header of the same type, and turns on the title page
+    /// for first page headers.
+    pub fn push_header<T: Into<Cow<'a, str>>>(mut self, ty: HeaderFooterType, id: T) -> Self {
+        self.headers.retain(|header| header.ty != ty);
+        self.headers.push(HeaderReference::new(ty, id));
+        if ty == HeaderFooterType::First {
+            self.title_page = Some(true.into());
+        }
+        self
+    }
+
+    /// Uses the footer with the given relationship id
+    ///
+    /// It replaces the footer of the same type, and turns on the title page
+    /// for first page footers.
+    pub fn push_footer<T: Into<Cow<'a, str>>>(mut self, ty: HeaderFooterType, id: T) -> Self {
+        self.footers.retain(|footer| footer.ty != ty);
+        self.footers.push(FooterReference::new(ty, id));
+        if ty == HeaderFooterType::First {
+            self.title_page = Some(true.into());
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+use crate::formatting::{SectionTypeVal, VerticalJustificationVal};
+
+__xml_test_suites!(
+    SectionProperty,
+    SectionProperty::default(),
+    r#"<w:sectPr/>"#,
+    SectionProperty::default().page_size(PageSize::a4()),
+    r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838"/></w:sectPr>"#,
+    SectionProperty::default()
+        .title_page(true)
+        .columns(Columns::from(2usize))
+        .vertical_justification(VerticalJustificationVal::Center),
+    r#"<w:sectPr><w:cols w:num="2"/><w:vAlign w:val="center"/><w:titlePg w:val="true"/></w:sectPr>"#,
+    SectionProperty::default()
+        .section_type(SectionTypeVal::Continuous)
+        .page_size(PageSize::a4()),
+    r#"<w:sectPr><w:type w:val="continuous"/><w:pgSz w:w="11906" w:h="16838"/></w:sectPr>"#,
+    SectionProperty::default()
+        .push_header(HeaderFooterType::First, "rId1")
+        .push_footer(HeaderFooterType::Default, "rId2"),
+    r#"<w:sectPr><w:headerReference w:type="first" r:id="rId1"/><w:footerReference w:type="default" r:id="rId2"/><w:titlePg w:val="true"/></w:sectPr>"#,
+);
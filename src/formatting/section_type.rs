@@ -0,0 +1,59 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__string_enum, __xml_test_suites};
+
+/// Section Type
+///
+/// Specifies how the section starts relative to the previous one.
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let ty = SectionType::from(SectionTypeVal::Continuous);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:type")]
+pub struct SectionType {
+    #[xml(attr = "w:val")]
+    pub value: Option<SectionTypeVal>,
+}
+
+impl<T: Into<Option<SectionTypeVal>>> From<T> for SectionType {
+    fn from(val: T) -> Self {
+        SectionType { value: val.into() }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum SectionTypeVal {
+    /// Starts on the next page, which is the default.
+    NextPage,
+    /// Starts on the same page.
+    Continuous,
+    /// Starts on the next even-numbered page.
+    EvenPage,
+    /// Starts on the next odd-numbered page.
+    OddPage,
+    /// Starts in the next column.
+    NextColumn,
+}
+
+__string_enum! {
+    SectionTypeVal {
+        NextPage = "nextPage",
+        Continuous = "continuous",
+        EvenPage = "evenPage",
+        OddPage = "oddPage",
+        NextColumn = "nextColumn",
+    }
+}
+
+__xml_test_suites!(
+    SectionType,
+    SectionType::default(),
+    r#"<w:type/>"#,
+    SectionType::from(SectionTypeVal::Continuous),
+    r#"<w:type w:val="continuous"/>"#,
+);
@@ -10,7 +10,7 @@ use crate::__xml_test_suites;
 /// let strike = Strike::from(false);
 /// let strike = Strike::from(true);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[derive(Debug, Clone, Default, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:strike")]
 pub struct Strike {
@@ -1,6 +1,10 @@
 use strong_xml::{XmlRead, XmlWrite};
 
-use crate::{__setter, __xml_test_suites, formatting::TableJustification};
+use crate::{
+    __setter, __xml_test_suites,
+    document::{Deletion, Insertion},
+    formatting::TableJustification,
+};
 
 /// Table Row Property
 ///
@@ -13,14 +17,22 @@ use crate::{__setter, __xml_test_suites, formatting::TableJustification};
 #[derive(Debug, Default, XmlRead, XmlWrite)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:trPr")]
-pub struct TableRowProperty {
+pub struct TableRowProperty<'a> {
     /// Specifies the alignment of the row with respect to the text margins in the section.
     #[xml(child = "w:jc")]
     pub justification: Option<TableJustification>,
+    /// Specifies that the row is inserted as a tracked change.
+    #[xml(child = "w:ins")]
+    pub insertion: Option<Insertion<'a>>,
+    /// Specifies that the row is deleted as a tracked change.
+    #[xml(child = "w:del")]
+    pub deletion: Option<Deletion<'a>>,
 }
 
-impl TableRowProperty {
+impl<'a> TableRowProperty<'a> {
     __setter!(justification: Option<TableJustification>);
+    __setter!(insertion: Option<Insertion<'a>>);
+    __setter!(deletion: Option<Deletion<'a>>);
 }
 
 __xml_test_suites!(
@@ -29,4 +41,8 @@ __xml_test_suites!(
     r#"<w:trPr/>"#,
     TableRowProperty::default().justification(crate::formatting::TableJustificationVal::Start),
     r#"<w:trPr><w:jc w:val="start"/></w:trPr>"#,
+    TableRowProperty::default().insertion(Insertion::new(0, "foo")),
+    r#"<w:trPr><w:ins w:id="0" w:author="foo"/></w:trPr>"#,
+    TableRowProperty::default().deletion(Deletion::new(1, "bar")),
+    r#"<w:trPr><w:del w:id="1" w:author="bar"/></w:trPr>"#,
 );
@@ -0,0 +1,32 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Different First Page Headers and Footers
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let title_page = TitlePage::from(true);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:titlePg")]
+pub struct TitlePage {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for TitlePage {
+    fn from(val: T) -> Self {
+        TitlePage { value: val.into() }
+    }
+}
+
+__xml_test_suites!(
+    TitlePage,
+    TitlePage::default(),
+    r#"<w:titlePg/>"#,
+    TitlePage::from(false),
+    r#"<w:titlePg w:val="false"/>"#,
+);
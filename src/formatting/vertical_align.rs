@@ -0,0 +1,46 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__string_enum, __xml_test_suites};
+
+/// Subscript/Superscript Text
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let align = VerticalAlign::from(VerticalAlignVal::Superscript);
+/// ```
+#[derive(Debug, Clone, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:vertAlign")]
+pub struct VerticalAlign {
+    #[xml(attr = "w:val")]
+    pub value: VerticalAlignVal,
+}
+
+impl From<VerticalAlignVal> for VerticalAlign {
+    fn from(value: VerticalAlignVal) -> Self {
+        VerticalAlign { value }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum VerticalAlignVal {
+    Baseline,
+    Superscript,
+    Subscript,
+}
+
+__string_enum! {
+    VerticalAlignVal {
+        Baseline = "baseline",
+        Superscript = "superscript",
+        Subscript = "subscript",
+    }
+}
+
+__xml_test_suites!(
+    VerticalAlign,
+    VerticalAlign::from(VerticalAlignVal::Superscript),
+    r#"<w:vertAlign w:val="superscript"/>"#,
+);
@@ -0,0 +1,48 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__string_enum, __xml_test_suites};
+
+/// Vertical Text Alignment on Page
+///
+/// ```rust
+/// use docx::formatting::*;
+///
+/// let align = VerticalJustification::from(VerticalJustificationVal::Center);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:vAlign")]
+pub struct VerticalJustification {
+    #[xml(attr = "w:val")]
+    pub value: VerticalJustificationVal,
+}
+
+impl From<VerticalJustificationVal> for VerticalJustification {
+    fn from(value: VerticalJustificationVal) -> Self {
+        VerticalJustification { value }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum VerticalJustificationVal {
+    Top,
+    Center,
+    Both,
+    Bottom,
+}
+
+__string_enum! {
+    VerticalJustificationVal {
+        Top = "top",
+        Center = "center",
+        Both = "both",
+        Bottom = "bottom",
+    }
+}
+
+__xml_test_suites!(
+    VerticalJustification,
+    VerticalJustification::from(VerticalJustificationVal::Center),
+    r#"<w:vAlign w:val="center"/>"#,
+);
@@ -20,9 +20,11 @@
 //! docx.write_file("demo.docx").unwrap();
 //! ```
 //!
-//! Also see: [`Docx::write`].
+//! Also see: [`Docx::write`] and [`Docx::write_stream`], the latter only
+//! requires a non-seekable writer like stdout.
 //!
 //! [`Docx::write`]: struct.Docx.html#method.write
+//! [`Docx::write_stream`]: struct.Docx.html#method.write_stream
 //!
 //! # Reading from files
 //!
@@ -66,6 +68,52 @@
 //!
 //! [`DocxFile::from_reader`]: struct.DocxFile.html#method.from_reader
 //!
+//! # Async I/O
+//!
+//! With the `async` feature enabled, [`DocxFile::from_async_reader`] and
+//! [`Docx::write_async`] accept tokio's `AsyncRead` and `AsyncWrite`
+//! respectively. The XML (de)serialization is the same as the blocking one.
+//!
+//! [`DocxFile::from_async_reader`]: struct.DocxFile.html#method.from_async_reader
+//! [`Docx::write_async`]: struct.Docx.html#method.write_async
+//!
+//! # Encrypted documents
+//!
+//! Documents protected with "Encrypt with Password" are not ZIP archives,
+//! so [`DocxFile::from_reader`] returns [`DocxError::Encrypted`] for them.
+//! With the `encryption` feature enabled, they can be read with
+//! [`DocxFile::from_encrypted_reader`] and written with
+//! [`Docx::write_encrypted`], using the agile encryption.
+//!
+//! [`DocxError::Encrypted`]: enum.DocxError.html#variant.Encrypted
+//! [`DocxFile::from_encrypted_reader`]: struct.DocxFile.html#method.from_encrypted_reader
+//! [`Docx::write_encrypted`]: struct.Docx.html#method.write_encrypted
+//!
+//! # Comparing documents
+//!
+//! [`Docx::compare`] compares two versions of a document and returns the
+//! revised one, with the differences recorded as tracked changes, as Word's
+//! Compare does.
+//!
+//! [`Docx::compare`]: struct.Docx.html#method.compare
+//!
+//! ```no_run
+//! use docx::document::TrackChanges;
+//! use docx::{Docx, DocxFile};
+//!
+//! let original = DocxFile::from_file("contract_v1.docx").unwrap();
+//! let revised = DocxFile::from_file("contract_v2.docx").unwrap();
+//!
+//! let mut changes = TrackChanges::new("Counsel");
+//! let mut redline = Docx::compare(
+//!     original.parse().unwrap(),
+//!     revised.parse().unwrap(),
+//!     &mut changes,
+//! );
+//!
+//! redline.write_file("contract_redline.docx").unwrap();
+//! ```
+//!
 //! # Similar Projects
 //!
 //! [`bokuweb/docx-rs`]: A .docx file writer with Rust/WebAssembly.
@@ -80,16 +128,37 @@
 mod macros;
 
 pub mod app;
+pub mod chart;
+pub mod comments;
+pub mod comments_extended;
+pub mod comments_ids;
+mod compare;
 pub mod content_type;
 pub mod core;
+pub mod custom;
+pub mod custom_xml;
+mod datetime;
 pub mod document;
 mod docx;
+pub mod drawing;
+#[cfg(feature = "encryption")]
+mod encryption;
+pub mod endnotes;
 mod error;
 pub mod font_table;
+pub mod footer;
+pub mod footnotes;
 pub mod formatting;
+pub mod header;
+pub mod media;
+pub mod people;
 pub mod rels;
 mod schema;
+pub mod settings;
+mod stream;
 pub mod styles;
+pub mod vml;
 
+pub use crate::datetime::DateTime;
 pub use crate::docx::{Docx, DocxFile};
 pub use crate::error::{DocxError, DocxResult};
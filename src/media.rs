@@ -0,0 +1,387 @@
+//! Media parts
+//!
+//! The corresponding ZIP items are usually `/word/media/*`, as well as other
+//! images which relationships refer to.
+
+use std::borrow::Cow;
+
+use crate::document::{Drawing, Paragraph};
+use crate::drawing::EMU_PER_INCH;
+use crate::error::{DocxError, DocxResult};
+
+/// A media part, e.g. an image
+#[derive(Debug)]
+pub struct Media<'a> {
+    /// Specifies the name of the part, e.g. `word/media/image1.png`.
+    pub name: Cow<'a, str>,
+    /// Specifies the content of the part.
+    pub data: Cow<'a, [u8]>,
+}
+
+impl<'a> Media<'a> {
+    /// Returns the extension of the part name, in lowercase
+    pub fn extension(&self) -> Option<String> {
+        let file_name = self.name.rsplit('/').next()?;
+        let (_, ext) = file_name.rsplit_once('.')?;
+        Some(ext.to_ascii_lowercase())
+    }
+
+    /// Returns the content type according to the extension, if known
+    pub fn content_type(&self) -> Option<&'static str> {
+        let ext = self.extension()?;
+        ImageFormat::from_extension(&ext).map(ImageFormat::content_type)
+    }
+}
+
+/// A picture in the document with the media part it refers to
+///
+/// Returned by `Docx::media_references`.
+#[derive(Debug)]
+pub struct MediaReference<'b, 'a> {
+    /// Specifies the part containing the picture.
+    pub source: MediaSource<'b>,
+    /// Specifies the paragraph containing the picture.
+    pub paragraph: &'b Paragraph<'a>,
+    /// Specifies the drawing of the picture.
+    pub drawing: &'b Drawing<'a>,
+    /// Specifies the media part of the image.
+    pub media: &'b Media<'a>,
+    /// Specifies the media part of the SVG original, if the image is a
+    /// fallback of it.
+    pub svg: Option<&'b Media<'a>>,
+}
+
+impl<'b, 'a> MediaReference<'b, 'a> {
+    /// Returns the alternative text
+    pub fn description(&self) -> Option<&'b str> {
+        self.drawing.doc_property()?.description.as_deref()
+    }
+
+    /// Returns the title
+    pub fn title(&self) -> Option<&'b str> {
+        self.drawing.doc_property()?.title.as_deref()
+    }
+
+    /// Returns the size of the picture in EMUs
+    pub fn extent(&self) -> Option<(u64, u64)> {
+        self.drawing.extent()
+    }
+
+    /// Returns the format and the pixel size of the image, if it can be read
+    pub fn image_info(&self) -> Option<ImageInfo> {
+        ImageInfo::from_bytes(&self.media.data).ok()
+    }
+}
+
+/// The part containing a picture
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaSource<'b> {
+    /// The main document part
+    Document,
+    /// The header part, with the id of its relationship
+    Header(&'b str),
+    /// The footer part, with the id of its relationship
+    Footer(&'b str),
+}
+
+/// Image formats
+///
+/// The size of SVG images isn't read, they're added along with a PNG
+/// fallback with `Docx::add_svg_image`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::Svg => "image/svg+xml",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpeg",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Svg => "svg",
+        }
+    }
+
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match &*ext.to_ascii_lowercase() {
+            "png" => Some(ImageFormat::Png),
+            "jpeg" | "jpg" | "jpe" => Some(ImageFormat::Jpeg),
+            "gif" => Some(ImageFormat::Gif),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// The format and size of an image, read from its header
+///
+/// ```rust
+/// use docx::media::*;
+///
+/// // a 1x1 GIF image
+/// let gif = b"GIF89a\x01\x00\x01\x00\x00\x00\x00";
+/// let info = ImageInfo::from_bytes(gif).unwrap();
+///
+/// assert_eq!(info.format, ImageFormat::Gif);
+/// assert_eq!((info.width, info.height), (1, 1));
+/// assert_eq!(info.extent(), (9525, 9525));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    /// Specifies the width in pixels.
+    pub width: u32,
+    /// Specifies the height in pixels.
+    pub height: u32,
+    /// Specifies the horizontal and vertical resolution in dots per inch,
+    /// if the image records it.
+    pub dpi: Option<(f64, f64)>,
+}
+
+/// The resolution of images which don't record it
+pub const DEFAULT_DPI: f64 = 96.0;
+
+impl ImageInfo {
+    /// Reads the header of a PNG, JPEG or GIF image
+    pub fn from_bytes(data: &[u8]) -> DocxResult<ImageInfo> {
+        match data {
+            [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, ..] => read_png(data),
+            [0xff, 0xd8, ..] => read_jpeg(data),
+            [b'G', b'I', b'F', b'8', ..] => read_gif(data),
+            _ => Err(DocxError::Image("unsupported image format".into())),
+        }
+    }
+
+    /// Returns the size in EMUs, at the resolution of the image
+    ///
+    /// The default resolution is used if the image doesn't record a
+    /// positive one.
+    pub fn extent(&self) -> (u64, u64) {
+        let (x, y) = match self.dpi {
+            Some((x, y)) if x > 0.0 && y > 0.0 => (x, y),
+            _ => (DEFAULT_DPI, DEFAULT_DPI),
+        };
+        (to_emu(self.width, x), to_emu(self.height, y))
+    }
+}
+
+/// Returns `true` if the data looks like an SVG image
+pub(crate) fn is_svg(data: &[u8]) -> bool {
+    data.windows(4).any(|window| window == b"<svg")
+}
+
+fn to_emu(pixels: u32, dpi: f64) -> u64 {
+    (pixels as f64 * EMU_PER_INCH as f64 / dpi).round() as u64
+}
+
+fn invalid(format: ImageFormat) -> DocxError {
+    DocxError::Image(format!("malformed {} image", format.extension()))
+}
+
+fn be_u16(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([*data.get(at)?, *data.get(at + 1)?]))
+}
+
+fn be_u32(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_png(data: &[u8]) -> DocxResult<ImageInfo> {
+    let invalid = || invalid(ImageFormat::Png);
+
+    // the first chunk is always IHDR, which starts with the size
+    if data.get(12..16) != Some(b"IHDR") {
+        return Err(invalid());
+    }
+    let width = be_u32(data, 16).ok_or_else(invalid)?;
+    let height = be_u32(data, 20).ok_or_else(invalid)?;
+
+    // pHYs chunk records pixels per unit, in meters if the unit is one,
+    // which is rounded to whole dots per inch, densities rounded to zero are
+    // ignored
+    let mut dpi = None;
+    let mut offset = 8;
+    while let (Some(len), Some(ty)) = (be_u32(data, offset), data.get(offset + 4..offset + 8)) {
+        match ty {
+            b"pHYs" => {
+                let x = be_u32(data, offset + 8).ok_or_else(invalid)?;
+                let y = be_u32(data, offset + 12).ok_or_else(invalid)?;
+                let (x, y) = ((x as f64 * 0.0254).round(), (y as f64 * 0.0254).round());
+                if data.get(offset + 16) == Some(&1) && x > 0.0 && y > 0.0 {
+                    dpi = Some((x, y));
+                }
+                break;
+            }
+            b"IDAT" | b"IEND" => break,
+            _ => offset += len as usize + 12,
+        }
+    }
+
+    Ok(ImageInfo {
+        format: ImageFormat::Png,
+        width,
+        height,
+        dpi,
+    })
+}
+
+fn read_jpeg(data: &[u8]) -> DocxResult<ImageInfo> {
+    let invalid = || invalid(ImageFormat::Jpeg);
+
+    let mut dpi = None;
+    let mut offset = 2;
+    loop {
+        if data.get(offset) != Some(&0xff) {
+            return Err(invalid());
+        }
+        let marker = *data.get(offset + 1).ok_or_else(invalid)?;
+        match marker {
+            // fill bytes
+            0xff => {
+                offset += 1;
+                continue;
+            }
+            // markers without segments
+            0x01 | 0xd0..=0xd7 => {
+                offset += 2;
+                continue;
+            }
+            _ => (),
+        }
+
+        let len = be_u16(data, offset + 2).ok_or_else(invalid)? as usize;
+        let segment = offset + 4;
+
+        match marker {
+            // JFIF segment records the density, in inches if the unit is one
+            // or in centimeters if two
+            0xe0 if data.get(segment..segment + 5) == Some(b"JFIF\0") => {
+                let unit = data.get(segment + 7).copied();
+                let x = be_u16(data, segment + 8).unwrap_or(0) as f64;
+                let y = be_u16(data, segment + 10).unwrap_or(0) as f64;
+                if x > 0.0 && y > 0.0 {
+                    dpi = match unit {
+                        Some(1) => Some((x, y)),
+                        Some(2) => Some((x * 2.54, y * 2.54)),
+                        _ => None,
+                    };
+                }
+            }
+            // start of frame segments, except DHT, JPG and DAC
+            0xc0..=0xcf if marker != 0xc4 && marker != 0xc8 && marker != 0xcc => {
+                let height = be_u16(data, segment + 1).ok_or_else(invalid)?;
+                let width = be_u16(data, segment + 3).ok_or_else(invalid)?;
+                return Ok(ImageInfo {
+                    format: ImageFormat::Jpeg,
+                    width: width as u32,
+                    height: height as u32,
+                    dpi,
+                });
+            }
+            // start of scan or end of image before any frame
+            0xda | 0xd9 => return Err(invalid()),
+            _ => (),
+        }
+
+        offset += 2 + len;
+    }
+}
+
+fn read_gif(data: &[u8]) -> DocxResult<ImageInfo> {
+    let invalid = || invalid(ImageFormat::Gif);
+
+    let width = data.get(6..8).ok_or_else(invalid)?;
+    let height = data.get(8..10).ok_or_else(invalid)?;
+
+    Ok(ImageInfo {
+        format: ImageFormat::Gif,
+        width: u16::from_le_bytes([width[0], width[1]]) as u32,
+        height: u16::from_le_bytes([height[0], height[1]]) as u32,
+        dpi: None,
+    })
+}
+
+#[cfg(test)]
+pub(crate) fn png(width: u32, height: u32, ppm: Option<u32>) -> Vec<u8> {
+    fn chunk(data: &mut Vec<u8>, ty: &[u8], content: &[u8]) {
+        data.extend_from_slice(&(content.len() as u32).to_be_bytes());
+        data.extend_from_slice(ty);
+        data.extend_from_slice(content);
+        // CRCs aren't checked
+        data.extend_from_slice(&[0; 4]);
+    }
+
+    let mut data = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    chunk(&mut data, b"IHDR", &header);
+
+    if let Some(ppm) = ppm {
+        let mut phys = Vec::new();
+        phys.extend_from_slice(&ppm.to_be_bytes());
+        phys.extend_from_slice(&ppm.to_be_bytes());
+        phys.push(1);
+        chunk(&mut data, b"pHYs", &phys);
+    }
+
+    chunk(&mut data, b"IEND", &[]);
+
+    data
+}
+
+#[test]
+fn image_info() -> DocxResult<()> {
+    let info = ImageInfo::from_bytes(&png(200, 100, None))?;
+    assert_eq!(info.format, ImageFormat::Png);
+    assert_eq!((info.width, info.height), (200, 100));
+    assert_eq!(info.dpi, None);
+    assert_eq!(info.extent(), (1905000, 952500));
+
+    // 7874 pixels per meter is about 200 dpi
+    let info = ImageInfo::from_bytes(&png(200, 100, Some(7874)))?;
+    assert_eq!(info.extent(), (914400, 457200));
+
+    // 19 pixels per meter rounds to 0 dpi
+    let mut info = ImageInfo::from_bytes(&png(200, 100, Some(19)))?;
+    assert_eq!(info.dpi, None);
+    assert_eq!(info.extent(), (1905000, 952500));
+    info.dpi = Some((0.0, -1.0));
+    assert_eq!(info.extent(), (1905000, 952500));
+
+    let jpeg = [
+        0xff, 0xd8, // SOI
+        0xff, 0xe0, 0x00, 0x10, b'J', b'F', b'I', b'F', 0, 1, 1, 1, 0x00, 0x48, 0x00, 0x48, 0, 0,
+        0xff, 0xc0, 0x00, 0x11, 8, 0x00, 0x30, 0x00, 0x60, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let info = ImageInfo::from_bytes(&jpeg)?;
+    assert_eq!(info.format, ImageFormat::Jpeg);
+    assert_eq!((info.width, info.height), (96, 48));
+    assert_eq!(info.dpi, Some((72.0, 72.0)));
+    assert_eq!(info.extent(), (1219200, 609600));
+
+    let info = ImageInfo::from_bytes(b"GIF89a\x40\x01\xf0\x00")?;
+    assert_eq!(info.format, ImageFormat::Gif);
+    assert_eq!((info.width, info.height), (320, 240));
+
+    assert!(ImageInfo::from_bytes(b"BM").is_err());
+    assert!(ImageInfo::from_bytes(&jpeg[..12]).is_err());
+
+    Ok(())
+}
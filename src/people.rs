@@ -0,0 +1,125 @@
+//! People part
+//!
+//! The corresponding ZIP item is `/word/people.xml`.
+
+use std::borrow::Cow;
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::schema::{SCHEMA_MARKUP_COMPATIBILITY, SCHEMA_WORD_2012};
+use crate::{__setter, __xml_test_suites};
+
+/// Authors of comments and revisions
+///
+/// ```rust
+/// use docx::people::*;
+///
+/// let mut people = People::default();
+/// people.add("Reviewer");
+/// people.add("Reviewer");
+///
+/// assert_eq!(people.people.len(), 1);
+/// ```
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:people")]
+pub struct People<'a> {
+    #[xml(child = "w15:person")]
+    pub people: Vec<Person<'a>>,
+}
+
+impl<'a> People<'a> {
+    /// Adds a person with the given name, unless there's one already
+    pub fn add<T: Into<Cow<'a, str>>>(&mut self, author: T) {
+        let author = author.into();
+        if self.people.iter().all(|person| person.author != author) {
+            self.people.push(Person::new(author));
+        }
+    }
+
+    /// Returns the person with the given name
+    pub fn get(&self, author: &str) -> Option<&Person<'a>> {
+        self.people.iter().find(|person| person.author == author)
+    }
+}
+
+impl<'a> XmlWrite for People<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let People { people } = self;
+
+        log::debug!("[People] Started writing.");
+
+        writer.write_element_start("w15:people")?;
+
+        writer.write_attribute("xmlns:w15", SCHEMA_WORD_2012)?;
+        writer.write_attribute("xmlns:mc", SCHEMA_MARKUP_COMPATIBILITY)?;
+        writer.write_attribute("mc:Ignorable", "w15")?;
+
+        if people.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for ele in people {
+                ele.to_writer(writer)?;
+            }
+            writer.write_element_end_close("w15:people")?;
+        }
+
+        log::debug!("[People] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Person
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:person")]
+pub struct Person<'a> {
+    /// Specifies the name used as the author of comments and revisions.
+    #[xml(attr = "w15:author")]
+    pub author: Cow<'a, str>,
+    /// Specifies the account of the person.
+    #[xml(child = "w15:presenceInfo")]
+    pub presence_info: Option<PresenceInfo<'a>>,
+}
+
+impl<'a> Person<'a> {
+    __setter!(presence_info: Option<PresenceInfo<'a>>);
+
+    pub fn new<T: Into<Cow<'a, str>>>(author: T) -> Self {
+        Person {
+            author: author.into(),
+            presence_info: None,
+        }
+    }
+}
+
+/// Presence Info
+///
+/// Identifies the account of a person, e.g. `AD` provider with an email.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:presenceInfo")]
+pub struct PresenceInfo<'a> {
+    #[xml(attr = "w15:providerId")]
+    pub provider_id: Cow<'a, str>,
+    #[xml(attr = "w15:userId")]
+    pub user_id: Cow<'a, str>,
+}
+
+__xml_test_suites!(
+    People,
+    People::default(),
+    r#"<w15:people xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" mc:Ignorable="w15"/>"#,
+    People {
+        people: vec![
+            Person::new("foo"),
+            Person::new("bar").presence_info(PresenceInfo {
+                provider_id: "None".into(),
+                user_id: "bar".into(),
+            }),
+        ]
+    },
+    r#"<w15:people xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" mc:Ignorable="w15"><w15:person w15:author="foo"/><w15:person w15:author="bar"><w15:presenceInfo w15:providerId="None" w15:userId="bar"/></w15:person></w15:people>"#,
+);
@@ -43,13 +43,33 @@ impl<'a> XmlWrite for Relationships<'a> {
 }
 
 impl<'a> Relationships<'a> {
-    pub fn add_rel(&mut self, schema: &'a str, target: &'a str) {
-        let len = self.relationships.len();
-        self.relationships.push(Relationship {
-            id: format!("rId{}", len).into(),
-            target: target.into(),
-            ty: schema.into(),
-        });
+    /// Adds a relationship and returns its id
+    ///
+    /// If the same relationship already exists, its id is returned instead.
+    pub fn add_rel<T: Into<Cow<'a, str>>>(&mut self, schema: &'a str, target: T) -> &str {
+        let target = target.into();
+
+        let position = match self
+            .relationships
+            .iter()
+            .position(|r| r.ty == schema && r.target == target)
+        {
+            Some(position) => position,
+            None => {
+                let id = (1..)
+                    .map(|i| format!("rId{}", i))
+                    .find(|id| self.relationships.iter().all(|r| r.id != *id))
+                    .unwrap();
+                self.relationships.push(Relationship {
+                    id: id.into(),
+                    target,
+                    ty: schema.into(),
+                });
+                self.relationships.len() - 1
+            }
+        };
+
+        &self.relationships[position].id
     }
 
     pub fn get_target(&self, id: &str) -> Option<&str> {
@@ -70,3 +90,12 @@ pub struct Relationship<'a> {
     #[xml(attr = "Type")]
     pub ty: Cow<'a, str>,
 }
+
+#[test]
+fn add_rel() {
+    let mut rels = Relationships::default();
+    assert_eq!(rels.add_rel("foo", "foo.xml"), "rId1");
+    assert_eq!(rels.add_rel("bar", "bar.xml"), "rId2");
+    assert_eq!(rels.add_rel("foo", "foo.xml"), "rId1");
+    assert_eq!(rels.relationships.len(), 2);
+}
@@ -6,6 +6,8 @@ pub const SCHEMA_OFFICE_DOCUMENT: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument";
 pub const SCHEMA_FONT_TABLE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable";
+pub const SCHEMA_SETTINGS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings";
 pub const SCHEMA_STYLES: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles";
 pub const SCHEMA_DOC_PROPS_V_TYPES: &str =
@@ -17,3 +19,60 @@ pub const SCHEMA_RELATIONSHIPS: &str =
     "http://schemas.openxmlformats.org/package/2006/relationships";
 pub const SCHEMAS_EXTENDED: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties";
+pub const SCHEMA_CORE_PROPERTIES: &str =
+    "http://schemas.openxmlformats.org/package/2006/metadata/core-properties";
+pub const SCHEMA_DC: &str = "http://purl.org/dc/elements/1.1/";
+pub const SCHEMA_DC_TERMS: &str = "http://purl.org/dc/terms/";
+pub const SCHEMA_DC_MI_TYPE: &str = "http://purl.org/dc/dcmitype/";
+pub const SCHEMA_XSI: &str = "http://www.w3.org/2001/XMLSchema-instance";
+pub const SCHEMA_REL_CUSTOM: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties";
+pub const SCHEMA_CUSTOM: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties";
+pub const SCHEMA_CUSTOM_XML: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/customXml";
+pub const SCHEMA_REL_CUSTOM_XML: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml";
+pub const SCHEMA_REL_CUSTOM_XML_PROPS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps";
+pub const SCHEMA_OFFICE_DOCUMENT_RELATIONSHIPS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+pub const SCHEMA_HEADER: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header";
+pub const SCHEMA_FOOTER: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer";
+pub const SCHEMA_FOOTNOTES: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footnotes";
+pub const SCHEMA_ENDNOTES: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/endnotes";
+pub const SCHEMA_COMMENTS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
+pub const SCHEMA_WORD_2010: &str = "http://schemas.microsoft.com/office/word/2010/wordml";
+pub const SCHEMA_WORD_2012: &str = "http://schemas.microsoft.com/office/word/2012/wordml";
+pub const SCHEMA_WORD_2016_CID: &str = "http://schemas.microsoft.com/office/word/2016/wordml/cid";
+pub const SCHEMA_COMMENTS_EXTENDED: &str =
+    "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
+pub const SCHEMA_COMMENTS_IDS: &str =
+    "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds";
+pub const SCHEMA_PEOPLE: &str = "http://schemas.microsoft.com/office/2011/relationships/people";
+pub const SCHEMA_WORDPROCESSING_DRAWING: &str =
+    "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing";
+pub const SCHEMA_DRAWING_MAIN: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
+pub const SCHEMA_PICTURE: &str = "http://schemas.openxmlformats.org/drawingml/2006/picture";
+pub const SCHEMA_IMAGE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
+pub const SCHEMA_WORDPROCESSING_SHAPE: &str =
+    "http://schemas.microsoft.com/office/word/2010/wordprocessingShape";
+pub const SCHEMA_MARKUP_COMPATIBILITY: &str =
+    "http://schemas.openxmlformats.org/markup-compatibility/2006";
+pub const SCHEMA_VML: &str = "urn:schemas-microsoft-com:vml";
+pub const SCHEMA_SVG: &str = "http://schemas.microsoft.com/office/drawing/2016/SVG/main";
+pub const SCHEMA_CHART: &str = "http://schemas.openxmlformats.org/drawingml/2006/chart";
+pub const SCHEMA_REL_CHART: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+pub const SCHEMA_REL_PACKAGE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/package";
+pub const SCHEMA_SPREADSHEET_MAIN: &str =
+    "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+pub const SCHEMA_REL_WORKSHEET: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet";
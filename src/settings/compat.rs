@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites};
+
+const SCHEMA_WORD: &str = "http://schemas.microsoft.com/office/word";
+
+/// Compatibility Settings
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// // opens the document without the compatibility mode
+/// let compat = Compat::default().push_setting(CompatSetting::compatibility_mode(15));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:compat")]
+pub struct Compat<'a> {
+    /// Specifies the compatibility settings.
+    #[xml(child = "w:compatSetting")]
+    pub settings: Vec<CompatSetting<'a>>,
+}
+
+impl<'a> Compat<'a> {
+    pub fn push_setting(mut self, setting: CompatSetting<'a>) -> Self {
+        self.settings.push(setting);
+        self
+    }
+
+    /// Returns the value of the compatibility mode, if any
+    pub fn compatibility_mode(&self) -> Option<usize> {
+        self.settings
+            .iter()
+            .find(|setting| {
+                setting.name.as_deref() == Some("compatibilityMode")
+                    && setting.uri.as_deref() == Some(SCHEMA_WORD)
+            })
+            .and_then(|setting| setting.value.as_ref())
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+/// Compatibility Setting
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:compatSetting")]
+pub struct CompatSetting<'a> {
+    /// Specifies the name of the setting.
+    #[xml(attr = "w:name")]
+    pub name: Option<Cow<'a, str>>,
+    /// Specifies the namespace of the setting.
+    #[xml(attr = "w:uri")]
+    pub uri: Option<Cow<'a, str>>,
+    /// Specifies the value of the setting.
+    #[xml(attr = "w:val")]
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a> CompatSetting<'a> {
+    __setter!(name: Option<Cow<'a, str>>);
+    __setter!(uri: Option<Cow<'a, str>>);
+    __setter!(value: Option<Cow<'a, str>>);
+
+    /// Creates a `compatibilityMode` setting
+    ///
+    /// Use `15` for Word 2013 and later, `14` for Word 2010 and `12` for Word 2007.
+    pub fn compatibility_mode(mode: usize) -> Self {
+        CompatSetting::default()
+            .name("compatibilityMode")
+            .uri(SCHEMA_WORD)
+            .value(mode.to_string())
+    }
+}
+
+__xml_test_suites!(
+    Compat,
+    Compat::default(),
+    r#"<w:compat/>"#,
+    Compat::default().push_setting(CompatSetting::compatibility_mode(15)),
+    r#"<w:compat><w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="15"/></w:compat>"#,
+);
+
+#[test]
+fn compatibility_mode() {
+    let compat = Compat::default().push_setting(CompatSetting::compatibility_mode(15));
+    assert_eq!(compat.compatibility_mode(), Some(15));
+    assert_eq!(Compat::default().compatibility_mode(), None);
+}
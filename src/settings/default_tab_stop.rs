@@ -0,0 +1,32 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Default Tab Stop
+///
+/// Specifies the interval between automatic tab stops, in twentieths of a point.
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let tab = DefaultTabStop::from(720usize);
+/// ```
+#[derive(Debug, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:defaultTabStop")]
+pub struct DefaultTabStop {
+    #[xml(attr = "w:val")]
+    pub value: usize,
+}
+
+impl<T: Into<usize>> From<T> for DefaultTabStop {
+    fn from(val: T) -> Self {
+        DefaultTabStop { value: val.into() }
+    }
+}
+
+__xml_test_suites!(
+    DefaultTabStop,
+    DefaultTabStop::from(720usize),
+    r#"<w:defaultTabStop w:val="720"/>"#,
+);
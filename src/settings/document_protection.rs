@@ -0,0 +1,342 @@
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites, error::DocxResult};
+
+/// Document Protection
+///
+/// Specifies the set of editing restrictions applied to the document,
+/// optionally guarded by a password.
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let protection = DocumentProtection::new(ProtectionType::Forms)
+///     .password("secret")
+///     .unwrap();
+///
+/// assert!(protection.verify_password("secret"));
+/// assert!(!protection.verify_password("guess"));
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:documentProtection")]
+pub struct DocumentProtection<'a> {
+    /// Specifies the editing restrictions.
+    #[xml(attr = "w:edit")]
+    pub edit: Option<ProtectionType>,
+    /// Specifies if formatting restrictions are in effect.
+    #[xml(attr = "w:formatting")]
+    pub formatting: Option<bool>,
+    /// Specifies if the editing restrictions are enforced.
+    #[xml(attr = "w:enforcement")]
+    pub enforcement: Option<bool>,
+    /// Specifies the cryptographic provider type used to hash the password.
+    #[xml(attr = "w:cryptProviderType")]
+    pub crypt_provider_type: Option<CryptProviderType>,
+    /// Specifies the class of the hashing algorithm, always `hash`.
+    #[xml(attr = "w:cryptAlgorithmClass")]
+    pub crypt_algorithm_class: Option<Cow<'a, str>>,
+    /// Specifies the type of the hashing algorithm, always `typeAny`.
+    #[xml(attr = "w:cryptAlgorithmType")]
+    pub crypt_algorithm_type: Option<Cow<'a, str>>,
+    /// Specifies the hashing algorithm by its security identifier.
+    #[xml(attr = "w:cryptAlgorithmSid")]
+    pub crypt_algorithm_sid: Option<u32>,
+    /// Specifies the number of times the hashing function is iterated.
+    #[xml(attr = "w:cryptSpinCount")]
+    pub crypt_spin_count: Option<u32>,
+    /// Specifies the base64-encoded password hash.
+    #[xml(attr = "w:hash")]
+    pub hash: Option<Cow<'a, str>>,
+    /// Specifies the base64-encoded salt used for hashing the password.
+    #[xml(attr = "w:salt")]
+    pub salt: Option<Cow<'a, str>>,
+    /// Specifies the hashing algorithm by its name.
+    #[xml(attr = "w:algorithmName")]
+    pub algorithm_name: Option<Cow<'a, str>>,
+    /// Specifies the base64-encoded password hash.
+    #[xml(attr = "w:hashValue")]
+    pub hash_value: Option<Cow<'a, str>>,
+    /// Specifies the base64-encoded salt used for hashing the password.
+    #[xml(attr = "w:saltValue")]
+    pub salt_value: Option<Cow<'a, str>>,
+    /// Specifies the number of times the hashing function is iterated.
+    #[xml(attr = "w:spinCount")]
+    pub spin_count: Option<u32>,
+}
+
+/// The number of iterations used by Word when hashing the password
+const SPIN_COUNT: u32 = 100_000;
+
+/// The security identifier of SHA-512
+const SID_SHA512: u32 = 14;
+
+impl<'a> DocumentProtection<'a> {
+    __setter!(edit: Option<ProtectionType>);
+    __setter!(formatting: Option<bool>);
+    __setter!(enforcement: Option<bool>);
+
+    /// Creates an enforced protection without password
+    pub fn new(edit: ProtectionType) -> Self {
+        DocumentProtection {
+            edit: Some(edit),
+            enforcement: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Protects the document with the password, hashed with a random salt
+    ///
+    /// Returns an error if no random numbers are available.
+    pub fn password(self, password: &str) -> DocxResult<Self> {
+        let mut salt = [0; 16];
+        getrandom::getrandom(&mut salt)?;
+        Ok(self.password_with_salt(password, &salt))
+    }
+
+    /// Protects the document with the password, hashed with the given salt
+    ///
+    /// The password is hashed with SHA-512 and 100,000 iterations, as Word does.
+    pub fn password_with_salt(mut self, password: &str, salt: &[u8]) -> Self {
+        self.algorithm_name = None;
+        self.hash_value = None;
+        self.salt_value = None;
+        self.spin_count = None;
+
+        if password.is_empty() {
+            self.crypt_provider_type = None;
+            self.crypt_algorithm_class = None;
+            self.crypt_algorithm_type = None;
+            self.crypt_algorithm_sid = None;
+            self.crypt_spin_count = None;
+            self.hash = None;
+            self.salt = None;
+        } else {
+            let hash = hash_password(password, SID_SHA512, salt, SPIN_COUNT)
+                .expect("SHA-512 should be supported");
+
+            self.crypt_provider_type = Some(CryptProviderType::RsaAes);
+            self.crypt_algorithm_class = Some("hash".into());
+            self.crypt_algorithm_type = Some("typeAny".into());
+            self.crypt_algorithm_sid = Some(SID_SHA512);
+            self.crypt_spin_count = Some(SPIN_COUNT);
+            self.hash = Some(base64::encode(hash).into());
+            self.salt = Some(base64::encode(salt).into());
+        }
+
+        self
+    }
+
+    /// Returns `true` if the password matches the stored hash
+    ///
+    /// Always returns `true` if the protection has no password.
+    pub fn verify_password(&self, password: &str) -> bool {
+        let (sid, spin_count, hash, salt) = match self {
+            DocumentProtection {
+                crypt_algorithm_sid: Some(sid),
+                crypt_spin_count,
+                hash: Some(hash),
+                salt: Some(salt),
+                ..
+            } => (*sid, crypt_spin_count.unwrap_or(0), hash, salt),
+            DocumentProtection {
+                algorithm_name: Some(name),
+                spin_count,
+                hash_value: Some(hash),
+                salt_value: Some(salt),
+                ..
+            } => match algorithm_sid(name) {
+                Some(sid) => (sid, spin_count.unwrap_or(0), hash, salt),
+                None => return false,
+            },
+            _ => return true,
+        };
+
+        let (hash, salt) = match (base64::decode(&**hash), base64::decode(&**salt)) {
+            (Ok(hash), Ok(salt)) => (hash, salt),
+            _ => return false,
+        };
+
+        hash_password(password, sid, &salt, spin_count) == Some(hash)
+    }
+}
+
+/// Specifies the editing restrictions of a protected document
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum ProtectionType {
+    /// No editing restrictions.
+    None,
+    /// No editing is allowed.
+    ReadOnly,
+    /// Only comments can be added.
+    Comments,
+    /// All edits are tracked as revisions.
+    TrackedChanges,
+    /// Only form fields and editable ranges can be edited.
+    Forms,
+}
+
+__string_enum! {
+    ProtectionType {
+        None = "none",
+        ReadOnly = "readOnly",
+        Comments = "comments",
+        TrackedChanges = "trackedChanges",
+        Forms = "forms",
+    }
+}
+
+/// Specifies the cryptographic provider type
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum CryptProviderType {
+    /// AES provider, used with SHA-2 algorithms
+    RsaAes,
+    /// Any provider, used with SHA-1
+    RsaFull,
+}
+
+__string_enum! {
+    CryptProviderType {
+        RsaAes = "rsaAES",
+        RsaFull = "rsaFull",
+    }
+}
+
+fn algorithm_sid(name: &str) -> Option<u32> {
+    match name {
+        "SHA-1" => Some(4),
+        "SHA-256" => Some(12),
+        "SHA-384" => Some(13),
+        "SHA-512" => Some(14),
+        _ => None,
+    }
+}
+
+/// Hashes the password as described in ISO/IEC 29500-1 17.15.1.29
+///
+/// The password is first converted into the legacy 32-bit key, whose bytes
+/// are reversed and formatted as a hexadecimal string. The string is then
+/// hashed together with the salt and hashed again for `spin_count` times.
+fn hash_password(password: &str, sid: u32, salt: &[u8], spin_count: u32) -> Option<Vec<u8>> {
+    fn hash<D: Digest>(password: &[u8], salt: &[u8], spin_count: u32) -> Vec<u8> {
+        let mut hash = D::new()
+            .chain_update(salt)
+            .chain_update(password)
+            .finalize();
+        for i in 0..spin_count {
+            hash = D::new()
+                .chain_update(&hash)
+                .chain_update(i.to_le_bytes())
+                .finalize();
+        }
+        hash.to_vec()
+    }
+
+    let key = legacy_key(password).to_le_bytes();
+    let key = format!("{:02X}{:02X}{:02X}{:02X}", key[0], key[1], key[2], key[3]);
+    let key: Vec<u8> = key.encode_utf16().flat_map(u16::to_le_bytes).collect();
+
+    match sid {
+        4 => Some(hash::<Sha1>(&key, salt, spin_count)),
+        12 => Some(hash::<Sha256>(&key, salt, spin_count)),
+        13 => Some(hash::<Sha384>(&key, salt, spin_count)),
+        14 => Some(hash::<Sha512>(&key, salt, spin_count)),
+        _ => None,
+    }
+}
+
+/// Generates the legacy 32-bit key of the password
+fn legacy_key(password: &str) -> u32 {
+    const INITIAL_CODES: [u16; 15] = [
+        0xE1F0, 0x1D0F, 0xCC9C, 0x84C0, 0x110C, 0x0E10, 0xF1CE, 0x313E, 0x1872, 0xE139, 0xD40F,
+        0x84F9, 0x280C, 0xA96A, 0x4EC3,
+    ];
+
+    const ENCRYPTION_MATRIX: [[u16; 7]; 15] = [
+        [0xAEFC, 0x4DD9, 0x9BB2, 0x2745, 0x4E8A, 0x9D14, 0x2A09],
+        [0x7B61, 0xF6C2, 0xFDA5, 0xEB6B, 0xC6F7, 0x9DCF, 0x2BBF],
+        [0x4563, 0x8AC6, 0x05AD, 0x0B5A, 0x16B4, 0x2D68, 0x5AD0],
+        [0x0375, 0x06EA, 0x0DD4, 0x1BA8, 0x3750, 0x6EA0, 0xDD40],
+        [0xD849, 0xA0B3, 0x5147, 0xA28E, 0x553D, 0xAA7A, 0x44D5],
+        [0x6F45, 0xDE8A, 0xAD35, 0x4A4B, 0x9496, 0x390D, 0x721A],
+        [0xEB23, 0xC667, 0x9CEF, 0x29FF, 0x53FE, 0xA7FC, 0x5FD9],
+        [0x47D3, 0x8FA6, 0x0F6D, 0x1EDA, 0x3DB4, 0x7B68, 0xF6D0],
+        [0xB861, 0x60E3, 0xC1C6, 0x93AD, 0x377B, 0x6EF6, 0xDDEC],
+        [0x45A0, 0x8B40, 0x06A1, 0x0D42, 0x1A84, 0x3508, 0x6A10],
+        [0xAA51, 0x4483, 0x8906, 0x022D, 0x045A, 0x08B4, 0x1168],
+        [0x76B4, 0xED68, 0xCAF1, 0x85C3, 0x1BA7, 0x374E, 0x6E9C],
+        [0x3730, 0x6E60, 0xDCC0, 0xA9A1, 0x4363, 0x86C6, 0x1DAD],
+        [0x3331, 0x6662, 0xCCC4, 0x89A9, 0x0373, 0x06E6, 0x0DCC],
+        [0x1021, 0x2042, 0x4084, 0x8108, 0x1231, 0x2462, 0x48C4],
+    ];
+
+    // takes the low byte of each character, or the high byte if it's zero,
+    // and truncates the password to 15 characters
+    let bytes: Vec<u8> = password
+        .encode_utf16()
+        .take(15)
+        .map(|c| match c.to_le_bytes() {
+            [0, high] => high,
+            [low, _] => low,
+        })
+        .collect();
+
+    if bytes.is_empty() {
+        return 0;
+    }
+
+    let mut high = INITIAL_CODES[bytes.len() - 1];
+    for (i, byte) in bytes.iter().enumerate() {
+        let row = &ENCRYPTION_MATRIX[15 - bytes.len() + i];
+        for (bit, code) in row.iter().enumerate() {
+            if byte & (1 << bit) != 0 {
+                high ^= code;
+            }
+        }
+    }
+
+    let rotate = |val: u16| ((val >> 14) & 1) | ((val << 1) & 0x7FFF);
+
+    let mut low = 0u16;
+    for byte in bytes.iter().rev() {
+        low = rotate(low) ^ *byte as u16;
+    }
+    low = rotate(low) ^ bytes.len() as u16 ^ 0xCE4B;
+
+    ((high as u32) << 16) | low as u32
+}
+
+#[test]
+fn legacy_key_example() {
+    assert_eq!(legacy_key("Example"), 0x64CEED7E);
+}
+
+#[test]
+fn password_hash() {
+    let salt = base64::decode("ZUdHa+D8F/OAKP3I7ssUnQ==").unwrap();
+    let protection =
+        DocumentProtection::new(ProtectionType::ReadOnly).password_with_salt("Example", &salt);
+
+    assert_eq!(
+        protection.hash.as_deref(),
+        Some("K1Kt8GI2R13SGtggtGCvzwKuQJpF7553j4xXgPlrQPMXd2zV2d1Zyvt9TfCDojDVTeQA3b+ZR0eCZk8Yd8h0VA==")
+    );
+    assert_eq!(protection.salt.as_deref(), Some("ZUdHa+D8F/OAKP3I7ssUnQ=="));
+    assert!(protection.verify_password("Example"));
+    assert!(!protection.verify_password("example"));
+}
+
+__xml_test_suites!(
+    DocumentProtection,
+    DocumentProtection::default(),
+    r#"<w:documentProtection/>"#,
+    DocumentProtection::new(ProtectionType::ReadOnly),
+    r#"<w:documentProtection w:edit="readOnly" w:enforcement="true"/>"#,
+    DocumentProtection::new(ProtectionType::Forms).password_with_salt("", &[0; 16]),
+    r#"<w:documentProtection w:edit="forms" w:enforcement="true"/>"#,
+);
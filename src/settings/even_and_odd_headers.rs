@@ -0,0 +1,36 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Different Even and Odd Page Headers and Footers
+///
+/// Specifies whether even and odd pages use different headers and footers.
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let val = EvenAndOddHeaders::from(true);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:evenAndOddHeaders")]
+pub struct EvenAndOddHeaders {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for EvenAndOddHeaders {
+    fn from(val: T) -> Self {
+        EvenAndOddHeaders { value: val.into() }
+    }
+}
+
+__xml_test_suites!(
+    EvenAndOddHeaders,
+    EvenAndOddHeaders::default(),
+    r#"<w:evenAndOddHeaders/>"#,
+    EvenAndOddHeaders::from(false),
+    r#"<w:evenAndOddHeaders w:val="false"/>"#,
+    EvenAndOddHeaders::from(true),
+    r#"<w:evenAndOddHeaders w:val="true"/>"#,
+);
@@ -0,0 +1,172 @@
+//! Document Settings part
+//!
+//! The corresponding ZIP item is `/word/settings.xml`.
+
+mod compat;
+mod default_tab_stop;
+mod document_protection;
+mod even_and_odd_headers;
+mod proof_state;
+mod update_fields;
+mod zoom;
+
+pub use self::{
+    compat::*, default_tab_stop::*, document_protection::*, even_and_odd_headers::*,
+    proof_state::*, update_fields::*, zoom::*,
+};
+
+use std::io::Write;
+use strong_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+
+use crate::formatting::{EndnoteProperty, FootnoteProperty};
+use crate::schema::SCHEMA_MAIN;
+use crate::{__setter, __xml_test_suites};
+
+/// The root element of the document settings part
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let settings = Settings::default()
+///     .zoom(100usize)
+///     .default_tab_stop(720usize)
+///     .update_fields(true)
+///     .compat(Compat::default().push_setting(CompatSetting::compatibility_mode(15)))
+///     .document_protection(DocumentProtection::new(ProtectionType::ReadOnly));
+/// ```
+#[derive(Debug, Default, XmlRead)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:settings")]
+pub struct Settings<'a> {
+    /// Specifies the magnification level of the document.
+    #[xml(child = "w:zoom")]
+    pub zoom: Option<Zoom>,
+    /// Specifies the spelling and grammar checking state.
+    #[xml(child = "w:proofState")]
+    pub proof_state: Option<ProofState>,
+    /// Specifies the editing restrictions of the document.
+    #[xml(child = "w:documentProtection")]
+    pub document_protection: Option<DocumentProtection<'a>>,
+    /// Specifies the interval between automatic tab stops.
+    #[xml(child = "w:defaultTabStop")]
+    pub default_tab_stop: Option<DefaultTabStop>,
+    /// Specifies whether even and odd pages use different headers and footers.
+    #[xml(child = "w:evenAndOddHeaders")]
+    pub even_and_odd_headers: Option<EvenAndOddHeaders>,
+    /// Specifies whether fields are updated when the document is opened.
+    #[xml(child = "w:updateFields")]
+    pub update_fields: Option<UpdateFields>,
+    /// Specifies the footnote properties of the document.
+    #[xml(child = "w:footnotePr")]
+    pub footnote_property: Option<FootnoteProperty>,
+    /// Specifies the endnote properties of the document.
+    #[xml(child = "w:endnotePr")]
+    pub endnote_property: Option<EndnoteProperty>,
+    /// Specifies the compatibility settings.
+    #[xml(child = "w:compat")]
+    pub compat: Option<Compat<'a>>,
+}
+
+impl<'a> Settings<'a> {
+    __setter!(zoom: Option<Zoom>);
+    __setter!(proof_state: Option<ProofState>);
+    __setter!(document_protection: Option<DocumentProtection<'a>>);
+    __setter!(default_tab_stop: Option<DefaultTabStop>);
+    __setter!(even_and_odd_headers: Option<EvenAndOddHeaders>);
+    __setter!(update_fields: Option<UpdateFields>);
+    __setter!(footnote_property: Option<FootnoteProperty>);
+    __setter!(endnote_property: Option<EndnoteProperty>);
+    __setter!(compat: Option<Compat<'a>>);
+}
+
+impl<'a> XmlWrite for Settings<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Settings {
+            zoom,
+            proof_state,
+            document_protection,
+            default_tab_stop,
+            even_and_odd_headers,
+            update_fields,
+            footnote_property,
+            endnote_property,
+            compat,
+        } = self;
+
+        log::debug!("[Settings] Started writing.");
+
+        writer.write_element_start("w:settings")?;
+
+        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+
+        writer.write_element_end_open()?;
+
+        if let Some(ele) = zoom {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = proof_state {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = document_protection {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = default_tab_stop {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = even_and_odd_headers {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = update_fields {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = footnote_property {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = endnote_property {
+            ele.to_writer(writer)?;
+        }
+
+        if let Some(ele) = compat {
+            ele.to_writer(writer)?;
+        }
+
+        writer.write_element_end_close("w:settings")?;
+
+        log::debug!("[Settings] Finished writing.");
+
+        Ok(())
+    }
+}
+
+__xml_test_suites!(
+    Settings,
+    Settings::default(),
+    format!(r#"<w:settings xmlns:w="{}"></w:settings>"#, SCHEMA_MAIN).as_str(),
+    Settings::default().document_protection(DocumentProtection::new(ProtectionType::Comments)),
+    format!(
+        r#"<w:settings xmlns:w="{}"><w:documentProtection w:edit="comments" w:enforcement="true"/></w:settings>"#,
+        SCHEMA_MAIN
+    )
+    .as_str(),
+    Settings::default()
+        .zoom(100usize)
+        .proof_state(ProofState::default().spelling(ProofStateType::Clean))
+        .default_tab_stop(720usize)
+        .even_and_odd_headers(true)
+        .update_fields(true)
+        .footnote_property(FootnoteProperty::default())
+        .endnote_property(EndnoteProperty::default())
+        .compat(Compat::default()),
+    format!(
+        r#"<w:settings xmlns:w="{}"><w:zoom w:percent="100"/><w:proofState w:spelling="clean"/><w:defaultTabStop w:val="720"/><w:evenAndOddHeaders w:val="true"/><w:updateFields w:val="true"/><w:footnotePr/><w:endnotePr/><w:compat/></w:settings>"#,
+        SCHEMA_MAIN
+    )
+    .as_str(),
+);
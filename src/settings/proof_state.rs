@@ -0,0 +1,55 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Proofing State
+///
+/// Specifies whether spelling and grammar have been checked in the document.
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let state = ProofState::default()
+///     .spelling(ProofStateType::Clean)
+///     .grammar(ProofStateType::Clean);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:proofState")]
+pub struct ProofState {
+    /// Specifies the spelling state.
+    #[xml(attr = "w:spelling")]
+    pub spelling: Option<ProofStateType>,
+    /// Specifies the grammar state.
+    #[xml(attr = "w:grammar")]
+    pub grammar: Option<ProofStateType>,
+}
+
+impl ProofState {
+    __setter!(spelling: Option<ProofStateType>);
+    __setter!(grammar: Option<ProofStateType>);
+}
+
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum ProofStateType {
+    Clean,
+    Dirty,
+}
+
+__string_enum! {
+    ProofStateType {
+        Clean = "clean",
+        Dirty = "dirty",
+    }
+}
+
+__xml_test_suites!(
+    ProofState,
+    ProofState::default(),
+    r#"<w:proofState/>"#,
+    ProofState::default()
+        .spelling(ProofStateType::Clean)
+        .grammar(ProofStateType::Dirty),
+    r#"<w:proofState w:spelling="clean" w:grammar="dirty"/>"#,
+);
@@ -0,0 +1,37 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::__xml_test_suites;
+
+/// Automatically Recalculate Fields on Open
+///
+/// Specifies whether fields, such as a table of contents, are updated when
+/// the document is opened.
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let val = UpdateFields::from(true);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:updateFields")]
+pub struct UpdateFields {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+impl<T: Into<Option<bool>>> From<T> for UpdateFields {
+    fn from(val: T) -> Self {
+        UpdateFields { value: val.into() }
+    }
+}
+
+__xml_test_suites!(
+    UpdateFields,
+    UpdateFields::default(),
+    r#"<w:updateFields/>"#,
+    UpdateFields::from(false),
+    r#"<w:updateFields w:val="false"/>"#,
+    UpdateFields::from(true),
+    r#"<w:updateFields w:val="true"/>"#,
+);
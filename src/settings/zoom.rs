@@ -0,0 +1,67 @@
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Zoom
+///
+/// Specifies the magnification level used when the document is opened.
+///
+/// ```rust
+/// use docx::settings::*;
+///
+/// let zoom = Zoom::from(120usize);
+/// let zoom = Zoom::default().value(ZoomType::BestFit);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:zoom")]
+pub struct Zoom {
+    /// Specifies a preset zoom type.
+    #[xml(attr = "w:val")]
+    pub value: Option<ZoomType>,
+    /// Specifies the zoom percentage.
+    #[xml(attr = "w:percent")]
+    pub percent: Option<usize>,
+}
+
+impl Zoom {
+    __setter!(value: Option<ZoomType>);
+    __setter!(percent: Option<usize>);
+}
+
+impl From<usize> for Zoom {
+    fn from(val: usize) -> Self {
+        Zoom {
+            value: None,
+            percent: Some(val),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum ZoomType {
+    None,
+    FullPage,
+    BestFit,
+    TextFit,
+}
+
+__string_enum! {
+    ZoomType {
+        None = "none",
+        FullPage = "fullPage",
+        BestFit = "bestFit",
+        TextFit = "textFit",
+    }
+}
+
+__xml_test_suites!(
+    Zoom,
+    Zoom::default(),
+    r#"<w:zoom/>"#,
+    Zoom::from(100usize),
+    r#"<w:zoom w:percent="100"/>"#,
+    Zoom::default().value(ZoomType::FullPage).percent(80usize),
+    r#"<w:zoom w:val="fullPage" w:percent="80"/>"#,
+);
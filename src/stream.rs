@@ -0,0 +1,254 @@
+//! Streaming ZIP writer
+//!
+//! Unlike `zip::ZipWriter`, this writer never seeks backwards: sizes and
+//! checksums of each entry are written after its data in a data descriptor,
+//! so the output only needs to implement `Write`.
+
+use crc32fast::Hasher;
+use flate2::{write::DeflateEncoder, Compression};
+use std::convert::TryFrom;
+use std::io::{Error as IOError, Result, Write};
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x0807_4b50;
+const CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+/// Version 2.0, the minimal version supporting deflate and data descriptors.
+const VERSION: u16 = 20;
+/// Bit 3: sizes and crc are stored in the data descriptor,
+/// bit 11: file names are encoded in UTF-8.
+const FLAGS: u16 = 0x0808;
+const METHOD_DEFLATED: u16 = 8;
+/// 1980-01-01 00:00:00 in MS-DOS format
+const DOS_TIME: u16 = 0;
+const DOS_DATE: u16 = 0x0021;
+
+/// Writer that counts the number of bytes written into it
+struct CountingWriter<W: Write> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = self.inner.write(buf)?;
+        self.count += len as u64;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct Entry {
+    name: String,
+    offset: u32,
+    crc: u32,
+    compressed_size: u32,
+    uncompressed_size: u32,
+}
+
+struct CurrentEntry {
+    name: String,
+    offset: u32,
+    data_start: u64,
+    hasher: Hasher,
+    uncompressed_size: u64,
+}
+
+/// ZIP writer for non-seekable outputs
+pub struct StreamWriter<W: Write> {
+    writer: Option<CountingWriter<W>>,
+    encoder: Option<DeflateEncoder<CountingWriter<W>>>,
+    current: Option<CurrentEntry>,
+    entries: Vec<Entry>,
+}
+
+impl<W: Write> StreamWriter<W> {
+    pub fn new(inner: W) -> Self {
+        StreamWriter {
+            writer: Some(CountingWriter { inner, count: 0 }),
+            encoder: None,
+            current: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Finishes the previous entry and starts a new one
+    pub fn start_file(&mut self, name: &str) -> Result<()> {
+        self.finish_file()?;
+
+        let mut writer = self.writer.take().ok_or_else(closed)?;
+
+        let offset = to_u32(writer.count)?;
+
+        write_u32(&mut writer, LOCAL_FILE_HEADER_SIGNATURE)?;
+        write_u16(&mut writer, VERSION)?;
+        write_u16(&mut writer, FLAGS)?;
+        write_u16(&mut writer, METHOD_DEFLATED)?;
+        write_u16(&mut writer, DOS_TIME)?;
+        write_u16(&mut writer, DOS_DATE)?;
+        // crc-32, compressed size and uncompressed size are deferred
+        // to the data descriptor
+        write_u32(&mut writer, 0)?;
+        write_u32(&mut writer, 0)?;
+        write_u32(&mut writer, 0)?;
+        write_u16(&mut writer, to_u16(name.len())?)?;
+        write_u16(&mut writer, 0)?;
+        writer.write_all(name.as_bytes())?;
+
+        self.current = Some(CurrentEntry {
+            name: name.to_owned(),
+            offset,
+            data_start: writer.count,
+            hasher: Hasher::new(),
+            uncompressed_size: 0,
+        });
+        self.encoder = Some(DeflateEncoder::new(writer, Compression::default()));
+
+        Ok(())
+    }
+
+    fn finish_file(&mut self) -> Result<()> {
+        let (encoder, current) = match (self.encoder.take(), self.current.take()) {
+            (Some(encoder), Some(current)) => (encoder, current),
+            _ => return Ok(()),
+        };
+
+        let mut writer = encoder.finish()?;
+
+        let entry = Entry {
+            name: current.name,
+            offset: current.offset,
+            crc: current.hasher.finalize(),
+            compressed_size: to_u32(writer.count - current.data_start)?,
+            uncompressed_size: to_u32(current.uncompressed_size)?,
+        };
+
+        write_u32(&mut writer, DATA_DESCRIPTOR_SIGNATURE)?;
+        write_u32(&mut writer, entry.crc)?;
+        write_u32(&mut writer, entry.compressed_size)?;
+        write_u32(&mut writer, entry.uncompressed_size)?;
+
+        self.entries.push(entry);
+        self.writer = Some(writer);
+
+        Ok(())
+    }
+
+    /// Writes the central directory and returns the inner writer
+    pub fn finish(mut self) -> Result<W> {
+        self.finish_file()?;
+
+        let mut writer = self.writer.take().ok_or_else(closed)?;
+
+        let directory_start = writer.count;
+
+        for entry in &self.entries {
+            write_u32(&mut writer, CENTRAL_DIRECTORY_HEADER_SIGNATURE)?;
+            write_u16(&mut writer, VERSION)?; // version made by
+            write_u16(&mut writer, VERSION)?; // version needed to extract
+            write_u16(&mut writer, FLAGS)?;
+            write_u16(&mut writer, METHOD_DEFLATED)?;
+            write_u16(&mut writer, DOS_TIME)?;
+            write_u16(&mut writer, DOS_DATE)?;
+            write_u32(&mut writer, entry.crc)?;
+            write_u32(&mut writer, entry.compressed_size)?;
+            write_u32(&mut writer, entry.uncompressed_size)?;
+            write_u16(&mut writer, to_u16(entry.name.len())?)?;
+            write_u16(&mut writer, 0)?; // extra field length
+            write_u16(&mut writer, 0)?; // file comment length
+            write_u16(&mut writer, 0)?; // disk number start
+            write_u16(&mut writer, 0)?; // internal file attributes
+            write_u32(&mut writer, 0)?; // external file attributes
+            write_u32(&mut writer, entry.offset)?;
+            writer.write_all(entry.name.as_bytes())?;
+        }
+
+        let directory_size = to_u32(writer.count - directory_start)?;
+        let entries = to_u16(self.entries.len())?;
+
+        write_u32(&mut writer, END_OF_CENTRAL_DIRECTORY_SIGNATURE)?;
+        write_u16(&mut writer, 0)?; // number of this disk
+        write_u16(&mut writer, 0)?; // disk where central directory starts
+        write_u16(&mut writer, entries)?;
+        write_u16(&mut writer, entries)?;
+        write_u32(&mut writer, directory_size)?;
+        write_u32(&mut writer, to_u32(directory_start)?)?;
+        write_u16(&mut writer, 0)?; // comment length
+
+        writer.flush()?;
+
+        Ok(writer.inner)
+    }
+}
+
+impl<W: Write> Write for StreamWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match (&mut self.encoder, &mut self.current) {
+            (Some(encoder), Some(current)) => {
+                let len = encoder.write(buf)?;
+                current.hasher.update(&buf[..len]);
+                current.uncompressed_size += len as u64;
+                Ok(len)
+            }
+            _ => Err(IOError::other("No file has been started")),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match (&mut self.encoder, &mut self.writer) {
+            (Some(encoder), _) => encoder.flush(),
+            (None, Some(writer)) => writer.flush(),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+fn closed() -> IOError {
+    IOError::other("The zip stream has been closed")
+}
+
+fn to_u16(val: usize) -> Result<u16> {
+    u16::try_from(val).map_err(|_| IOError::other("Too many entries or file name too long"))
+}
+
+fn to_u32(val: u64) -> Result<u32> {
+    u32::try_from(val).map_err(|_| IOError::other("ZIP64 is not supported"))
+}
+
+fn write_u16<W: Write>(writer: &mut W, val: u16) -> Result<()> {
+    writer.write_all(&val.to_le_bytes())
+}
+
+fn write_u32<W: Write>(writer: &mut W, val: u32) -> Result<()> {
+    writer.write_all(&val.to_le_bytes())
+}
+
+#[test]
+fn stream_writer() -> crate::DocxResult<()> {
+    use std::io::{Cursor, Read};
+
+    let mut writer = StreamWriter::new(Vec::new());
+    writer.start_file("foo.xml")?;
+    writer.write_all(b"<foo/>")?;
+    writer.start_file("bar/baz.xml")?;
+    writer.write_all(b"<baz>")?;
+    writer.write_all(b"</baz>")?;
+    let bytes = writer.finish()?;
+
+    let mut zip = zip::ZipArchive::new(Cursor::new(bytes))?;
+    assert_eq!(zip.len(), 2);
+
+    let mut content = String::new();
+    zip.by_name("foo.xml")?.read_to_string(&mut content)?;
+    assert_eq!(content, "<foo/>");
+
+    content.clear();
+    zip.by_name("bar/baz.xml")?.read_to_string(&mut content)?;
+    assert_eq!(content, "<baz></baz>");
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+//! Vector Markup Language
+//!
+//! VML shapes are the legacy form of drawings, placed in runs with
+//! `document::Picture`. Word writes them as the fallback of DrawingML
+//! shapes for readers which don't support DrawingML.
+
+mod shape;
+mod text_box;
+
+// re-export
+pub use self::{shape::*, text_box::*};
@@ -0,0 +1,179 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, vml::TextBox};
+
+/// VML Rectangle
+///
+/// ```rust
+/// use docx::vml::*;
+///
+/// let rect = Rect::default()
+///     .style("width:144pt;height:36pt")
+///     .fill_color("#FFFFFF")
+///     .stroke_color("#000000");
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "v:rect")]
+pub struct Rect<'a> {
+    #[xml(attr = "id")]
+    pub id: Option<Cow<'a, str>>,
+    /// Specifies the CSS style, including the position and the size.
+    #[xml(attr = "style")]
+    pub style: Option<Cow<'a, str>>,
+    #[xml(attr = "fillcolor")]
+    pub fill_color: Option<Cow<'a, str>>,
+    #[xml(attr = "filled")]
+    pub filled: Option<bool>,
+    #[xml(attr = "strokecolor")]
+    pub stroke_color: Option<Cow<'a, str>>,
+    /// Specifies the width of the outline, e.g. `.5pt`.
+    #[xml(attr = "strokeweight")]
+    pub stroke_weight: Option<Cow<'a, str>>,
+    #[xml(attr = "stroked")]
+    pub stroked: Option<bool>,
+    #[xml(child = "v:textbox")]
+    pub text_box: Option<TextBox<'a>>,
+}
+
+impl<'a> Rect<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+    __setter!(style: Option<Cow<'a, str>>);
+    __setter!(fill_color: Option<Cow<'a, str>>);
+    __setter!(filled: Option<bool>);
+    __setter!(stroke_color: Option<Cow<'a, str>>);
+    __setter!(stroke_weight: Option<Cow<'a, str>>);
+    __setter!(stroked: Option<bool>);
+    __setter!(text_box: Option<TextBox<'a>>);
+}
+
+/// VML Rounded Rectangle
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "v:roundrect")]
+pub struct RoundRect<'a> {
+    #[xml(attr = "id")]
+    pub id: Option<Cow<'a, str>>,
+    /// Specifies the CSS style, including the position and the size.
+    #[xml(attr = "style")]
+    pub style: Option<Cow<'a, str>>,
+    /// Specifies the rounding of the corners, e.g. `10923f`.
+    #[xml(attr = "arcsize")]
+    pub arc_size: Option<Cow<'a, str>>,
+    #[xml(attr = "fillcolor")]
+    pub fill_color: Option<Cow<'a, str>>,
+    #[xml(attr = "filled")]
+    pub filled: Option<bool>,
+    #[xml(attr = "strokecolor")]
+    pub stroke_color: Option<Cow<'a, str>>,
+    /// Specifies the width of the outline, e.g. `.5pt`.
+    #[xml(attr = "strokeweight")]
+    pub stroke_weight: Option<Cow<'a, str>>,
+    #[xml(attr = "stroked")]
+    pub stroked: Option<bool>,
+    #[xml(child = "v:textbox")]
+    pub text_box: Option<TextBox<'a>>,
+}
+
+impl<'a> RoundRect<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+    __setter!(style: Option<Cow<'a, str>>);
+    __setter!(arc_size: Option<Cow<'a, str>>);
+    __setter!(fill_color: Option<Cow<'a, str>>);
+    __setter!(filled: Option<bool>);
+    __setter!(stroke_color: Option<Cow<'a, str>>);
+    __setter!(stroke_weight: Option<Cow<'a, str>>);
+    __setter!(stroked: Option<bool>);
+    __setter!(text_box: Option<TextBox<'a>>);
+}
+
+/// VML Line
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "v:line")]
+pub struct Line<'a> {
+    #[xml(attr = "id")]
+    pub id: Option<Cow<'a, str>>,
+    /// Specifies the CSS style, including the position.
+    #[xml(attr = "style")]
+    pub style: Option<Cow<'a, str>>,
+    /// Specifies the start point, e.g. `0,0`.
+    #[xml(attr = "from")]
+    pub from: Option<Cow<'a, str>>,
+    /// Specifies the end point, e.g. `144pt,0`.
+    #[xml(attr = "to")]
+    pub to: Option<Cow<'a, str>>,
+    #[xml(attr = "strokecolor")]
+    pub stroke_color: Option<Cow<'a, str>>,
+    /// Specifies the width of the line, e.g. `.5pt`.
+    #[xml(attr = "strokeweight")]
+    pub stroke_weight: Option<Cow<'a, str>>,
+    #[xml(attr = "stroked")]
+    pub stroked: Option<bool>,
+}
+
+impl<'a> Line<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+    __setter!(style: Option<Cow<'a, str>>);
+    __setter!(from: Option<Cow<'a, str>>);
+    __setter!(to: Option<Cow<'a, str>>);
+    __setter!(stroke_color: Option<Cow<'a, str>>);
+    __setter!(stroke_weight: Option<Cow<'a, str>>);
+    __setter!(stroked: Option<bool>);
+}
+
+/// VML Shape
+///
+/// A shape of a shape type defined elsewhere in the document, e.g.
+/// `#_x0000_t202` for text boxes written by Word.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "v:shape")]
+pub struct Shape<'a> {
+    #[xml(attr = "id")]
+    pub id: Option<Cow<'a, str>>,
+    /// Specifies the reference to the shape type.
+    #[xml(attr = "type")]
+    pub shape_type: Option<Cow<'a, str>>,
+    /// Specifies the CSS style, including the position and the size.
+    #[xml(attr = "style")]
+    pub style: Option<Cow<'a, str>>,
+    #[xml(attr = "fillcolor")]
+    pub fill_color: Option<Cow<'a, str>>,
+    #[xml(attr = "filled")]
+    pub filled: Option<bool>,
+    #[xml(attr = "strokecolor")]
+    pub stroke_color: Option<Cow<'a, str>>,
+    /// Specifies the width of the outline, e.g. `.5pt`.
+    #[xml(attr = "strokeweight")]
+    pub stroke_weight: Option<Cow<'a, str>>,
+    #[xml(attr = "stroked")]
+    pub stroked: Option<bool>,
+    #[xml(child = "v:textbox")]
+    pub text_box: Option<TextBox<'a>>,
+}
+
+impl<'a> Shape<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+    __setter!(shape_type: Option<Cow<'a, str>>);
+    __setter!(style: Option<Cow<'a, str>>);
+    __setter!(fill_color: Option<Cow<'a, str>>);
+    __setter!(filled: Option<bool>);
+    __setter!(stroke_color: Option<Cow<'a, str>>);
+    __setter!(stroke_weight: Option<Cow<'a, str>>);
+    __setter!(stroked: Option<bool>);
+    __setter!(text_box: Option<TextBox<'a>>);
+}
+
+__xml_test_suites!(
+    Rect,
+    Rect::default(),
+    r#"<v:rect/>"#,
+    Rect::default()
+        .style("width:144pt;height:36pt")
+        .fill_color("#FFFFFF")
+        .stroked(false)
+        .text_box(TextBox::default()),
+    r##"<v:rect style="width:144pt;height:36pt" fillcolor="#FFFFFF" stroked="false"><v:textbox><w:txbxContent/></v:textbox></v:rect>"##,
+);
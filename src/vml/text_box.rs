@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+use strong_xml::{XmlRead, XmlWrite};
+
+use crate::{__setter, __xml_test_suites, document::TextBoxContent};
+
+/// VML Text Box
+///
+/// Specifies the text box of a VML shape.
+#[derive(Debug, Default, XmlRead, XmlWrite)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "v:textbox")]
+pub struct TextBox<'a> {
+    /// Specifies the insets of the text, e.g. `7.2pt,3.6pt,7.2pt,3.6pt`.
+    #[xml(attr = "inset")]
+    pub inset: Option<Cow<'a, str>>,
+    #[xml(default, child = "w:txbxContent")]
+    pub content: TextBoxContent<'a>,
+}
+
+impl<'a> TextBox<'a> {
+    __setter!(inset: Option<Cow<'a, str>>);
+}
+
+impl<'a> From<TextBoxContent<'a>> for TextBox<'a> {
+    fn from(content: TextBoxContent<'a>) -> Self {
+        TextBox {
+            inset: None,
+            content,
+        }
+    }
+}
+
+__xml_test_suites!(
+    TextBox,
+    TextBox::default(),
+    r#"<v:textbox><w:txbxContent/></v:textbox>"#,
+    TextBox::default().inset("0,0,0,0"),
+    r#"<v:textbox inset="0,0,0,0"><w:txbxContent/></v:textbox>"#,
+);